@@ -0,0 +1,30 @@
+#!/bin/bash
+# Regenerate static/fonts/NotoSansJP-Weather-Regular.ttf, the Noto Sans JP
+# subset the dashboard falls back to for Japanese text (Roboto has no CJK
+# glyphs). The subset keeps only the characters the Japanese strings in
+# src/i18n.rs and src/dashboard/summary.rs use, plus ASCII, so run this after
+# changing either.
+#
+# Usage:
+#   ./scripts/subset-japanese-font.sh path/to/NotoSansJP-Regular.ttf
+#
+# Requires pyftsubset (pip install fonttools).
+
+set -euo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+cd "$SCRIPT_DIR/.."
+
+SOURCE_FONT="${1:?Usage: $0 path/to/NotoSansJP-Regular.ttf}"
+OUTPUT_FONT="static/fonts/NotoSansJP-Weather-Regular.ttf"
+
+# Hiragana, katakana, kanji and Japanese punctuation found in the sources.
+TEXT="$(LC_ALL=C.UTF-8 grep -ohP '[\x{3000}-\x{30FF}\x{4E00}-\x{9FFF}\x{FF00}-\x{FFEF}]' \
+    src/i18n.rs src/dashboard/summary.rs | sort -u | tr -d '\n')"
+
+pyftsubset "$SOURCE_FONT" \
+    --text="$TEXT" \
+    --unicodes="U+0020-007E" \
+    --output-file="$OUTPUT_FONT"
+
+echo "Wrote $OUTPUT_FONT ($(echo -n "$TEXT" | wc -m) Japanese characters)"
@@ -47,11 +47,22 @@ impl Display for Temperature {
 pub struct Wind {
     pub speed_kilometre: u16,
     // pub speed_knot: u16,
-    // pub direction: String,
+    /// 16-point compass direction the wind blows *from* (e.g. "SSE"), or
+    /// "CALM"; absent in some responses.
+    #[serde(default)]
+    pub direction: Option<String>,
     // pub gust_speed_knot: Option<u16>,
     pub gust_speed_kilometre: u16,
 }
 
+impl Wind {
+    /// The compass `direction` as degrees clockwise from north, or `None`
+    /// if absent, calm, or not a recognised compass point.
+    pub fn direction_degrees(&self) -> Option<u16> {
+        self.direction.as_deref().and_then(compass_point_to_degrees)
+    }
+}
+
 // #[derive(Deserialize, Debug)]
 // pub struct HourlyMetadata {
 //     pub response_timestamp: DateTime<Utc>,
@@ -120,9 +131,9 @@ pub struct DailyEntry {
     pub temp_max: Option<Temperature>,
     #[serde(deserialize_with = "de_temp_celsius_opt")]
     pub temp_min: Option<Temperature>,
-    // pub extended_text: Option<String>,
+    pub extended_text: Option<String>,
     // pub icon_descriptor: Option<String>,
-    // pub short_text: Option<String>,
+    pub short_text: Option<String>,
     // pub surf_danger: Option<String>,
     // pub fire_danger: Option<String>,
     // pub fire_danger_category: Option<FireDangerCategory>,
@@ -202,6 +213,8 @@ mod tests {
         assert_eq!(first.temp_feels_like.value, 15.0);
         assert_eq!(first.wind.speed_kilometre, 9);
         assert_eq!(first.wind.gust_speed_kilometre, 19);
+        assert_eq!(first.wind.direction.as_deref(), Some("ENE"));
+        assert_eq!(first.wind.direction_degrees(), Some(68));
         assert_eq!(first.relative_humidity.0, 64);
         assert_eq!(first.uv.unwrap().0, 0);
         assert_eq!(first.rain.chance, Some(30));
@@ -32,6 +32,22 @@ where
     }))
 }
 
+/// The 16 compass points BOM reports wind direction in, clockwise from north.
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
+/// Converts a BOM compass point (e.g. "SSE") to whole degrees clockwise from
+/// north. Returns `None` for anything else — notably "CALM", which has no
+/// direction to draw.
+pub fn compass_point_to_degrees(point: &str) -> Option<u16> {
+    COMPASS_POINTS
+        .iter()
+        .position(|&p| p.eq_ignore_ascii_case(point.trim()))
+        .map(|i| (i as f32 * 22.5).round() as u16)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,4 +88,26 @@ mod tests {
         // masking a real upstream problem as ordinary missing data.
         assert!(de_opt_result(r#""not a number""#).is_err());
     }
+
+    #[test]
+    fn cardinal_points_map_to_right_angles() {
+        assert_eq!(compass_point_to_degrees("N"), Some(0));
+        assert_eq!(compass_point_to_degrees("E"), Some(90));
+        assert_eq!(compass_point_to_degrees("S"), Some(180));
+        assert_eq!(compass_point_to_degrees("W"), Some(270));
+    }
+
+    #[test]
+    fn intermediate_points_round_to_whole_degrees() {
+        assert_eq!(compass_point_to_degrees("NNE"), Some(23));
+        assert_eq!(compass_point_to_degrees("SSE"), Some(158));
+        assert_eq!(compass_point_to_degrees("NNW"), Some(338));
+    }
+
+    #[test]
+    fn calm_and_unknown_values_have_no_direction() {
+        assert_eq!(compass_point_to_degrees("CALM"), None);
+        assert_eq!(compass_point_to_degrees(""), None);
+        assert_eq!(compass_point_to_degrees("NORTH"), None);
+    }
 }
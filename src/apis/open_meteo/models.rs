@@ -100,6 +100,10 @@ pub struct Hourly {
     pub wind_speed_10m: Vec<f32>,
     #[serde(rename = "wind_gusts_10m")]
     pub wind_gusts_10m: Vec<f32>,
+    /// Direction the wind blows from, in degrees (0-360). `default` for the
+    /// same pre-upgrade cache reason as `is_day` below.
+    #[serde(rename = "wind_direction_10m", default)]
+    pub wind_direction_10m: Vec<Option<u16>>,
     #[serde(rename = "relative_humidity_2m")]
     pub relative_humidity_2m: Vec<u16>,
     #[serde(rename = "cloud_cover")]
@@ -198,7 +202,8 @@ impl OpenMeteoHourlyResponse {
                 let wind = DomainWind::new(
                     hourly_data.wind_speed_10m[i].round() as u16,
                     hourly_data.wind_gusts_10m[i].round() as u16,
-                );
+                )
+                .with_direction(hourly_data.wind_direction_10m.get(i).copied().flatten());
 
                 let precipitation = Precipitation::new_with_snowfall(
                     Some(hourly_data.precipitation_probability[i]),
@@ -387,6 +392,9 @@ impl OpenMeteoDailyResponse {
                     astronomical,
                     cloud_cover,
                     weather_code,
+                    // Open-Meteo has no forecaster text
+                    summary: None,
+                    short_summary: None,
                 }
             })
             .collect()
@@ -1,8 +1,13 @@
 use super::validation::*;
+use crate::constants::OUTLINED_ICONS_DIRECTORY;
 use crate::i18n::Language;
+use crate::weather::icon_pack::IconPack;
 use nutype::nutype;
 use serde::Deserialize;
-use std::{env, fmt, path::PathBuf};
+use std::{
+    env, fmt,
+    path::{Path, PathBuf},
+};
 use strum_macros::Display;
 use url::Url;
 
@@ -41,6 +46,248 @@ pub enum HourFormat {
     TwentyFour,
 }
 
+/// How the hours between sunset and sunrise are filled on the hourly chart.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum NightShading {
+    /// No night-time bands.
+    #[default]
+    None,
+    /// Flat fill at low opacity — the panel's dithering turns it into a
+    /// light tint.
+    Solid,
+    /// Thin diagonal lines; crisp on panels without greyscale.
+    Hatched,
+    /// Sparse dot grid; the lightest-looking option.
+    Dotted,
+}
+
+/// What the hourly chart's precipitation area and right axis represent.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PrecipitationMode {
+    /// Height is chance of precipitation (0–100%).
+    #[default]
+    Chance,
+    /// Height is expected amount per hour, drawn as bars.
+    Amount,
+    /// Amount bars, with chance shown as fill opacity.
+    Both,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+pub enum PrecipitationUnit {
+    #[default]
+    #[serde(rename = "mm")]
+    #[strum(serialize = "mm")]
+    Mm,
+    #[serde(rename = "in")]
+    #[strum(serialize = "in")]
+    In,
+}
+
+impl PrecipitationUnit {
+    /// Converts a millimetre amount into this unit.
+    pub fn from_mm(self, mm: f32) -> f32 {
+        match self {
+            PrecipitationUnit::Mm => mm,
+            PrecipitationUnit::In => mm / 25.4,
+        }
+    }
+}
+
+/// Which page the dashboard renders.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DashboardView {
+    /// Current conditions, hourly chart and 6-day strip.
+    #[default]
+    Today,
+    /// 14-day outlook grid and temperature trend.
+    Outlook,
+    /// The outlook between `outlook_start_hour` and `outlook_end_hour`,
+    /// today's view otherwise.
+    Scheduled,
+}
+
+/// The e-paper panel being drawn for; sets the canvas size and the
+/// built-in layout.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+pub enum DisplayModel {
+    /// Inky Impression 7.3", 800×480: the classic template.
+    #[default]
+    #[serde(rename = "impression_7_3")]
+    #[strum(serialize = "impression_7_3")]
+    Impression73,
+    /// Inky Impression 5.7", 600×448.
+    #[serde(rename = "impression_5_7")]
+    #[strum(serialize = "impression_5_7")]
+    Impression57,
+    /// Inky Impression 4", 640×400.
+    #[serde(rename = "impression_4")]
+    #[strum(serialize = "impression_4")]
+    Impression4,
+    /// Inky Impression 13.3", 1600×1200.
+    #[serde(rename = "impression_13_3")]
+    #[strum(serialize = "impression_13_3")]
+    Impression133,
+    /// Inky Impression 7.3" mounted portrait, 480×800.
+    #[serde(rename = "impression_7_3_portrait")]
+    #[strum(serialize = "impression_7_3_portrait")]
+    Impression73Portrait,
+}
+
+impl DisplayModel {
+    /// Canvas size in pixels, `(width, height)`.
+    pub fn canvas(self) -> (f32, f32) {
+        match self {
+            Self::Impression73 => (800.0, 480.0),
+            Self::Impression57 => (600.0, 448.0),
+            Self::Impression4 => (640.0, 400.0),
+            Self::Impression133 => (1600.0, 1200.0),
+            Self::Impression73Portrait => (480.0, 800.0),
+        }
+    }
+}
+
+/// Inks the panel can show. Panels with fewer than the Impression's seven
+/// swap colour distinctions for dash patterns, hatching and outlined icons,
+/// and `[colours]` must stay within their inks.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ColourProfile {
+    /// Any colour, dithered where the panel lacks it.
+    #[default]
+    FullColour,
+    /// Black, white and red, e.g. the red Inky wHAT.
+    BlackWhiteRed,
+    /// Black and white only.
+    BlackWhite,
+}
+
+impl ColourProfile {
+    /// The panel's inks, or `None` when it can show any colour.
+    pub fn inks(self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::FullColour => None,
+            Self::BlackWhiteRed => Some(&["black", "white", "red"]),
+            Self::BlackWhite => Some(&["black", "white"]),
+        }
+    }
+
+    pub fn is_limited(self) -> bool {
+        self.inks().is_some()
+    }
+
+    /// The panel's one colour ink, if it has one.
+    pub fn accent(self) -> Option<&'static str> {
+        match self {
+            Self::BlackWhiteRed => Some("red"),
+            Self::FullColour | Self::BlackWhite => None,
+        }
+    }
+
+    /// Whether `colour` is one of the panel's inks, by name or as hex.
+    /// Keywords like `transparent` are always allowed.
+    pub fn can_show(self, colour: &Colour) -> bool {
+        let Some(inks) = self.inks() else {
+            return true;
+        };
+        let colour = colour.to_string().to_ascii_lowercase();
+        let name = match colour.as_str() {
+            "#000" | "#000000" => "black",
+            "#fff" | "#ffffff" => "white",
+            "#f00" | "#ff0000" => "red",
+            other => other,
+        };
+        inks.contains(&name) || is_special_colour(name)
+    }
+}
+
+/// How each day card in the 6-day strip shows its temperatures.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DailyLayout {
+    /// Max and min as numbers under the icon.
+    #[default]
+    Numbers,
+    /// Min–max bar on a scale shared by the week, with precipitation below.
+    RangeBars,
+}
+
+/// How the hourly chart's temperature (left) axis picks its range.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TempAxisScale {
+    /// Fit the window's min/max exactly.
+    #[default]
+    Auto,
+    /// The current season's range from `temp_axis_fixed_ranges`, widened
+    /// only when the forecast falls outside it.
+    Fixed,
+    /// Round out to whole steps, spanning at least `temp_axis_min_span`.
+    Nice,
+}
+
+/// A temperature axis range, in `render_options.temp_unit`.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy)]
+pub struct TempRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl fmt::Display for TempRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.min, self.max)
+    }
+}
+
+/// Fixed temperature axis ranges for `TempAxisScale::Fixed`, one per
+/// meteorological season.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SeasonalTempRanges {
+    pub summer: TempRange,
+    pub autumn: TempRange,
+    pub winter: TempRange,
+    pub spring: TempRange,
+}
+
+impl SeasonalTempRanges {
+    /// Range for the meteorological season of `month` (1-12), flipped for
+    /// the southern hemisphere (negative `latitude`), where December is
+    /// summer.
+    pub fn for_month(&self, month: u32, latitude: f64) -> TempRange {
+        // Shift southern months by half a year onto the northern calendar.
+        let month = if latitude < 0.0 {
+            (month + 5) % 12 + 1
+        } else {
+            month
+        };
+        match month {
+            3..=5 => self.spring,
+            6..=8 => self.summer,
+            9..=11 => self.autumn,
+            _ => self.winter,
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&'static str, TempRange)> {
+        [
+            ("summer", self.summer),
+            ("autumn", self.autumn),
+            ("winter", self.winter),
+            ("spring", self.spring),
+        ]
+        .into_iter()
+    }
+}
+
 #[derive(Debug, Deserialize, PartialOrd, PartialEq, Clone, Copy, Display)]
 pub enum WindSpeedUnit {
     #[serde(rename = "km/h")]
@@ -162,6 +409,118 @@ pub struct Colours {
     pub feels_like_colour: Colour,
     pub rain_colour: Colour,
     pub snow_colour: Colour,
+    pub wind_colour: Colour,
+    pub night_colour: Colour,
+    pub cloud_colour: Colour,
+    pub freezing_colour: Colour,
+    /// Palette swapped in overnight; see [`NightColours`].
+    pub night: NightColours,
+}
+
+impl Colours {
+    /// Every colour with its key under `[colours]`.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Colour)> {
+        [
+            ("background_colour", &self.background_colour),
+            ("text_colour", &self.text_colour),
+            ("x_axis_colour", &self.x_axis_colour),
+            ("y_left_axis_colour", &self.y_left_axis_colour),
+            ("y_right_axis_colour", &self.y_right_axis_colour),
+            ("actual_temp_colour", &self.actual_temp_colour),
+            ("feels_like_colour", &self.feels_like_colour),
+            ("rain_colour", &self.rain_colour),
+            ("snow_colour", &self.snow_colour),
+            ("wind_colour", &self.wind_colour),
+            ("night_colour", &self.night_colour),
+            ("cloud_colour", &self.cloud_colour),
+            ("freezing_colour", &self.freezing_colour),
+        ]
+        .into_iter()
+    }
+
+    /// This palette with `[colours.night]`'s overrides applied; colours it
+    /// leaves out keep their day value.
+    pub fn for_night(&self) -> Colours {
+        let night = &self.night;
+        let pick = |override_colour: &Option<Colour>, day: &Colour| {
+            override_colour.clone().unwrap_or_else(|| day.clone())
+        };
+        Colours {
+            background_colour: pick(&night.background_colour, &self.background_colour),
+            text_colour: pick(&night.text_colour, &self.text_colour),
+            x_axis_colour: pick(&night.x_axis_colour, &self.x_axis_colour),
+            y_left_axis_colour: pick(&night.y_left_axis_colour, &self.y_left_axis_colour),
+            y_right_axis_colour: pick(&night.y_right_axis_colour, &self.y_right_axis_colour),
+            actual_temp_colour: pick(&night.actual_temp_colour, &self.actual_temp_colour),
+            feels_like_colour: pick(&night.feels_like_colour, &self.feels_like_colour),
+            rain_colour: pick(&night.rain_colour, &self.rain_colour),
+            snow_colour: pick(&night.snow_colour, &self.snow_colour),
+            wind_colour: pick(&night.wind_colour, &self.wind_colour),
+            night_colour: pick(&night.night_colour, &self.night_colour),
+            cloud_colour: pick(&night.cloud_colour, &self.cloud_colour),
+            freezing_colour: pick(&night.freezing_colour, &self.freezing_colour),
+            night: night.clone(),
+        }
+    }
+}
+
+/// When the `[colours.night]` palette replaces the day one.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum NightSchedule {
+    /// Always the day palette.
+    #[default]
+    Off,
+    /// From today's sunset until the next sunrise, per the daily forecast.
+    Sunset,
+    /// From `start_hour` until `end_hour`, local time.
+    Fixed,
+}
+
+/// Overrides for the `[colours]` palette at night. Each colour is optional
+/// and falls back to its day value.
+#[derive(Debug, Deserialize, Clone)]
+pub struct NightColours {
+    pub schedule: NightSchedule,
+    /// Local hour the fixed schedule starts (inclusive).
+    pub start_hour: HourOfDay,
+    /// Local hour the fixed schedule ends (exclusive); may wrap past midnight.
+    pub end_hour: HourOfDay,
+    pub background_colour: Option<Colour>,
+    pub text_colour: Option<Colour>,
+    pub x_axis_colour: Option<Colour>,
+    pub y_left_axis_colour: Option<Colour>,
+    pub y_right_axis_colour: Option<Colour>,
+    pub actual_temp_colour: Option<Colour>,
+    pub feels_like_colour: Option<Colour>,
+    pub rain_colour: Option<Colour>,
+    pub snow_colour: Option<Colour>,
+    pub wind_colour: Option<Colour>,
+    pub night_colour: Option<Colour>,
+    pub cloud_colour: Option<Colour>,
+    pub freezing_colour: Option<Colour>,
+}
+
+impl NightColours {
+    /// Whether the fixed schedule covers local `hour` (0–23). Always false
+    /// for the other schedules.
+    pub fn fixed_schedule_covers(&self, hour: u32) -> bool {
+        self.schedule == NightSchedule::Fixed
+            && hour_in_window(self.start_hour, self.end_hour, hour)
+    }
+}
+
+/// Whether `hour` falls in `start..end`, wrapping past midnight when `end`
+/// is earlier than `start`.
+fn hour_in_window(start: HourOfDay, end: HourOfDay, hour: u32) -> bool {
+    let start = u32::from(start.into_inner());
+    let end = u32::from(end.into_inner());
+    if start <= end {
+        (start..end).contains(&hour)
+    } else {
+        hour >= start || hour < end
+    }
 }
 
 /// Detects the system timezone, falling back to UTC and logging a warning
@@ -200,9 +559,25 @@ pub struct Misc {
     pub timezone: chrono_tz::Tz,
     pub weather_data_cache_path: PathBuf,
     pub template_path: PathBuf,
+    /// Template for the 14-day outlook page (see `render_options.view`).
+    pub outlook_template_path: PathBuf,
     pub generated_svg_name: PathBuf,
     pub generated_png_name: PathBuf,
     pub svg_icons_directory: PathBuf,
+    /// The pack in `svg_icons_directory`, loaded and checked against every
+    /// icon the dashboard can draw once the config is validated.
+    #[serde(skip)]
+    pub icon_pack: IconPack,
+    /// The outlined pack in [`OUTLINED_ICONS_DIRECTORY`], if it loads. Only
+    /// drawn from on 2- and 3-colour panels.
+    #[serde(skip)]
+    pub outlined_icon_pack: Option<IconPack>,
+    /// Inline icons and embed fonts in the generated SVG, so it renders the
+    /// same when copied elsewhere.
+    pub self_contained_svg: bool,
+    /// With `self_contained_svg`, also embed the fonts (about 1.5 MB). Off,
+    /// only the icons are inlined and viewers draw text in their own fonts.
+    pub embed_svg_fonts: bool,
 }
 
 #[nutype(
@@ -211,6 +586,42 @@ pub struct Misc {
 )]
 pub struct Opacity(f32);
 
+#[nutype(
+    validate(greater_or_equal = 1, less_or_equal = 24),
+    derive(Debug, Deserialize, Clone, Copy, PartialEq)
+)]
+pub struct WindArrowIntervalHours(u16);
+
+#[nutype(
+    validate(less_or_equal = 23),
+    derive(Debug, Deserialize, Clone, Copy, PartialEq)
+)]
+pub struct HourOfDay(u8);
+
+impl fmt::Display for HourOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:00", self.into_inner())
+    }
+}
+
+#[nutype(
+    validate(finite, greater = 0.0),
+    derive(Debug, Deserialize, Clone, Copy, PartialEq)
+)]
+pub struct TempAxisMinSpan(f32);
+
+impl fmt::Display for TempAxisMinSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.into_inner())
+    }
+}
+
+impl fmt::Display for WindArrowIntervalHours {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.into_inner())
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct RenderOptions {
     pub temp_unit: TemperatureUnit,
@@ -227,6 +638,223 @@ pub struct RenderOptions {
     pub precipitation_opacity_min: Opacity,
     /// Gradient fill opacity at 100% precipitation chance. Must be > precipitation_opacity_min.
     pub precipitation_opacity_max: Opacity,
+    /// Plot wind speed (or gust, per `use_gust_instead_of_wind`) as an extra
+    /// chart series with direction arrows.
+    pub show_wind_series: bool,
+    /// Hours between direction arrows on the wind series (1-24).
+    pub wind_arrow_interval_hours: WindArrowIntervalHours,
+    /// Background fill for the hours between sunset and sunrise.
+    pub night_shading: NightShading,
+    /// Draw hourly cloud cover as a stippled strip above the chart.
+    pub show_cloud_cover: bool,
+    /// Plot precipitation chance, expected amount, or amount with chance as opacity.
+    pub precipitation_mode: PrecipitationMode,
+    /// Unit for the precipitation amount axis (amount/both modes only).
+    pub precipitation_unit: PrecipitationUnit,
+    /// Label the highest and lowest points of the temperature curve.
+    pub show_temp_extremes: bool,
+    /// How the temperature axis range is chosen.
+    pub temp_axis_scale: TempAxisScale,
+    /// Smallest temperature axis span, in degrees, for `TempAxisScale::Nice`.
+    pub temp_axis_min_span: TempAxisMinSpan,
+    /// Per-season axis ranges for `TempAxisScale::Fixed`.
+    pub temp_axis_fixed_ranges: SeasonalTempRanges,
+    /// Highlight 0°C / 32°F on the chart when the temperature axis crosses it.
+    pub show_freezing_line: bool,
+    /// One-line forecast summary under the date.
+    pub show_summary: bool,
+    /// Show daily temperatures as numbers or as range bars.
+    pub daily_layout: DailyLayout,
+    /// Render today's dashboard, the 14-day outlook, or switch by time of day.
+    pub view: DashboardView,
+    /// Local hour the scheduled outlook starts (inclusive).
+    pub outlook_start_hour: HourOfDay,
+    /// Local hour the scheduled outlook ends (exclusive); may wrap past midnight.
+    pub outlook_end_hour: HourOfDay,
+}
+
+impl RenderOptions {
+    /// Whether the outlook page should be rendered at local `hour` (0–23).
+    pub fn shows_outlook_at(&self, hour: u32) -> bool {
+        match self.view {
+            DashboardView::Today => false,
+            DashboardView::Outlook => true,
+            DashboardView::Scheduled => {
+                hour_in_window(self.outlook_start_hour, self.outlook_end_hour, hour)
+            }
+        }
+    }
+}
+
+/// Where `[layout]` draws a named widget (see `dashboard::widgets`), in
+/// canvas pixels. Width and height default to the widget's own size;
+/// anything else scales it to fit.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct WidgetPlacement {
+    pub widget: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+}
+
+impl fmt::Display for WidgetPlacement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at ({}, {})", self.widget, self.x, self.y)?;
+        if let (Some(width), Some(height)) = (self.width, self.height) {
+            write!(f, " {width}x{height}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Layout {
+    /// Widgets composing the dashboard, drawn in order. Empty renders
+    /// `misc.template_path` instead.
+    pub widgets: Vec<WidgetPlacement>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Display {
+    pub model: DisplayModel,
+    pub profile: ColourProfile,
+}
+
+/// Encodings an extra raster output can be written in.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutputFormat {
+    Png,
+    /// 24-bit uncompressed BMP.
+    Bmp,
+    /// Binary 1-bit PBM (`P4`).
+    Pbm,
+    /// Binary 8-bit greyscale PGM (`P5`).
+    Pgm,
+    /// Raw 1 bit per pixel, 8 pixels per byte MSB first, 1 = white, each row
+    /// padded to a whole byte: the buffer Waveshare's e-paper drivers take.
+    #[serde(rename = "waveshare_1bit")]
+    #[strum(serialize = "waveshare_1bit")]
+    Waveshare1Bit,
+    /// Raw 2 bits per pixel for Waveshare's 4-grey mode, 4 pixels per byte
+    /// MSB first, `11` = white down to `00` = black.
+    #[serde(rename = "waveshare_2bit")]
+    #[strum(serialize = "waveshare_2bit")]
+    Waveshare2Bit,
+    /// Raw pixels in the pixel format of a Linux `/dev/fb*` device, written
+    /// straight to it.
+    Framebuffer,
+}
+
+/// Clockwise rotation applied to an output after rendering.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+#[serde(try_from = "u16")]
+pub enum Rotation {
+    #[default]
+    #[strum(serialize = "0")]
+    None,
+    #[strum(serialize = "90")]
+    Clockwise90,
+    #[strum(serialize = "180")]
+    Clockwise180,
+    #[strum(serialize = "270")]
+    Clockwise270,
+}
+
+impl TryFrom<u16> for Rotation {
+    type Error = String;
+
+    fn try_from(degrees: u16) -> Result<Self, Self::Error> {
+        match degrees {
+            0 => Ok(Self::None),
+            90 => Ok(Self::Clockwise90),
+            180 => Ok(Self::Clockwise180),
+            270 => Ok(Self::Clockwise270),
+            _ => Err(format!(
+                "rotation must be 0, 90, 180 or 270 degrees, got {degrees}"
+            )),
+        }
+    }
+}
+
+impl Rotation {
+    /// Whether the output is the rendered image turned on its side.
+    pub fn is_quarter_turn(self) -> bool {
+        matches!(self, Self::Clockwise90 | Self::Clockwise270)
+    }
+}
+
+/// Mirroring applied to an output after rotation.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy, Display, Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Mirror {
+    #[default]
+    None,
+    /// Flip left to right.
+    Horizontal,
+    /// Flip top to bottom.
+    Vertical,
+}
+
+/// An image written from the dashboard in addition to the PNG, e.g. for a
+/// second panel or a photo frame.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct OutputTarget {
+    pub format: OutputFormat,
+    /// File written, or the device for [`OutputFormat::Framebuffer`].
+    pub path: PathBuf,
+    /// Target resolution after rotation. Defaults to the dashboard's own
+    /// size, or the framebuffer's.
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    #[serde(default)]
+    pub rotation: Rotation,
+    #[serde(default)]
+    pub mirror: Mirror,
+}
+
+impl fmt::Display for OutputTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.format, self.path.display())?;
+        if let (Some(width), Some(height)) = (self.width, self.height) {
+            write!(f, " {width}x{height}")?;
+        }
+        if self.rotation != Rotation::None {
+            write!(f, ", rotated {}°", self.rotation)?;
+        }
+        if self.mirror != Mirror::None {
+            write!(f, ", mirrored {}", self.mirror)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Output {
+    /// Extra raster outputs, written after the PNG.
+    pub targets: Vec<OutputTarget>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Refresh {
+    /// Leaves the outputs untouched when the dashboard renders the same as
+    /// last time, exiting with [`crate::refresh::UNCHANGED_EXIT_CODE`].
+    pub skip_unchanged: bool,
+    /// Writes the outputs anyway once this many hours have passed since the
+    /// last refresh, to clear ghosting. 0 never forces a refresh.
+    pub force_every_hours: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DataAge {
+    /// Shows when the forecast was fetched ("Updated 14:05", "Data 2 d old").
+    pub show_label: bool,
+    /// Shows the stale-data diagnostic only once cached data is at least this
+    /// many hours old. 0 shows it whenever cached data is used.
+    pub stale_after_hours: u32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -243,6 +871,11 @@ pub struct DashboardSettings {
     pub colours: Colours,
     pub misc: Misc,
     pub render_options: RenderOptions,
+    pub display: Display,
+    pub layout: Layout,
+    pub output: Output,
+    pub refresh: Refresh,
+    pub data_age: DataAge,
     pub dev: Dev,
 }
 
@@ -263,6 +896,112 @@ fn validate_release_cross_fields(
     Ok(())
 }
 
+/// Validates that every season's fixed temperature axis range is non-empty.
+///
+/// Returns `Ok(())` if valid, or `Err(message)` naming the first bad season.
+fn validate_temp_axis_ranges(ranges: &SeasonalTempRanges) -> Result<(), String> {
+    for (season, range) in ranges.iter() {
+        if range.min.is_nan() || range.max.is_nan() || range.min >= range.max {
+            return Err(format!(
+                "temp_axis_fixed_ranges.{season}: min ({}) must be less than max ({})",
+                range.min, range.max
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// A scheduled outlook needs a non-empty window; `start == end` would never
+/// (or, read the other way, always) show it.
+fn validate_outlook_schedule(
+    view: DashboardView,
+    start: HourOfDay,
+    end: HourOfDay,
+) -> Result<(), String> {
+    if view == DashboardView::Scheduled && start == end {
+        return Err(format!(
+            "outlook_start_hour and outlook_end_hour must differ when view = \"scheduled\" (both {start})"
+        ));
+    }
+    Ok(())
+}
+
+/// Every placement needs a widget name and, when resized, a positive size.
+fn validate_layout(layout: &Layout) -> Result<(), String> {
+    for (index, placement) in layout.widgets.iter().enumerate() {
+        if placement.widget.trim().is_empty() {
+            return Err(format!("layout.widgets[{index}]: widget name is empty"));
+        }
+        for (field, value) in [("width", placement.width), ("height", placement.height)] {
+            if value.is_some_and(|v| v.is_nan() || v <= 0.0) {
+                return Err(format!(
+                    "layout.widgets[{index}] ({}): {field} must be positive",
+                    placement.widget
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_outputs(output: &Output) -> Result<(), String> {
+    for (index, target) in output.targets.iter().enumerate() {
+        if target.path.as_os_str().is_empty() {
+            return Err(format!("output.targets[{index}]: path is empty"));
+        }
+        if target.width.is_some() != target.height.is_some() {
+            return Err(format!(
+                "output.targets[{index}] ({}): set both width and height, or neither",
+                target.format
+            ));
+        }
+        if target.width == Some(0) || target.height == Some(0) {
+            return Err(format!(
+                "output.targets[{index}] ({}): width and height must be positive",
+                target.format
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// On a limited panel, every colour must be one of its inks.
+fn validate_colours(colours: &Colours, profile: ColourProfile) -> Result<(), String> {
+    let Some(inks) = profile.inks() else {
+        return Ok(());
+    };
+    // Day colours first, so a night colour falling back to a bad day one is
+    // reported under its day key.
+    let night = colours.for_night();
+    let unshowable = colours
+        .iter()
+        .map(|(key, colour)| (String::new(), key, colour))
+        .chain(
+            night
+                .iter()
+                .map(|(key, colour)| ("night.".to_string(), key, colour)),
+        )
+        .find(|(_, _, colour)| !profile.can_show(colour));
+    match unshowable {
+        Some((table, key, colour)) => Err(format!(
+            "colours.{table}{key} = \"{colour}\" can't be shown with display.profile = \"{profile}\" (inks: {})",
+            inks.join(", ")
+        )),
+        None => Ok(()),
+    }
+}
+
+/// A fixed night schedule needs a non-empty window, as for the outlook.
+fn validate_night_schedule(night: &NightColours) -> Result<(), String> {
+    if night.schedule == NightSchedule::Fixed && night.start_hour == night.end_hour {
+        return Err(format!(
+            "colours.night.start_hour and end_hour must differ when schedule = \"fixed\" (both {})",
+            night.start_hour
+        ));
+    }
+    Ok(())
+}
+
 /// Which config layer to merge on top of `default.toml`, selected by `RUN_MODE`.
 enum ConfigLayer {
     /// `development.toml` + `local.toml` (local dev overrides, not checked into git).
@@ -271,6 +1010,80 @@ enum ConfigLayer {
     Test,
 }
 
+/// The config layer `RUN_MODE` selects: `test` for the deterministic test
+/// layer, anything else for development.
+fn run_mode_layer() -> ConfigLayer {
+    let run_mode = env::var("RUN_MODE").unwrap_or_else(|_| "development".into());
+    if run_mode == "test" {
+        ConfigLayer::Test
+    } else {
+        ConfigLayer::Development
+    }
+}
+
+/// Shared source-composition pipeline behind [`DashboardSettings::load`],
+/// [`DashboardSettings::load_unvalidated`] and
+/// [`DashboardSettings::load_test_config`], so they never drift on how a
+/// config layer is merged in — only on whether the user config file and
+/// `APP_*` env are included.
+fn compose_sources(layer: ConfigLayer, include_user_and_env: bool) -> Result<Config, ConfigError> {
+    let root = std::env::current_dir().map_err(|e| ConfigError::Message(e.to_string()))?;
+    let default_config_path = root.join(CONFIG_DIR).join(DEFAULT_CONFIG_NAME);
+
+    let mut config_builder =
+        Config::builder().add_source(File::with_name(default_config_path.to_str().unwrap()));
+
+    if include_user_and_env {
+        // user config path is located at ~/.config/pi-inky-weather-epd.toml
+        match env::var("HOME") {
+            Ok(home_dir) => {
+                let user_config_path = std::path::PathBuf::from(&home_dir)
+                    .join(".config")
+                    .join(env!("CARGO_PKG_NAME"));
+                config_builder = config_builder.add_source(
+                    File::with_name(user_config_path.to_str().unwrap()).required(false),
+                );
+            }
+            Err(_) => {
+                crate::logger::warning(
+                    "HOME environment variable not set; skipping user config file (~/.config/...)",
+                );
+            }
+        }
+    }
+
+    config_builder = match layer {
+        ConfigLayer::Test => {
+            let test_config_path = root.join(CONFIG_DIR).join("test");
+            config_builder
+                .add_source(File::with_name(test_config_path.to_str().unwrap()).required(false))
+        }
+        ConfigLayer::Development => {
+            let development_config_path = root.join(CONFIG_DIR).join("development");
+            let local_config_path = root.join(CONFIG_DIR).join("local");
+            config_builder
+                .add_source(
+                    File::with_name(development_config_path.to_str().unwrap()).required(false),
+                )
+                .add_source(File::with_name(local_config_path.to_str().unwrap()).required(false))
+        }
+    };
+
+    if include_user_and_env {
+        // Add in settings from the environment (with a prefix of APP)
+        // Eg.. `APP_API__PROVIDER=open_meteo` would set the `api.provider` key
+        // Note: Single underscore _ separates prefix from key, double __ for nesting
+        config_builder = config_builder.add_source(
+            Environment::with_prefix("APP")
+                .prefix_separator("_") // Separator between prefix and key (APP_api)
+                .separator("__") // Separator for nested keys (api__provider)
+                .try_parsing(true), // Parse values to correct types
+        );
+    }
+
+    config_builder.build()
+}
+
 /// Dashboard settings.
 ///
 /// # Fields
@@ -298,13 +1111,14 @@ impl DashboardSettings {
     /// uses, but — unlike `load_test_config` — still merges the user config file
     /// and `APP_*` environment variables on top of it.
     pub fn load() -> Result<Self, ConfigError> {
-        let run_mode = env::var("RUN_MODE").unwrap_or_else(|_| "development".into());
-        let layer = if run_mode == "test" {
-            ConfigLayer::Test
-        } else {
-            ConfigLayer::Development
-        };
-        Self::load_from_sources(layer, /* include_user_and_env */ true)
+        Self::load_from_sources(run_mode_layer(), /* include_user_and_env */ true)
+    }
+
+    /// Loads the same sources as [`Self::load`] but only deserializes them,
+    /// skipping validation and the icon pack. For when `load` has failed and
+    /// the error screen still needs the display model and output paths.
+    pub fn load_unvalidated() -> Result<Self, ConfigError> {
+        compose_sources(run_mode_layer(), /* include_user_and_env */ true)?.try_deserialize()
     }
 
     /// Load configuration for tests: `default.toml` merged with `test.toml` only.
@@ -322,72 +1136,11 @@ impl DashboardSettings {
         Self::load_from_sources(ConfigLayer::Test, /* include_user_and_env */ false)
     }
 
-    /// Shared source-composition pipeline behind [`Self::load`] and
-    /// [`Self::load_test_config`], so the two never drift on how a config
-    /// layer is merged in — only on whether the user config file and `APP_*`
-    /// env are included.
     fn load_from_sources(
         layer: ConfigLayer,
         include_user_and_env: bool,
     ) -> Result<Self, ConfigError> {
-        let root = std::env::current_dir().map_err(|e| ConfigError::Message(e.to_string()))?;
-        let default_config_path = root.join(CONFIG_DIR).join(DEFAULT_CONFIG_NAME);
-
-        let mut config_builder =
-            Config::builder().add_source(File::with_name(default_config_path.to_str().unwrap()));
-
-        if include_user_and_env {
-            // user config path is located at ~/.config/pi-inky-weather-epd.toml
-            match env::var("HOME") {
-                Ok(home_dir) => {
-                    let user_config_path = std::path::PathBuf::from(&home_dir)
-                        .join(".config")
-                        .join(env!("CARGO_PKG_NAME"));
-                    config_builder = config_builder.add_source(
-                        File::with_name(user_config_path.to_str().unwrap()).required(false),
-                    );
-                }
-                Err(_) => {
-                    crate::logger::warning(
-                        "HOME environment variable not set; skipping user config file (~/.config/...)",
-                    );
-                }
-            }
-        }
-
-        config_builder = match layer {
-            ConfigLayer::Test => {
-                let test_config_path = root.join(CONFIG_DIR).join("test");
-                config_builder
-                    .add_source(File::with_name(test_config_path.to_str().unwrap()).required(false))
-            }
-            ConfigLayer::Development => {
-                let development_config_path = root.join(CONFIG_DIR).join("development");
-                let local_config_path = root.join(CONFIG_DIR).join("local");
-                config_builder
-                    .add_source(
-                        File::with_name(development_config_path.to_str().unwrap()).required(false),
-                    )
-                    .add_source(
-                        File::with_name(local_config_path.to_str().unwrap()).required(false),
-                    )
-            }
-        };
-
-        if include_user_and_env {
-            // Add in settings from the environment (with a prefix of APP)
-            // Eg.. `APP_API__PROVIDER=open_meteo` would set the `api.provider` key
-            // Note: Single underscore _ separates prefix from key, double __ for nesting
-            config_builder = config_builder.add_source(
-                Environment::with_prefix("APP")
-                    .prefix_separator("_") // Separator between prefix and key (APP_api)
-                    .separator("__") // Separator for nested keys (api__provider)
-                    .try_parsing(true), // Parse values to correct types
-            );
-        }
-
-        let settings = config_builder.build()?;
-        Self::deserialize_and_validate(settings)
+        Self::deserialize_and_validate(compose_sources(layer, include_user_and_env)?)
     }
 
     fn deserialize_and_validate(settings: Config) -> Result<Self, ConfigError> {
@@ -411,6 +1164,34 @@ impl DashboardSettings {
                 return Err(ConfigError::Message(msg));
             }
 
+            if let Err(msg) = validate_temp_axis_ranges(&s.render_options.temp_axis_fixed_ranges) {
+                return Err(ConfigError::Message(msg));
+            }
+
+            if let Err(msg) = validate_outlook_schedule(
+                s.render_options.view,
+                s.render_options.outlook_start_hour,
+                s.render_options.outlook_end_hour,
+            ) {
+                return Err(ConfigError::Message(msg));
+            }
+
+            if let Err(msg) = validate_layout(&s.layout) {
+                return Err(ConfigError::Message(msg));
+            }
+
+            if let Err(msg) = validate_outputs(&s.output) {
+                return Err(ConfigError::Message(msg));
+            }
+
+            if let Err(msg) = validate_colours(&s.colours, s.display.profile) {
+                return Err(ConfigError::Message(msg));
+            }
+
+            if let Err(msg) = validate_night_schedule(&s.colours.night) {
+                return Err(ConfigError::Message(msg));
+            }
+
             let omin = s.render_options.precipitation_opacity_min.into_inner();
             let omax = s.render_options.precipitation_opacity_max.into_inner();
             if omin >= omax {
@@ -420,7 +1201,16 @@ impl DashboardSettings {
             }
         }
 
-        final_settings
+        let mut settings = final_settings?;
+        settings.misc.icon_pack =
+            IconPack::load(&settings.misc.svg_icons_directory).map_err(ConfigError::Message)?;
+        settings
+            .misc
+            .icon_pack
+            .validate()
+            .map_err(ConfigError::Message)?;
+        settings.misc.outlined_icon_pack = IconPack::load(Path::new(OUTLINED_ICONS_DIRECTORY)).ok();
+        Ok(settings)
     }
 
     /// Print configuration settings in a structured, hierarchical format
@@ -473,6 +1263,34 @@ impl DashboardSettings {
             "Prefer Weather Codes",
             self.render_options.prefer_weather_codes,
         );
+        logger::kvp("Show Wind Series", self.render_options.show_wind_series);
+        logger::kvp(
+            "Wind Arrow Interval (hours)",
+            self.render_options.wind_arrow_interval_hours,
+        );
+        logger::kvp("Night Shading", self.render_options.night_shading);
+        logger::kvp("Show Cloud Cover", self.render_options.show_cloud_cover);
+        logger::kvp("Precipitation Mode", self.render_options.precipitation_mode);
+        logger::kvp("Precipitation Unit", self.render_options.precipitation_unit);
+        logger::kvp("Show Temp Extremes", self.render_options.show_temp_extremes);
+        logger::kvp("Temp Axis Scale", self.render_options.temp_axis_scale);
+        logger::kvp("Temp Axis Min Span", self.render_options.temp_axis_min_span);
+        for (season, range) in self.render_options.temp_axis_fixed_ranges.iter() {
+            logger::kvp(format!("Temp Axis Range ({season})"), range);
+        }
+        logger::kvp("Show Freezing Line", self.render_options.show_freezing_line);
+        logger::kvp("Show Summary", self.render_options.show_summary);
+        logger::kvp("Daily Layout", self.render_options.daily_layout);
+        logger::kvp("View", self.render_options.view);
+        if self.render_options.view == DashboardView::Scheduled {
+            logger::kvp(
+                "Outlook Hours",
+                format!(
+                    "{} - {}",
+                    self.render_options.outlook_start_hour, self.render_options.outlook_end_hour
+                ),
+            );
+        }
 
         // Colours
         logger::config_group("Display Colours");
@@ -485,14 +1303,67 @@ impl DashboardSettings {
         logger::kvp("Feels Like", &self.colours.feels_like_colour);
         logger::kvp("Rain", &self.colours.rain_colour);
         logger::kvp("Snow", &self.colours.snow_colour);
+        logger::kvp("Wind", &self.colours.wind_colour);
+        logger::kvp("Night", &self.colours.night_colour);
+        logger::kvp("Cloud Cover", &self.colours.cloud_colour);
+        logger::kvp("Freezing Line", &self.colours.freezing_colour);
+        logger::kvp("Night Palette", self.colours.night.schedule);
+        if self.colours.night.schedule == NightSchedule::Fixed {
+            logger::kvp(
+                "Night Hours",
+                format!(
+                    "{} - {}",
+                    self.colours.night.start_hour, self.colours.night.end_hour
+                ),
+            );
+        }
 
         // File Paths
         logger::config_group("File Paths");
         logger::kvp("Cache Path", self.misc.weather_data_cache_path.display());
         logger::kvp("Template", self.misc.template_path.display());
+        logger::kvp(
+            "Outlook Template",
+            self.misc.outlook_template_path.display(),
+        );
         logger::kvp("Output SVG", self.misc.generated_svg_name.display());
         logger::kvp("Output PNG", self.misc.generated_png_name.display());
         logger::kvp("Icons Directory", self.misc.svg_icons_directory.display());
+        logger::kvp("Icon Pack", &self.misc.icon_pack.name);
+        logger::kvp("Self-contained SVG", self.misc.self_contained_svg);
+        logger::kvp("Embed SVG fonts", self.misc.embed_svg_fonts);
+
+        // Display and layout
+        logger::config_group("Display");
+        let (width, height) = self.display.model.canvas();
+        logger::kvp(
+            "Model",
+            format!("{} ({width}x{height})", self.display.model),
+        );
+        logger::kvp("Profile", self.display.profile);
+        logger::config_group("Layout");
+        if self.layout.widgets.is_empty() {
+            logger::kvp("Widgets", "none (using template)");
+        }
+        for placement in &self.layout.widgets {
+            logger::kvp("Widget", placement);
+        }
+        if !self.output.targets.is_empty() {
+            logger::config_group("Extra Outputs");
+            for target in &self.output.targets {
+                logger::kvp("Output", target);
+            }
+        }
+
+        logger::config_group("Refresh");
+        logger::kvp("Skip Unchanged", self.refresh.skip_unchanged);
+        if self.refresh.skip_unchanged {
+            logger::kvp("Force Every (hours)", self.refresh.force_every_hours);
+        }
+
+        logger::config_group("Data Age");
+        logger::kvp("Show Label", self.data_age.show_label);
+        logger::kvp("Stale After (hours)", self.data_age.stale_after_hours);
 
         // Release/Update Settings
         logger::config_group("Update Settings");
@@ -521,7 +1392,71 @@ impl DashboardSettings {
 
 #[cfg(test)]
 mod tests {
-    use super::{validate_release_cross_fields, UpdateIntervalDays};
+    use super::{
+        validate_colours, validate_layout, validate_night_schedule, validate_outlook_schedule,
+        validate_outputs, validate_release_cross_fields, validate_temp_axis_ranges, Colour,
+        ColourProfile, Colours, DashboardSettings, DashboardView, DisplayModel, HourOfDay, Layout,
+        Mirror, NightSchedule, Output, OutputFormat, OutputTarget, RenderOptions, Rotation,
+        SeasonalTempRanges, TempRange, UpdateIntervalDays, WidgetPlacement,
+    };
+
+    fn seasonal_ranges() -> SeasonalTempRanges {
+        let range = |min, max| TempRange { min, max };
+        SeasonalTempRanges {
+            summer: range(10.0, 40.0),
+            autumn: range(0.0, 30.0),
+            winter: range(-10.0, 20.0),
+            spring: range(5.0, 25.0),
+        }
+    }
+
+    mod outputs {
+        use super::*;
+
+        fn target(width: Option<u32>, height: Option<u32>) -> Output {
+            Output {
+                targets: vec![OutputTarget {
+                    format: OutputFormat::Waveshare1Bit,
+                    path: "dashboard.bin".into(),
+                    width,
+                    height,
+                    rotation: Rotation::None,
+                    mirror: Mirror::None,
+                }],
+            }
+        }
+
+        #[test]
+        fn size_is_optional() {
+            assert!(validate_outputs(&target(None, None)).is_ok());
+            assert!(validate_outputs(&target(Some(800), Some(480))).is_ok());
+        }
+
+        #[test]
+        fn width_without_height_is_rejected() {
+            let msg = validate_outputs(&target(Some(800), None)).unwrap_err();
+            assert!(msg.contains("output.targets[0] (waveshare_1bit)"));
+        }
+
+        #[test]
+        fn zero_size_is_rejected() {
+            assert!(validate_outputs(&target(Some(0), Some(480))).is_err());
+        }
+
+        #[test]
+        fn rotation_is_given_in_degrees() {
+            assert_eq!(Rotation::try_from(270), Ok(Rotation::Clockwise270));
+            assert!(Rotation::try_from(45).is_err());
+            assert_eq!(Rotation::Clockwise90.to_string(), "90");
+        }
+    }
+
+    #[test]
+    fn icon_pack_is_loaded_and_validated_with_the_config() {
+        let settings = DashboardSettings::load_test_config().unwrap();
+        assert_eq!(settings.misc.icon_pack.name, "Meteocons (fill)");
+        assert!(settings.misc.icon_pack.icons.contains("not-available.svg"));
+    }
 
     #[test]
     fn allow_pre_release_with_zero_interval_is_rejected() {
@@ -564,4 +1499,284 @@ mod tests {
             super::HourFormat::TwentyFour
         );
     }
+
+    #[test]
+    fn precipitation_mode_and_unit_deserialize_documented_values() {
+        for (raw, expected) in [
+            ("\"chance\"", super::PrecipitationMode::Chance),
+            ("\"amount\"", super::PrecipitationMode::Amount),
+            ("\"both\"", super::PrecipitationMode::Both),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<super::PrecipitationMode>(raw).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(
+            serde_json::from_str::<super::PrecipitationUnit>("\"in\"").unwrap(),
+            super::PrecipitationUnit::In
+        );
+        assert!((super::PrecipitationUnit::In.from_mm(25.4) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn night_shading_deserializes_documented_values() {
+        for (raw, expected) in [
+            ("\"none\"", super::NightShading::None),
+            ("\"solid\"", super::NightShading::Solid),
+            ("\"hatched\"", super::NightShading::Hatched),
+            ("\"dotted\"", super::NightShading::Dotted),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<super::NightShading>(raw).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn temp_axis_scale_deserializes_documented_values() {
+        for (raw, expected) in [
+            ("\"auto\"", super::TempAxisScale::Auto),
+            ("\"fixed\"", super::TempAxisScale::Fixed),
+            ("\"nice\"", super::TempAxisScale::Nice),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<super::TempAxisScale>(raw).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn daily_layout_deserializes_documented_values() {
+        for (raw, expected) in [
+            ("\"numbers\"", super::DailyLayout::Numbers),
+            ("\"range_bars\"", super::DailyLayout::RangeBars),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<super::DailyLayout>(raw).unwrap(),
+                expected
+            );
+        }
+    }
+
+    fn outlook_options(view: DashboardView, start: u8, end: u8) -> RenderOptions {
+        let mut options = DashboardSettings::load_test_config()
+            .unwrap()
+            .render_options;
+        options.view = view;
+        options.outlook_start_hour = HourOfDay::try_new(start).unwrap();
+        options.outlook_end_hour = HourOfDay::try_new(end).unwrap();
+        options
+    }
+
+    #[test]
+    fn scheduled_outlook_window_wraps_past_midnight() {
+        let overnight = outlook_options(DashboardView::Scheduled, 20, 6);
+        for (hour, expected) in [
+            (19, false),
+            (20, true),
+            (23, true),
+            (0, true),
+            (5, true),
+            (6, false),
+        ] {
+            assert_eq!(overnight.shows_outlook_at(hour), expected, "hour {hour}");
+        }
+        let daytime = outlook_options(DashboardView::Scheduled, 9, 17);
+        assert!(!daytime.shows_outlook_at(8));
+        assert!(daytime.shows_outlook_at(9));
+        assert!(!daytime.shows_outlook_at(17));
+
+        assert!(outlook_options(DashboardView::Outlook, 20, 6).shows_outlook_at(12));
+        assert!(!outlook_options(DashboardView::Today, 20, 6).shows_outlook_at(22));
+    }
+
+    #[test]
+    fn empty_outlook_schedule_is_rejected() {
+        let hour = |h| HourOfDay::try_new(h).unwrap();
+        assert!(validate_outlook_schedule(DashboardView::Scheduled, hour(20), hour(6)).is_ok());
+        assert!(validate_outlook_schedule(DashboardView::Today, hour(6), hour(6)).is_ok());
+        let msg =
+            validate_outlook_schedule(DashboardView::Scheduled, hour(6), hour(6)).unwrap_err();
+        assert!(msg.contains("06:00"), "{msg}");
+        assert!(HourOfDay::try_new(24).is_err());
+    }
+
+    #[test]
+    fn layout_is_empty_by_default() {
+        let settings = DashboardSettings::load_test_config().unwrap();
+        assert!(settings.layout.widgets.is_empty());
+    }
+
+    #[test]
+    fn display_model_defaults_to_the_7_3_panel() {
+        let settings = DashboardSettings::load_test_config().unwrap();
+        assert_eq!(settings.display.model, DisplayModel::Impression73);
+        assert_eq!(settings.display.model.canvas(), (800.0, 480.0));
+        assert_eq!(DisplayModel::Impression73Portrait.canvas(), (480.0, 800.0));
+    }
+
+    #[test]
+    fn layout_placements_need_a_name_and_positive_size() {
+        let placement = |widget: &str, width| WidgetPlacement {
+            widget: widget.to_string(),
+            x: 0.0,
+            y: 0.0,
+            width,
+            height: None,
+        };
+        let layout = |widgets| Layout { widgets };
+
+        assert!(validate_layout(&layout(vec![placement("hourly_chart", None)])).is_ok());
+        assert!(validate_layout(&layout(vec![placement("hourly_chart", Some(300.0))])).is_ok());
+        let msg = validate_layout(&layout(vec![placement(" ", None)])).unwrap_err();
+        assert!(msg.contains("layout.widgets[0]"), "{msg}");
+        let msg = validate_layout(&layout(vec![
+            placement("daily_strip", None),
+            placement("hourly_chart", Some(0.0)),
+        ]))
+        .unwrap_err();
+        assert!(msg.contains("[1] (hourly_chart): width"), "{msg}");
+    }
+
+    #[test]
+    fn seasons_follow_the_hemisphere() {
+        let ranges = seasonal_ranges();
+        // Melbourne: January is summer, July is winter.
+        assert_eq!(ranges.for_month(1, -37.8), ranges.summer);
+        assert_eq!(ranges.for_month(7, -37.8), ranges.winter);
+        assert_eq!(ranges.for_month(4, -37.8), ranges.autumn);
+        assert_eq!(ranges.for_month(10, -37.8), ranges.spring);
+        // New York: the other way round.
+        assert_eq!(ranges.for_month(1, 40.7), ranges.winter);
+        assert_eq!(ranges.for_month(7, 40.7), ranges.summer);
+        assert_eq!(ranges.for_month(4, 40.7), ranges.spring);
+        assert_eq!(ranges.for_month(10, 40.7), ranges.autumn);
+        assert_eq!(ranges.for_month(12, 40.7), ranges.winter);
+        assert_eq!(ranges.for_month(12, -37.8), ranges.summer);
+    }
+
+    #[test]
+    fn inverted_temp_axis_range_is_rejected() {
+        assert!(validate_temp_axis_ranges(&seasonal_ranges()).is_ok());
+        let mut ranges = seasonal_ranges();
+        ranges.winter = TempRange {
+            min: 20.0,
+            max: 20.0,
+        };
+        let msg = validate_temp_axis_ranges(&ranges).unwrap_err();
+        assert!(msg.contains("temp_axis_fixed_ranges.winter"), "{msg}");
+    }
+
+    #[test]
+    fn colour_profile_defaults_to_full_colour() {
+        let settings = DashboardSettings::load_test_config().unwrap();
+        assert_eq!(settings.display.profile, ColourProfile::FullColour);
+        assert!(validate_colours(&settings.colours, ColourProfile::FullColour).is_ok());
+    }
+
+    /// The test palette redrawn in black on white.
+    fn ink_colours() -> Colours {
+        let colour = |value: &str| Colour::try_new(value).unwrap();
+        let mut colours = DashboardSettings::load_test_config().unwrap().colours;
+        colours.background_colour = colour("#FFFFFF");
+        for field in [
+            &mut colours.text_colour,
+            &mut colours.x_axis_colour,
+            &mut colours.y_left_axis_colour,
+            &mut colours.y_right_axis_colour,
+            &mut colours.actual_temp_colour,
+            &mut colours.feels_like_colour,
+            &mut colours.rain_colour,
+            &mut colours.snow_colour,
+            &mut colours.wind_colour,
+            &mut colours.night_colour,
+            &mut colours.cloud_colour,
+            &mut colours.freezing_colour,
+        ] {
+            *field = colour("black");
+        }
+        colours
+    }
+
+    #[test]
+    fn limited_profiles_reject_colours_outside_their_inks() {
+        let colour = |value: &str| Colour::try_new(value).unwrap();
+        let mut colours = ink_colours();
+        colours.actual_temp_colour = colour("#f00");
+        assert!(validate_colours(&colours, ColourProfile::BlackWhiteRed).is_ok());
+        let msg = validate_colours(&colours, ColourProfile::BlackWhite).unwrap_err();
+        assert!(
+            msg.contains(r##"colours.actual_temp_colour = "#f00""##),
+            "{msg}"
+        );
+
+        colours.actual_temp_colour = colour("black");
+        assert!(validate_colours(&colours, ColourProfile::BlackWhite).is_ok());
+        colours.wind_colour = colour("blue");
+        let msg = validate_colours(&colours, ColourProfile::BlackWhiteRed).unwrap_err();
+        assert!(msg.contains(r#"colours.wind_colour = "blue""#), "{msg}");
+        assert!(msg.contains("(inks: black, white, red)"), "{msg}");
+        assert!(validate_colours(&colours, ColourProfile::FullColour).is_ok());
+    }
+
+    #[test]
+    fn night_palette_is_off_by_default() {
+        let colours = DashboardSettings::load_test_config().unwrap().colours;
+        assert_eq!(colours.night.schedule, NightSchedule::Off);
+        assert!(!colours.night.fixed_schedule_covers(23));
+        // With no overrides, night is the day palette.
+        assert_eq!(
+            colours.for_night().iter().collect::<Vec<_>>(),
+            colours.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn night_overrides_replace_only_their_colours() {
+        let mut colours = DashboardSettings::load_test_config().unwrap().colours;
+        colours.night.background_colour = Some(Colour::try_new("black").unwrap());
+        let night = colours.for_night();
+        assert_eq!(night.background_colour.to_string(), "black");
+        assert_eq!(night.text_colour, colours.text_colour);
+        assert_eq!(night.rain_colour, colours.rain_colour);
+    }
+
+    #[test]
+    fn fixed_night_schedule_wraps_past_midnight() {
+        let hour = |h| HourOfDay::try_new(h).unwrap();
+        let mut night = DashboardSettings::load_test_config().unwrap().colours.night;
+        night.schedule = NightSchedule::Fixed;
+        night.start_hour = hour(19);
+        night.end_hour = hour(7);
+        for (h, expected) in [(18, false), (19, true), (0, true), (6, true), (7, false)] {
+            assert_eq!(night.fixed_schedule_covers(h), expected, "hour {h}");
+        }
+        assert!(validate_night_schedule(&night).is_ok());
+
+        night.end_hour = hour(19);
+        let msg = validate_night_schedule(&night).unwrap_err();
+        assert!(msg.contains("colours.night.start_hour"), "{msg}");
+        night.schedule = NightSchedule::Sunset;
+        assert!(validate_night_schedule(&night).is_ok());
+    }
+
+    #[test]
+    fn limited_profiles_check_night_colours_too() {
+        let colour = |value: &str| Colour::try_new(value).unwrap();
+        let mut colours = ink_colours();
+        colours.night.background_colour = Some(colour("black"));
+        colours.night.text_colour = Some(colour("white"));
+        assert!(validate_colours(&colours, ColourProfile::BlackWhite).is_ok());
+
+        colours.night.wind_colour = Some(colour("orange"));
+        let msg = validate_colours(&colours, ColourProfile::BlackWhiteRed).unwrap_err();
+        assert!(
+            msg.starts_with(r#"colours.night.wind_colour = "orange""#),
+            "{msg}"
+        );
+        assert!(validate_colours(&colours, ColourProfile::FullColour).is_ok());
+    }
 }
@@ -321,7 +321,7 @@ fn is_hsla_colour(colour: &str) -> bool {
     }
 }
 
-fn is_special_colour(colour: &str) -> bool {
+pub fn is_special_colour(colour: &str) -> bool {
     SPECIAL_COLOURS.contains(&colour)
 }
 pub fn is_valid_colour(colour: &str) -> Result<(), ValidationError> {
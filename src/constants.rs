@@ -1,5 +1,6 @@
 use crate::configs::settings::{DashboardSettings, TemperatureUnit};
 use crate::utils::encode;
+use crate::weather::icons::outlined_icon_pack;
 use std::path::PathBuf;
 use url::Url;
 
@@ -11,6 +12,10 @@ pub const HOURLY_CACHE_SUFFIX: &str = "hourly_forecast.json";
 pub const DAILY_CACHE_SUFFIX: &str = "daily_forecast.json";
 pub const CACHE_SUFFIX: &str = "forecast.json";
 
+/// The bundled outlined icon pack, which 2- and 3-colour panels draw their
+/// icons from wherever it has them.
+pub const OUTLINED_ICONS_DIRECTORY: &str = "static/line-svg-static/";
+
 fn build_forecast_url(settings: &DashboardSettings, frequency: &str) -> Url {
     let mut u = settings.api.bom_base_url.clone();
 
@@ -46,7 +51,7 @@ pub fn open_meteo_hourly_endpoint(settings: &DashboardSettings) -> Url {
         "{}/v1/forecast?\
         latitude={}&\
         longitude={}&\
-        hourly=temperature_2m,apparent_temperature,precipitation_probability,precipitation,uv_index,wind_speed_10m,wind_gusts_10m,relative_humidity_2m,snowfall,cloud_cover,weather_code,is_day&\
+        hourly=temperature_2m,apparent_temperature,precipitation_probability,precipitation,uv_index,wind_speed_10m,wind_gusts_10m,wind_direction_10m,relative_humidity_2m,snowfall,cloud_cover,weather_code,is_day&\
         current=is_day&\
         forecast_days=14&\
         timezone=UTC",
@@ -88,7 +93,15 @@ pub fn open_meteo_daily_endpoint(settings: &DashboardSettings) -> Url {
 }
 
 pub fn not_available_icon_path(settings: &DashboardSettings) -> PathBuf {
-    settings.misc.svg_icons_directory.join("not-available.svg")
+    let name = "not-available.svg";
+    outlined_icon_pack(settings)
+        .and_then(|pack| pack.path_of(name))
+        .unwrap_or_else(|| {
+            settings
+                .misc
+                .svg_icons_directory
+                .join(settings.misc.icon_pack.resolve(name))
+        })
 }
 
 #[cfg(test)]
@@ -148,6 +161,7 @@ mod tests {
         assert!(query.contains("longitude=144.9631"));
         assert!(query.contains("timezone=UTC"));
         assert!(query.contains("hourly=temperature_2m"));
+        assert!(query.contains("wind_direction_10m"));
     }
 
     #[test]
@@ -1,10 +1,14 @@
 use crate::{
     clock::Clock,
-    configs::settings::HourFormat,
+    configs::settings::{
+        ColourProfile, HourFormat, NightShading, PrecipitationMode, PrecipitationUnit,
+        TempAxisScale, TempRange,
+    },
     constants::DEFAULT_AXIS_LABEL_FONT_SIZE,
+    domain::weather_code::WmoWeatherCode,
     i18n::{weekday_long, Language},
     logger,
-    utils::{measure_ink_y_center, weekday_after_days},
+    utils::{measure_ink_y_center, measure_text_width, weekday_after_days},
     weather::icons::UVIndexIcon,
 };
 use anyhow::Error;
@@ -12,7 +16,7 @@ use chrono::Weekday;
 use std::fmt;
 use strum_macros::Display;
 
-#[derive(Clone, Debug, Copy)]
+#[derive(Clone, Debug, Copy, PartialEq)]
 pub struct Point {
     pub x: f32,
     pub y: f32,
@@ -40,10 +44,14 @@ impl Curve {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PrecipitationPattern {
     Snow,
     Rain,
+    Drizzle,
+    FreezingRain,
+    Hail,
+    Thunderstorm,
 }
 
 impl fmt::Display for PrecipitationPattern {
@@ -51,6 +59,108 @@ impl fmt::Display for PrecipitationPattern {
         match self {
             PrecipitationPattern::Snow => write!(f, "snow"),
             PrecipitationPattern::Rain => write!(f, "rain"),
+            PrecipitationPattern::Drizzle => write!(f, "drizzle"),
+            PrecipitationPattern::FreezingRain => write!(f, "freezing rain"),
+            PrecipitationPattern::Hail => write!(f, "hail"),
+            PrecipitationPattern::Thunderstorm => write!(f, "thunderstorm"),
+        }
+    }
+}
+
+/// Placement parameters for one pattern's glyphs inside a precipitation block.
+struct GlyphLayout {
+    /// Block area (px²) per glyph at 100% chance; larger means sparser.
+    density_div: f32,
+    max_count: u32,
+    /// Half-extent of the glyph, kept clear of open block edges.
+    r_x: f32,
+    r_y: f32,
+    /// Minimum centre-to-centre distance to any other glyph.
+    separation: (f32, f32),
+}
+
+impl PrecipitationPattern {
+    /// Patterns that aren't self-explanatory and get a legend entry, in the
+    /// order the legend lists them.
+    pub const LEGEND_ORDER: [PrecipitationPattern; 4] = [
+        PrecipitationPattern::Thunderstorm,
+        PrecipitationPattern::Hail,
+        PrecipitationPattern::FreezingRain,
+        PrecipitationPattern::Drizzle,
+    ];
+
+    fn layout(self) -> GlyphLayout {
+        let (density_div, max_count, r_x, r_y, separation) = match self {
+            PrecipitationPattern::Snow => (150.0, 25, 4.0, 4.0, (8.0, 8.0)),
+            PrecipitationPattern::Rain => (80.0, 30, 4.0, 9.0, (7.0, 14.0)),
+            PrecipitationPattern::Drizzle => (40.0, 45, 2.0, 2.0, (5.0, 5.0)),
+            PrecipitationPattern::FreezingRain => (90.0, 25, 4.0, 9.0, (8.0, 14.0)),
+            PrecipitationPattern::Hail => (150.0, 25, 4.0, 4.0, (9.0, 9.0)),
+            PrecipitationPattern::Thunderstorm => (250.0, 8, 5.0, 9.0, (14.0, 20.0)),
+        };
+        GlyphLayout {
+            density_div,
+            max_count,
+            r_x,
+            r_y,
+            separation,
+        }
+    }
+
+    /// Minimum separation between a glyph of `self` and one of `other`.
+    ///
+    /// Snowflakes keep their historical rule — the snowflake separation
+    /// applies whenever either glyph is one — otherwise the larger extent on
+    /// each axis wins.
+    fn separation_from(self, other: PrecipitationPattern) -> (f32, f32) {
+        if self == PrecipitationPattern::Snow || other == PrecipitationPattern::Snow {
+            return PrecipitationPattern::Snow.layout().separation;
+        }
+        let (ax, ay) = self.layout().separation;
+        let (bx, by) = other.layout().separation;
+        (ax.max(bx), ay.max(by))
+    }
+
+    /// One white glyph centred on (`x`, `y`) in the precipitation group's
+    /// flipped (y-up) space. Snowflakes draw their radius from `seed`.
+    fn glyph_svg(self, x: f32, y: f32, seed: &mut u64) -> String {
+        match self {
+            PrecipitationPattern::Snow => {
+                *seed = lcg_next(*seed);
+                let radius = 2.0 + (*seed as f32 / u64::MAX as f32) * 1.5;
+                format!(
+                    r#"<circle cx="{x:.2}" cy="{y:.2}" r="{radius:.1}" fill="white" fill-opacity="0.85"/>"#
+                )
+            }
+            PrecipitationPattern::Rain => format!(
+                r#"<path d="M-1,1 C-1,0.45 -0.55,0 0,0 C0.55,0 1,0.45 1,1 L1,8 C1,8.55 0.55,9 0,9 C-0.55,9 -1,8.55 -1,8 Z" fill="white" fill-opacity="0.8" transform="translate({x:.2},{y:.2}) rotate(-15)"/>"#
+            ),
+            PrecipitationPattern::Drizzle => format!(
+                r#"<circle cx="{x:.2}" cy="{y:.2}" r="1.2" fill="white" fill-opacity="0.85"/>"#
+            ),
+            // A falling streak over a flat ice bar (the bar sits lowest once flipped).
+            PrecipitationPattern::FreezingRain => format!(
+                r#"<path d="M0,3 L0,9 M-3,0 L3,0" stroke="white" stroke-width="1.6" stroke-linecap="round" stroke-opacity="0.9" fill="none" transform="translate({x:.2},{y:.2})"/>"#
+            ),
+            // Hollow pellets, so hail reads differently from solid snowflakes.
+            PrecipitationPattern::Hail => format!(
+                r#"<circle cx="{x:.2}" cy="{y:.2}" r="2.6" fill="none" stroke="white" stroke-width="1.5" stroke-opacity="0.9"/>"#
+            ),
+            // Drawn y-down, so flipped back upright inside the y-up group.
+            PrecipitationPattern::Thunderstorm => format!(
+                r#"<path d="M1,-8 L-4,1 L-0.5,1 L-2,8 L4,-2 L0.5,-2 Z" fill="white" fill-opacity="0.9" transform="translate({x:.2},{y:.2}) scale(1,-1)"/>"#
+            ),
+        }
+    }
+
+    /// Representative weather code, used to pick the legend icon.
+    fn legend_weather_code(self) -> Option<WmoWeatherCode> {
+        match self {
+            PrecipitationPattern::Snow | PrecipitationPattern::Rain => None,
+            PrecipitationPattern::Drizzle => Some(WmoWeatherCode::DrizzleModerate),
+            PrecipitationPattern::FreezingRain => Some(WmoWeatherCode::FreezingRainLight),
+            PrecipitationPattern::Hail => Some(WmoWeatherCode::ThunderstormHailSlight),
+            PrecipitationPattern::Thunderstorm => Some(WmoWeatherCode::Thunderstorm),
         }
     }
 }
@@ -77,7 +187,11 @@ pub struct GraphData {
 pub struct PrecipitationPoint {
     pub x: f32,
     pub chance: f32,
+    /// Expected amount for the hour, already converted to the display unit.
+    pub amount: f32,
     pub is_primarily_snow: bool,
+    /// Decoded WMO code for the hour, when the provider sends one.
+    pub weather_code: Option<WmoWeatherCode>,
 }
 
 #[derive(Clone, Debug)]
@@ -85,11 +199,39 @@ pub struct PrecipitationData {
     pub points: Vec<PrecipitationPoint>,
 }
 
+#[derive(Clone, Debug, Copy)]
+pub struct WindPoint {
+    pub x: f32,
+    /// Wind (or gust) speed, already converted to the display unit.
+    pub speed: f32,
+    /// Direction the wind blows from, degrees clockwise from north.
+    pub direction_deg: Option<u16>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WindData {
+    pub points: Vec<WindPoint>,
+}
+
+#[derive(Clone, Debug, Copy)]
+pub struct CloudCoverPoint {
+    pub x: f32,
+    /// Total cloud cover in percent; `None` when the provider has none.
+    pub cover: Option<u16>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CloudCoverData {
+    pub points: Vec<CloudCoverPoint>,
+}
+
 #[derive(Clone, Debug)]
 pub enum CurveType {
     ActualTemp(GraphData),
     TempFeelLike(GraphData),
     PrecipitationChance(PrecipitationData),
+    WindSpeed(WindData),
+    CloudCover(CloudCoverData),
 }
 
 impl GraphData {
@@ -99,14 +241,133 @@ impl GraphData {
 }
 
 impl PrecipitationData {
-    pub fn add_point(&mut self, x: f32, chance: f32, is_primarily_snow: bool) {
+    pub fn add_point(
+        &mut self,
+        x: f32,
+        chance: f32,
+        amount: f32,
+        is_primarily_snow: bool,
+        weather_code: Option<WmoWeatherCode>,
+    ) {
         self.points.push(PrecipitationPoint {
             x,
             chance,
+            amount,
             is_primarily_snow,
+            weather_code,
+        })
+    }
+}
+
+impl WindData {
+    pub fn add_point(&mut self, x: f32, speed: f32, direction_deg: Option<u16>) {
+        self.points.push(WindPoint {
+            x,
+            speed,
+            direction_deg,
         })
     }
 }
+
+impl CloudCoverData {
+    pub fn add_point(&mut self, x: f32, cover: Option<u16>) {
+        self.points.push(CloudCoverPoint { x, cover })
+    }
+}
+/// Chart-unit margins around the plot for a chart drawn into a layout
+/// region: axis labels left and right, the UV and cloud strips above, hour
+/// labels below.
+const MARGIN_LEFT: f32 = 50.0;
+const MARGIN_RIGHT: f32 = 60.0;
+const MARGIN_TOP: f32 = 30.0;
+const MARGIN_BOTTOM: f32 = 45.0;
+/// Pixels per chart unit on the 800×480 canvas, where a 690-unit-wide view
+/// box is drawn 560px wide.
+const CLASSIC_PIXELS_PER_UNIT: f32 = 560.0 / 690.0;
+/// Hour steps that divide the 24-hour window evenly.
+const X_TICK_CHOICES: [u16; 5] = [3, 4, 6, 8, 12];
+
+/// How big the hourly chart is drawn: its plot size and tick counts in
+/// chart units, and the pixel box it occupies on the canvas.
+///
+/// Labels, tick marks and offsets in this module are in chart units, sized
+/// for `DEFAULT_AXIS_LABEL_FONT_SIZE`. The view box maps those units to
+/// pixels at a scale derived from the canvas, so a bigger panel gets
+/// proportionally bigger text without re-tuning every offset, while the
+/// plot's own width and height follow the region's shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChartMetrics {
+    pub width: f32,
+    pub height: f32,
+    pub x_ticks: u16,
+    pub y_ticks: u16,
+    /// Pixel size of the `<svg>` holding the chart.
+    pub svg_width: f32,
+    pub svg_height: f32,
+    /// Size of the chart as a widget, which may be smaller than the `<svg>`
+    /// when labels are allowed to overflow it.
+    pub box_width: f32,
+    pub box_height: f32,
+    view_box: (f32, f32, f32, f32),
+}
+
+impl ChartMetrics {
+    /// The 800×480 template's chart.
+    pub fn classic() -> Self {
+        Self {
+            width: 600.0,
+            height: 300.0,
+            x_ticks: 6,
+            y_ticks: 5,
+            svg_width: 560.0,
+            svg_height: 350.0,
+            box_width: 580.0,
+            box_height: 320.0,
+            view_box: (-50.0, 0.0, 690.0, 300.0),
+        }
+    }
+
+    /// A chart filling a `region` (pixels) of a `canvas`. The unit scale
+    /// grows with the canvas area, so portrait and landscape panels of the
+    /// same size share text sizes.
+    pub fn for_region(canvas: (f32, f32), region: (f32, f32)) -> Self {
+        let (canvas_width, canvas_height) = canvas;
+        let (region_width, region_height) = region;
+        let scale =
+            CLASSIC_PIXELS_PER_UNIT * (canvas_width * canvas_height / (800.0 * 480.0)).sqrt();
+        let view_width = region_width / scale;
+        let view_height = region_height / scale;
+        let width = (view_width - MARGIN_LEFT - MARGIN_RIGHT).round();
+        let height = (view_height - MARGIN_TOP - MARGIN_BOTTOM).round();
+        let x_ticks = X_TICK_CHOICES
+            .into_iter()
+            .min_by(|a, b| {
+                let distance = |ticks: u16| (width / 100.0 - f32::from(ticks)).abs();
+                distance(*a).total_cmp(&distance(*b))
+            })
+            .unwrap_or(6);
+        Self {
+            width,
+            height,
+            x_ticks,
+            // Steps of 20% (or 25%) keep the right axis labels round.
+            y_ticks: if height >= 240.0 { 5 } else { 4 },
+            svg_width: region_width,
+            svg_height: region_height,
+            box_width: region_width,
+            box_height: region_height,
+            view_box: (-MARGIN_LEFT, -MARGIN_TOP, view_width, view_height),
+        }
+    }
+
+    /// The `viewBox` attribute for the chart's `<svg>`.
+    pub fn view_box(&self) -> String {
+        let (x, y, width, height) = self.view_box;
+        let tenths = |v: f32| (v * 10.0).round() / 10.0;
+        format!("{x} {y} {} {}", tenths(width), tenths(height))
+    }
+}
+
 pub struct HourlyForecastGraph {
     pub curves: Vec<CurveType>,
     pub uv_data: [u16; 24],
@@ -131,6 +392,41 @@ pub struct HourlyForecastGraph {
     pub language: Language,
     /// Clock convention for the x-axis hour labels (e.g. "3pm" vs "15:00").
     pub hour_format: HourFormat,
+    /// Stroke/fill colour of the optional wind series and its direction arrows.
+    pub wind_colour: String,
+    /// Draw a direction arrow on the wind series every this many hours.
+    pub wind_arrow_interval: u16,
+    /// Night-time spans as `(start, end)` in the same hour-index units as the
+    /// curve x values; already clipped to the visible window.
+    pub night_bands: Vec<(f32, f32)>,
+    /// Fill style for `night_bands`; `None` disables them entirely.
+    pub night_shading: NightShading,
+    /// Colour of the night band fill and its sun/moon glyphs.
+    pub night_colour: String,
+    /// Stipple colour of the optional cloud-cover strip.
+    pub cloud_colour: String,
+    /// Whether the precipitation area plots chance, amount, or both.
+    pub precipitation_mode: PrecipitationMode,
+    /// Unit of `PrecipitationPoint::amount`, used for the right-axis labels.
+    pub precipitation_unit: PrecipitationUnit,
+    /// Top of the right axis: 100 (%) in chance mode, otherwise a rounded
+    /// amount set from the data by `initialize_x_y_bounds`.
+    pub right_axis_max: f32,
+    /// How `initialize_x_y_bounds` turns the temperature data's min/max
+    /// into the left axis range.
+    pub temp_axis_scale: TempAxisScale,
+    /// Smallest left axis span for `TempAxisScale::Nice`, in degrees.
+    pub temp_axis_min_span: f32,
+    /// This season's left axis range for `TempAxisScale::Fixed`.
+    pub temp_axis_fixed_range: TempRange,
+    /// Freezing point in the display unit; the guideline is drawn when the
+    /// left axis crosses it. `None` disables it.
+    pub freezing_point: Option<f32>,
+    /// Colour of the freezing guideline and its label.
+    pub freezing_colour: String,
+    /// Inks the panel can show; limited panels get patterns in place of
+    /// tints and colour-coded strips.
+    pub colour_profile: ColourProfile,
 }
 
 // TODO: use the builder pattern to create the graph
@@ -165,6 +461,24 @@ impl Default for HourlyForecastGraph {
             tz: chrono_tz::UTC,
             language: Language::En,
             hour_format: HourFormat::Auto,
+            wind_colour: "black".to_string(),
+            wind_arrow_interval: 3,
+            night_bands: vec![],
+            night_shading: NightShading::None,
+            night_colour: "black".to_string(),
+            cloud_colour: "black".to_string(),
+            precipitation_mode: PrecipitationMode::Chance,
+            precipitation_unit: PrecipitationUnit::Mm,
+            right_axis_max: 100.0,
+            temp_axis_scale: TempAxisScale::Auto,
+            temp_axis_min_span: 10.0,
+            temp_axis_fixed_range: TempRange {
+                min: 0.0,
+                max: 30.0,
+            },
+            freezing_point: None,
+            freezing_colour: "blue".to_string(),
+            colour_profile: ColourProfile::FullColour,
         }
     }
 }
@@ -173,6 +487,11 @@ pub enum GraphDataPath {
     Temp(String),
     TempFeelLike(String),
     Precipitation(Vec<PrecipitationBlock>),
+    /// Complete SVG fragment (curve, arrows and speed labels) in chart
+    /// coordinates, unlike the bare path data of the temperature curves.
+    Wind(String),
+    /// Complete SVG fragment for the cloud-cover strip above the plot.
+    CloudCover(String),
 }
 
 #[derive(Debug, Display)]
@@ -191,20 +510,264 @@ pub enum ElementVisibility {
     Hidden,
 }
 
+/// Builds a small arrowhead centred on (`x`, `y`) pointing the way the wind
+/// is blowing *to* — i.e. `direction_deg` (where it blows from) turned
+/// around — so a northerly (0°) points down the page.
+pub(crate) fn wind_direction_arrow_svg(
+    direction_deg: u16,
+    x: f32,
+    y: f32,
+    size: f32,
+    colour: &str,
+) -> String {
+    let rotation = (direction_deg % 360 + 180) % 360;
+    let half_width = size * 0.6;
+    let notch = size * 0.5;
+    format!(
+        r#"<path d="M 0 {tip:.1} L {half_width:.1} {size:.1} L 0 {notch:.1} L -{half_width:.1} {size:.1} Z" fill="{colour}" transform="translate({x:.2},{y:.2}) rotate({rotation})"/>"#,
+        tip = -size,
+    )
+}
+
+/// Crescent moon centred on (`x`, `y`): the left half of a disc with a
+/// narrower half-ellipse taken out of it.
+fn moon_glyph_svg(x: f32, y: f32, radius: f32, colour: &str) -> String {
+    let inner = radius * 0.5;
+    format!(
+        r#"<path d="M {x:.2} {top:.2} A {radius:.1} {radius:.1} 0 0 0 {x:.2} {bottom:.2} A {inner:.1} {radius:.1} 0 0 1 {x:.2} {top:.2} Z" fill="{colour}"/>"#,
+        top = y - radius,
+        bottom = y + radius,
+    )
+}
+
+/// Sun centred on (`x`, `y`): a small disc with eight short rays.
+fn sun_glyph_svg(x: f32, y: f32, radius: f32, colour: &str) -> String {
+    let core = radius * 0.5;
+    let rays: String = (0..8)
+        .map(|i| {
+            let angle = i as f32 * std::f32::consts::FRAC_PI_4;
+            let (sin, cos) = angle.sin_cos();
+            format!(
+                "M {x1:.2} {y1:.2} L {x2:.2} {y2:.2} ",
+                x1 = x + cos * core * 1.5,
+                y1 = y + sin * core * 1.5,
+                x2 = x + cos * radius,
+                y2 = y + sin * radius,
+            )
+        })
+        .collect();
+    format!(
+        r#"<circle cx="{x:.2}" cy="{y:.2}" r="{core:.1}" fill="{colour}"/><path d="{rays}" stroke="{colour}" stroke-width="1.5" stroke-linecap="round"/>"#,
+        rays = rays.trim_end(),
+    )
+}
+
+/// Top edge and height of the cloud-cover strip, in chart coordinates —
+/// just above the UV bar at y=-14 in the template.
+const CLOUD_STRIP_Y: f32 = -26.0;
+const CLOUD_STRIP_HEIGHT: f32 = 8.0;
+
+/// Font of the min/max temperature annotations. The family must match the
+/// template's inherited root `font-family`, since labels are measured with it.
+const TEMP_EXTREME_FONT_FAMILY: &str = "Roboto, sans-serif";
+const TEMP_EXTREME_FONT_SIZE: f32 = 16.0;
+/// Distance between an annotated point and its label.
+const TEMP_EXTREME_GAP: f32 = 6.0;
+/// Clearance kept between a label and the curves it must not cover.
+const TEMP_EXTREME_CLEARANCE: f32 = 2.0;
+
+/// Axis-aligned box in unflipped chart coordinates, used to keep the
+/// temperature annotations clear of each other, the curves and the axes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct LabelBox {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl LabelBox {
+    fn inflate(self, by: f32) -> Self {
+        Self {
+            x: self.x - by,
+            y: self.y - by,
+            width: self.width + by * 2.0,
+            height: self.height + by * 2.0,
+        }
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        (self.x..=self.x + self.width).contains(&point.x)
+            && (self.y..=self.y + self.height).contains(&point.y)
+    }
+
+    fn overlaps(&self, other: &LabelBox) -> bool {
+        self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+
+    /// Whether the segment `a`-`b` passes through the box, sampled every
+    /// pixel; curve segments are a few tens of pixels long at most.
+    fn crossed_by(&self, a: Point, b: Point) -> bool {
+        let steps = (a.x - b.x).hypot(a.y - b.y).ceil().max(1.0) as usize;
+        (0..=steps).any(|i| {
+            let t = i as f32 / steps as f32;
+            self.contains(Point {
+                x: a.x + (b.x - a.x) * t,
+                y: a.y + (b.y - a.y) * t,
+            })
+        })
+    }
+}
+
+/// A placed min/max annotation: the marked point and its two-line label
+/// (value over time), centred in `label_box`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct TemperatureExtremeLabel {
+    pub point: Point,
+    pub value: String,
+    pub time: String,
+    pub label_box: LabelBox,
+}
+
+/// Temperature as shown on a min/max annotation: whole degrees when the
+/// forecast is whole (BOM), otherwise one decimal like the inner axis
+/// labels, so the label never disagrees with the curve it points at.
+fn format_temperature_extreme(temperature: f32) -> String {
+    let rounded = temperature.round();
+    if (temperature - rounded).abs() < 0.05 {
+        // `+ 0.0` turns a rounded -0 into 0.
+        format!("{:.0}°", rounded + 0.0)
+    } else {
+        format!("{temperature:.1}°")
+    }
+}
+
+/// Smallest "nice" step (1, 2 or 5 times a power of ten) at least `raw`.
+fn nice_step(raw: f32) -> f32 {
+    let magnitude = 10f32.powf(raw.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|multiple| multiple * magnitude)
+        .find(|&step| step >= raw * 0.9999)
+        .unwrap_or(magnitude * 10.0)
+}
+
+/// Rounds the temperature range `min..max` out to `ticks` whole steps of a
+/// nice size, at least `min_span` tall. When the minimum span, not the data,
+/// sets the size, the data is kept roughly centred.
+fn nice_temp_bounds(min: f32, max: f32, ticks: u16, min_span: f32) -> (f32, f32) {
+    let ticks = f32::from(ticks.max(1));
+    let centre = (min + max) / 2.0;
+    let mut step = nice_step((max - min).max(min_span) / ticks);
+    loop {
+        // Range of lower bounds that still reach both ends of the data.
+        let lowest = (max / step).ceil() * step - step * ticks;
+        let highest = (min / step).floor() * step;
+        if lowest <= highest {
+            let centred = ((centre - step * ticks / 2.0) / step).round() * step;
+            let lower = centred.clamp(lowest, highest) + 0.0;
+            return (lower, lower + step * ticks);
+        }
+        step = nice_step(step * 1.5);
+    }
+}
+
+/// Buckets a cloud-cover percentage into roughly okta-like steps:
+/// 0 clear, 1 few, 2 scattered, 3 broken, 4 overcast.
+fn cloud_cover_level(cover: u16) -> u8 {
+    match cover {
+        0..=12 => 0,
+        13..=37 => 1,
+        38..=62 => 2,
+        63..=87 => 3,
+        _ => 4,
+    }
+}
+
+/// Top of the precipitation amount axis: the smallest "nice" value
+/// (1, 2 or 5 times a power of ten, or 2.5 with a step of 0.5) that fits the
+/// wettest hour, so five ticks land on round numbers. Never below 1 mm /
+/// 0.1 in so a dry day doesn't blow a trace up to full height.
+fn precipitation_amount_scale_max(peak: f32, unit: PrecipitationUnit) -> f32 {
+    let floor = match unit {
+        PrecipitationUnit::Mm => 1.0,
+        PrecipitationUnit::In => 0.1,
+    };
+    let target = peak.max(floor);
+    let magnitude = 10f32.powf(target.log10().floor());
+    [1.0, 2.0, 2.5, 5.0, 10.0]
+        .into_iter()
+        .map(|m| m * magnitude)
+        .find(|&candidate| candidate >= target - f32::EPSILON * magnitude)
+        .unwrap_or(10.0 * magnitude)
+}
+
+/// Top of the wind series' implicit scale: the peak speed rounded up to the
+/// next multiple of 10, but never below 20 so a calm day doesn't draw a
+/// breeze as a gale.
+fn wind_scale_max(points: &[WindPoint]) -> f32 {
+    let peak = points.iter().map(|p| p.speed).fold(0.0, f32::max);
+    ((peak / 10.0).ceil() * 10.0).max(20.0)
+}
+
+/// Joins scaled points into SVG path data, either as Catmull-Rom-smoothed
+/// Bézier segments or straight line segments.
+fn points_to_path(scaled_points: Vec<Point>, smooth: bool) -> String {
+    if smooth {
+        catmull_rom_to_bezier(scaled_points)
+            .iter()
+            .enumerate()
+            .map(|(i, val)| {
+                if i == 0 {
+                    format!("M {:.4} {:.4}", val.c1.x, val.c1.y)
+                } else {
+                    val.to_svg()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("")
+    } else {
+        scaled_points
+            .iter()
+            .enumerate()
+            .map(|(i, val)| {
+                if i == 0 {
+                    format!("M {:.4} {:.4}", val.x, val.y)
+                } else {
+                    val.to_svg()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("")
+    }
+}
+
+/// Pattern ids for hatched precipitation, shared with the legend swatches.
+const RAIN_HATCH_ID: &str = "precipHatchRain";
+const SNOW_HATCH_ID: &str = "precipHatchSnow";
+
 fn lcg_next(seed: u64) -> u64 {
     seed.wrapping_mul(6364136223846793005)
         .wrapping_add(1442695040888963407)
 }
 
-/// Builds one unified SVG fragment for all precipitation blocks (rain and snow mixed):
+/// Builds one unified SVG fragment for all precipitation blocks (every pattern mixed):
 /// - one `<clipPath>` covering every block regardless of type
 /// - one `<linearGradient>` whose stop-colour and stop-opacity vary per block
-///   (rain_colour/snow_colour, opacity scaled between opacity_min and opacity_max by chance)
+///   (snow_colour for snow, rain_colour for everything else, opacity scaled
+///   between opacity_min and opacity_max by chance)
 /// - a single LCG placement pass with a shared `placed` list so the seed never
 ///   resets at block boundaries; glyph type is chosen per block from `pattern`
 ///
-/// Separation uses the larger snowflake threshold whenever either the new or an
-/// existing glyph is a snowflake, keeping glyphs of different types from colliding.
+/// Separation between glyphs of different types follows
+/// `PrecipitationPattern::separation_from`, keeping them from colliding.
+///
+/// `hatched` (for panels without greys) fills each block with a hatch in
+/// its colour instead of the gradient, so chance is read from the block's
+/// height alone.
 pub(crate) fn generate_unified_precipitation_svg(
     blocks: &[PrecipitationBlock],
     rain_colour: &str,
@@ -212,6 +775,7 @@ pub(crate) fn generate_unified_precipitation_svg(
     graph_height: f32,
     opacity_min: f32,
     opacity_max: f32,
+    hatched: bool,
 ) -> String {
     let x_start = blocks.first().map(|b| b.x_start).unwrap_or(0.0);
     let x_end = blocks.last().map(|b| b.x_end).unwrap_or(0.0);
@@ -225,12 +789,13 @@ pub(crate) fn generate_unified_precipitation_svg(
 
     // --- gradient: one stop per hour, colour and opacity vary by type ---
     let mut gradient_stops = String::new();
+    let fill_colour = |pattern: PrecipitationPattern| match pattern {
+        PrecipitationPattern::Snow => snow_colour,
+        _ => rain_colour,
+    };
     for block in blocks.iter() {
         let offset = (block.x_start - x_start) / x_range * 100.0;
-        let colour = match &block.pattern {
-            PrecipitationPattern::Snow => snow_colour,
-            PrecipitationPattern::Rain => rain_colour,
-        };
+        let colour = fill_colour(block.pattern);
         let stop_opacity =
             (opacity_min + (block.chance / 100.0) * (opacity_max - opacity_min)).clamp(0.0, 1.0);
         gradient_stops.push_str(&format!(
@@ -238,10 +803,7 @@ pub(crate) fn generate_unified_precipitation_svg(
         ));
     }
     if let Some(last) = blocks.last() {
-        let colour = match &last.pattern {
-            PrecipitationPattern::Snow => snow_colour,
-            PrecipitationPattern::Rain => rain_colour,
-        };
+        let colour = fill_colour(last.pattern);
         let stop_opacity =
             (opacity_min + (last.chance / 100.0) * (opacity_max - opacity_min)).clamp(0.0, 1.0);
         gradient_stops.push_str(&format!(
@@ -250,28 +812,24 @@ pub(crate) fn generate_unified_precipitation_svg(
     }
 
     // --- single placement pass across all blocks ---
-    // placed: (x, y, is_snow) — type tracked so we can use the right separation threshold.
+    // placed: (x, y, pattern) — type tracked so we can use the right separation threshold.
     let mut seed: u64 = 2654435761;
-    let mut placed: Vec<(f32, f32, bool)> = Vec::new();
+    let mut placed: Vec<(f32, f32, PrecipitationPattern)> = Vec::new();
     let mut glyphs = String::new();
 
     for (idx, block) in blocks.iter().enumerate() {
         if block.chance == 0.0 || block.max_height == 0.0 {
             continue;
         }
-        let is_snow = match &block.pattern {
-            PrecipitationPattern::Snow => true,
-            PrecipitationPattern::Rain => false,
-        };
+        let pattern = block.pattern;
+        let layout = pattern.layout();
 
         let width = block.x_end - block.x_start;
 
-        let (density_div, max_count) = if is_snow { (150.0, 25) } else { (80.0, 30) };
-        let count =
-            ((width * block.max_height / density_div * block.chance / 100.0) as u32).min(max_count);
+        let count = ((width * block.max_height / layout.density_div * block.chance / 100.0) as u32)
+            .min(layout.max_count);
 
-        let r_x: f32 = 4.0;
-        let r_y: f32 = if is_snow { 4.0 } else { 9.0 };
+        let (r_x, r_y) = (layout.r_x, layout.r_y);
 
         // Only pad x where there is no adjacent non-zero block to extend the clip region.
         let left_open =
@@ -301,35 +859,46 @@ pub(crate) fn generate_unified_precipitation_svg(
                     continue;
                 }
 
-                // Use the larger snowflake separation whenever either glyph is a snowflake.
-                let overlaps = placed.iter().any(|&(px, py, placed_snow)| {
-                    let (sx, sy) = if is_snow || placed_snow {
-                        (8.0_f32, 8.0_f32)
-                    } else {
-                        (7.0_f32, 14.0_f32)
-                    };
+                let overlaps = placed.iter().any(|&(px, py, placed_pattern)| {
+                    let (sx, sy) = pattern.separation_from(placed_pattern);
                     (rx - px).abs() < sx && (ry - py).abs() < sy
                 });
 
                 if !overlaps {
-                    placed.push((rx, ry, is_snow));
-                    glyphs.push_str(&if is_snow {
-                        seed = lcg_next(seed);
-                        let radius = 2.0 + (seed as f32 / u64::MAX as f32) * 1.5;
-                        format!(
-                            r#"<circle cx="{rx:.2}" cy="{ry:.2}" r="{radius:.1}" fill="white" fill-opacity="0.85"/>"#
-                        )
-                    } else {
-                        format!(
-                            r#"<path d="M-1,1 C-1,0.45 -0.55,0 0,0 C0.55,0 1,0.45 1,1 L1,8 C1,8.55 0.55,9 0,9 C-0.55,9 -1,8.55 -1,8 Z" fill="white" fill-opacity="0.8" transform="translate({rx:.2},{ry:.2}) rotate(-15)"/>"#
-                        )
-                    });
+                    placed.push((rx, ry, pattern));
+                    glyphs.push_str(&pattern.glyph_svg(rx, ry, &mut seed));
                     continue 'outer;
                 }
             }
         }
     }
 
+    if hatched {
+        let fills: String = blocks
+            .iter()
+            .map(|b| {
+                let id = match b.pattern {
+                    PrecipitationPattern::Snow => SNOW_HATCH_ID,
+                    _ => RAIN_HATCH_ID,
+                };
+                format!(r#"<path d="{}" fill="url(#{id})"/>"#, b.path)
+            })
+            .collect();
+        return format!(
+            r#"<defs>
+            <pattern id="{RAIN_HATCH_ID}" patternUnits="userSpaceOnUse" width="6" height="6" patternTransform="rotate(45)"><line x1="0" y1="0" x2="0" y2="6" stroke="{rain_colour}" stroke-width="2"/></pattern>
+            <pattern id="{SNOW_HATCH_ID}" patternUnits="userSpaceOnUse" width="8" height="8" patternTransform="rotate(45)"><path d="M0,0 V8 M0,0 H8" stroke="{snow_colour}" stroke-width="1.5"/></pattern>
+            <clipPath id="precipClip">
+                {clip_paths}
+            </clipPath>
+        </defs>
+        {fills}
+        <g clip-path="url(#precipClip)">
+            {glyphs}
+        </g>"#
+        );
+    }
+
     format!(
         r#"<defs>
             <linearGradient id="precipBg" gradientUnits="userSpaceOnUse" x1="{x_start}" y1="0" x2="{x_end}" y2="0">
@@ -346,6 +915,66 @@ pub(crate) fn generate_unified_precipitation_svg(
     )
 }
 
+/// Small key for the less obvious precipitation patterns, one entry per
+/// pattern present: a swatch with the pattern's glyph next to the matching
+/// weather icon (icons rather than words, since the bundled Japanese font
+/// only covers the locale's fixed strings). Drawn in the chart's top-left
+/// corner on a translucent backing; empty when only rain/snow appear.
+///
+/// `icon_path` maps a weather code to the icon file for the current icon set.
+/// With `hatched`, swatches use the blocks' hatch rather than a tint.
+pub(crate) fn precipitation_legend_svg(
+    blocks: &[PrecipitationBlock],
+    rain_colour: &str,
+    background_colour: &str,
+    hatched: bool,
+    icon_path: impl Fn(WmoWeatherCode) -> String,
+) -> String {
+    const ENTRY_WIDTH: f32 = 62.0;
+    const SWATCH: f32 = 22.0;
+    const ICON: f32 = 36.0;
+    const PADDING: f32 = 4.0;
+
+    let present: Vec<(PrecipitationPattern, WmoWeatherCode)> = PrecipitationPattern::LEGEND_ORDER
+        .into_iter()
+        .filter(|pattern| {
+            blocks
+                .iter()
+                .any(|b| b.pattern == *pattern && b.chance > 0.0 && b.max_height > 0.0)
+        })
+        .filter_map(|pattern| pattern.legend_weather_code().map(|code| (pattern, code)))
+        .collect();
+    if present.is_empty() {
+        return String::new();
+    }
+
+    let swatch_fill = if hatched {
+        format!(r#"fill="url(#{RAIN_HATCH_ID})""#)
+    } else {
+        format!(r#"fill="{rain_colour}" fill-opacity="0.6""#)
+    };
+    let mut entries = String::new();
+    let mut seed = 0;
+    for (i, (pattern, code)) in present.iter().enumerate() {
+        let x = PADDING + i as f32 * ENTRY_WIDTH;
+        let swatch_y = PADDING + (ICON - SWATCH) / 2.0;
+        entries.push_str(&format!(
+            r#"<rect x="{x:.1}" y="{swatch_y:.1}" width="{SWATCH}" height="{SWATCH}" {swatch_fill}/><g transform="translate({cx:.1},{cy:.1}) scale(1,-1)">{glyph}</g><image x="{icon_x:.1}" y="{PADDING}" width="{ICON}" height="{ICON}" href="{href}"/>"#,
+            cx = x + SWATCH / 2.0,
+            cy = swatch_y + SWATCH / 2.0,
+            glyph = pattern.glyph_svg(0.0, 0.0, &mut seed),
+            icon_x = x + SWATCH,
+            href = icon_path(*code),
+        ));
+    }
+
+    format!(
+        r#"<g><rect x="0" y="0" width="{width:.1}" height="{height}" fill="{background_colour}" fill-opacity="0.8"/>{entries}</g>"#,
+        width = present.len() as f32 * ENTRY_WIDTH + PADDING,
+        height = ICON + PADDING * 2.0,
+    )
+}
+
 /// Convert a list of points to a list of Bézier curves
 /// using the Catmull-Rom to Bézier conversion
 ///
@@ -458,7 +1087,7 @@ fn stacked_tspans(chars: &[char], x: f32, start_y: f32, line_height: f32) -> Str
 /// `HourFormat::Auto` follows the clock convention of `language` (12-hour for
 /// English, 24-hour for the other supported languages); `TwelveHour`/`TwentyFour`
 /// override that regardless of language.
-fn format_hour_label(hour: f32, hour_format: HourFormat, language: Language) -> String {
+pub(crate) fn format_hour_label(hour: f32, hour_format: HourFormat, language: Language) -> String {
     let use_twelve_hour = match hour_format {
         HourFormat::TwelveHour => true,
         HourFormat::TwentyFour => false,
@@ -485,14 +1114,15 @@ impl HourlyForecastGraph {
     pub fn create_axis_with_labels(&self, current_hour: f32, clock: &dyn Clock) -> AxisPaths {
         let range_x = self.ending_x - self.starting_x + 1.0; // +1 because last hour is 23
         let range_y_left = self.max_y - self.min_y;
-        let range_y_right = 100.0; // Rain data is in percentage
+        // Percentage in chance mode, otherwise the rounded amount scale.
+        let range_y_right = self.right_axis_max;
 
         // Mapping functions from data space to SVG space
         // x data domain maps to [0, width]
         // y data domain maps to [height, 0] (SVG y goes down)
         let map_x = |x: f32| (x - self.starting_x) * (self.width / range_x);
         let map_y_left = |y: f32| self.height - ((y - self.min_y) * (self.height / range_y_left));
-        // For the right axis, we assume 0 to 100% maps directly onto the height.
+        // For the right axis, 0 to `right_axis_max` maps directly onto the height.
         let map_y_right = |y: f32| self.height - (y * (self.height / range_y_right));
 
         // Determine where to place the x-axis (shared between both left and right data)
@@ -552,7 +1182,7 @@ impl HourlyForecastGraph {
         let y_left_labels =
             self.generate_y_axis_ticks(map_y_left, y_axis_x, &mut y_left_axis_path, y_left_step);
 
-        // Y-axis ticks and labels (right - 0 to 100%)
+        // Y-axis ticks and labels (right - 0 to 100%, or 0 to the amount scale)
         let y_right_labels = self.generate_right_axis_ticks(
             map_y_right,
             y_right_axis_x,
@@ -586,9 +1216,17 @@ impl HourlyForecastGraph {
         y_right_step: f32,
     ) -> String {
         let mut y_right_labels = String::new();
+        // Enough decimals to tell adjacent amount ticks apart (0.2 mm, 0.02 in).
+        let decimals = if y_right_step >= 1.0 {
+            0
+        } else if y_right_step >= 0.1 {
+            1
+        } else {
+            2
+        };
         for k in 0..=self.y_right_ticks {
-            let y_val = k as f32 * y_right_step; // percentage step
-            if y_val > 100.0 {
+            let y_val = k as f32 * y_right_step;
+            if y_val > self.right_axis_max + y_right_step / 2.0 {
                 break;
             }
             let ys = map_y_right(y_val);
@@ -603,7 +1241,21 @@ impl HourlyForecastGraph {
 
             // Label (align to the start since it's on the right side)
             let label_x = y_right_axis_x + 10.0;
-            let label_str = format!("{y_val:.0}%");
+            let label_str = match self.precipitation_mode {
+                PrecipitationMode::Chance => format!("{y_val:.0}%"),
+                // Unit only on the top tick, trimmed ("2mm", not "2.0mm") so it
+                // fits the narrow right margin.
+                _ if k == self.y_right_ticks => {
+                    let value = format!("{y_val:.decimals$}");
+                    let value = if value.contains('.') {
+                        value.trim_end_matches('0').trim_end_matches('.')
+                    } else {
+                        &value
+                    };
+                    format!("{value}{}", self.precipitation_unit)
+                }
+                _ => format!("{y_val:.decimals$}"),
+            };
             y_right_labels.push_str(&format!(
                 r#"<text x="{x}" y="{y}" fill="{colour}"  font-size="{DEFAULT_AXIS_LABEL_FONT_SIZE}" text-anchor="start" dy="4">{text}</text>"#,
                 x = label_x,
@@ -643,7 +1295,15 @@ impl HourlyForecastGraph {
 
             // Label: placed to the left of the y-axis
             let label_x = y_axis_x - 10.0;
-            let mut label_str = format!("{y_val:.1}°");
+            // Nice/fixed scales usually step in whole degrees; drop the ".0".
+            let whole_steps = self.temp_axis_scale != TempAxisScale::Auto
+                && (y_left_step - y_left_step.round()).abs() < 0.001
+                && (self.min_y - self.min_y.round()).abs() < 0.001;
+            let mut label_str = if whole_steps {
+                format!("{:.0}°", y_val.round() + 0.0)
+            } else {
+                format!("{y_val:.1}°")
+            };
             let mut font_size = DEFAULT_AXIS_LABEL_FONT_SIZE;
             if j == 0 || j == self.y_left_ticks {
                 // Normalize negative zero when rounding to integer (e.g., -0.1 → 0, not -0)
@@ -826,6 +1486,21 @@ impl HourlyForecastGraph {
                     let ending_x_data = data.points.last().map(|val| val.x).unwrap_or(0.0);
                     self.starting_x = starting_x_data;
                     self.ending_x = ending_x_data;
+                    self.right_axis_max = match self.precipitation_mode {
+                        PrecipitationMode::Chance => 100.0,
+                        PrecipitationMode::Amount | PrecipitationMode::Both => {
+                            let peak = data.points.iter().map(|p| p.amount).fold(0.0, f32::max);
+                            precipitation_amount_scale_max(peak, self.precipitation_unit)
+                        }
+                    };
+                }
+                CurveType::WindSpeed(data) => {
+                    self.starting_x = data.points.first().map(|val| val.x).unwrap_or(0.0);
+                    self.ending_x = data.points.last().map(|val| val.x).unwrap_or(0.0);
+                }
+                CurveType::CloudCover(data) => {
+                    self.starting_x = data.points.first().map(|val| val.x).unwrap_or(0.0);
+                    self.ending_x = data.points.last().map(|val| val.x).unwrap_or(0.0);
                 }
             }
         }
@@ -838,6 +1513,33 @@ impl HourlyForecastGraph {
             "24h forecast range: Min {}°, Max {}°",
             self.min_y, self.max_y
         ));
+        self.apply_temp_axis_scale();
+    }
+
+    /// Widens the data's min/max per `temp_axis_scale`, so the grid stays put
+    /// between refreshes instead of hugging each forecast.
+    fn apply_temp_axis_scale(&mut self) {
+        if !self.min_y.is_finite() || !self.max_y.is_finite() {
+            return;
+        }
+        (self.min_y, self.max_y) = match self.temp_axis_scale {
+            TempAxisScale::Auto => return,
+            // Never clip the curves: a heatwave still fits.
+            TempAxisScale::Fixed => (
+                self.min_y.min(self.temp_axis_fixed_range.min),
+                self.max_y.max(self.temp_axis_fixed_range.max),
+            ),
+            TempAxisScale::Nice => nice_temp_bounds(
+                self.min_y,
+                self.max_y,
+                self.y_left_ticks,
+                self.temp_axis_min_span,
+            ),
+        };
+        logger::detail(format!(
+            "Temperature axis ({}): {}° to {}°",
+            self.temp_axis_scale, self.min_y, self.max_y
+        ));
     }
 
     pub fn draw_uv_gradient_over_time(&self) -> String {
@@ -845,7 +1547,11 @@ impl HourlyForecastGraph {
 
         for (i, &uv) in self.uv_data.iter().enumerate() {
             let offset = (i as f32 / 23.0) * 100.0;
-            let colour = UVIndexIcon::from(uv).to_colour(&self.background_colour);
+            let colour = UVIndexIcon::from(uv).to_panel_colour(
+                &self.background_colour,
+                &self.text_colour,
+                self.colour_profile,
+            );
             gradient.push_str(&format!(
                 r#"<stop offset="{offset:.2}%" stop-color="{colour}"/>"#
             ));
@@ -854,76 +1560,448 @@ impl HourlyForecastGraph {
         gradient
     }
 
-    /// Select precipitation pattern based on whether the hour is primarily
-    /// snow (pre-computed from `Precipitation::is_primarily_snow()`).
-    /// TODO: consider adding precipitation chance as a parameter to allow for more nuanced pattern selection (e.g., light snow vs. heavy snow).
-    pub(crate) fn select_precipitation_pattern(is_snow: bool) -> PrecipitationPattern {
-        if is_snow {
-            PrecipitationPattern::Snow
-        } else {
-            PrecipitationPattern::Rain
+    /// Shaded rectangles spanning the full plot height for each night band,
+    /// with a moon where the night begins and a sun where it ends.
+    ///
+    /// Bands are drawn in unflipped chart coordinates and belong underneath
+    /// every curve. A glyph is left off an edge that was clipped by the
+    /// window (there's no sunset at hour 0, only a window boundary) and off
+    /// bands too narrow to hold it.
+    pub fn draw_night_bands(&self) -> String {
+        const GLYPH_RADIUS: f32 = 7.0;
+        const GLYPH_INSET: f32 = 10.0;
+        const GLYPH_Y: f32 = 12.0;
+
+        if self.night_shading == NightShading::None
+            || self.night_bands.is_empty()
+            || self.ending_x <= 0.0
+        {
+            return String::new();
         }
-    }
 
-    pub fn draw_graph(&mut self) -> Result<Vec<GraphDataPath>, Error> {
-        // Calculate the minimum and maximum x values from the points
-        let mut data_path = vec![];
+        let colour = &self.night_colour;
+        // A low-opacity tint needs greys the limited panels don't have.
+        let shading = match self.night_shading {
+            NightShading::Solid if self.colour_profile.is_limited() => NightShading::Hatched,
+            shading => shading,
+        };
+        let (defs, fill) = match shading {
+            NightShading::None => unreachable!("handled above"),
+            NightShading::Solid => (
+                String::new(),
+                format!(r#"fill="{colour}" fill-opacity="0.15""#),
+            ),
+            NightShading::Hatched => (
+                format!(
+                    r#"<defs><pattern id="nightShading" patternUnits="userSpaceOnUse" width="8" height="8" patternTransform="rotate(45)"><line x1="0" y1="0" x2="0" y2="8" stroke="{colour}" stroke-width="1"/></pattern></defs>"#
+                ),
+                r#"fill="url(#nightShading)""#.to_string(),
+            ),
+            NightShading::Dotted => (
+                format!(
+                    r#"<defs><pattern id="nightShading" patternUnits="userSpaceOnUse" width="6" height="6"><circle cx="3" cy="3" r="1" fill="{colour}"/></pattern></defs>"#
+                ),
+                r#"fill="url(#nightShading)""#.to_string(),
+            ),
+        };
 
-        self.initialize_x_y_bounds();
-        for curve in &self.curves {
-            // println!("Data: {:?}", data);
-            // Calculate scaling factors for x and y to fit the graph within the given width and height
-            let xfactor = self.width / self.ending_x;
-            let yfactor = match curve {
-                CurveType::PrecipitationChance(_) => self.height / 100.0, // Rain data is in percentage
-                CurveType::ActualTemp(_) | CurveType::TempFeelLike(_) => {
-                    if self.max_y >= 0.0 && self.min_y < 0.0 {
-                        self.height / (self.max_y + self.min_y.abs())
-                    } else if self.min_y < 0.0 && self.max_y < 0.0 {
-                        // both are negative - use the absolute difference
-                        self.height / (self.min_y.abs() - self.max_y.abs())
-                    } else {
-                        // when both are positive
-                        self.height / (self.max_y - self.min_y)
-                    }
-                }
-            };
+        let xfactor = self.width / self.ending_x;
+        let mut rects = String::new();
+        let mut glyphs = String::new();
+        for &(start, end) in &self.night_bands {
+            let x_start = (start.max(0.0) * xfactor).min(self.width);
+            let x_end = (end.min(self.ending_x) * xfactor).max(x_start);
+            let band_width = x_end - x_start;
+            if band_width <= 0.0 {
+                continue;
+            }
+            rects.push_str(&format!(
+                r#"<rect x="{x_start:.2}" y="0" width="{band_width:.2}" height="{height}" {fill}/>"#,
+                height = self.height,
+            ));
 
-            // println!("X factor: {}, Y factor: {}", xfactor, yfactor);
+            if band_width < GLYPH_RADIUS * 2.0 + GLYPH_INSET {
+                continue;
+            }
+            if start > 0.0 {
+                glyphs.push_str(&moon_glyph_svg(
+                    x_start + GLYPH_INSET,
+                    GLYPH_Y,
+                    GLYPH_RADIUS,
+                    colour,
+                ));
+            }
+            if end < self.ending_x {
+                glyphs.push_str(&sun_glyph_svg(
+                    x_end - GLYPH_INSET,
+                    GLYPH_Y,
+                    GLYPH_RADIUS,
+                    colour,
+                ));
+            }
+        }
 
-            match curve {
-                CurveType::PrecipitationChance(precipitation_data) => {
-                    // Scale precipitation points according to the calculated factors.
-                    let scaled_points: Vec<Point> = precipitation_data
-                        .points
-                        .iter()
-                        .map(|val| Point {
-                            x: val.x * xfactor,
-                            y: val.chance * yfactor,
-                        })
-                        .collect();
+        format!("<g>{defs}{rects}{glyphs}</g>")
+    }
 
-                    // Generate individual blocks for each hour with stepped path segments
-                    let mut blocks = Vec::new();
+    /// Chart y of the freezing guideline and its label box, when enabled and
+    /// strictly inside the left axis range. The label sits at the right end,
+    /// above the line unless that would leave the plot.
+    fn freezing_line_layout(&self) -> Option<(f32, String, LabelBox)> {
+        const FONT_SIZE: f32 = 14.0;
+        let freezing = self.freezing_point?;
+        if !(self.min_y < freezing && freezing < self.max_y) {
+            return None;
+        }
+        let y = self.height - (freezing - self.min_y) * (self.height / (self.max_y - self.min_y));
+        let label = format!("{freezing:.0}°");
+        let width = measure_text_width(&label, TEMP_EXTREME_FONT_FAMILY, FONT_SIZE)
+            .unwrap_or(FONT_SIZE * 0.6 * label.chars().count() as f32);
+        let top = if y - FONT_SIZE - 4.0 >= 0.0 {
+            y - FONT_SIZE - 3.0
+        } else {
+            y + 3.0
+        };
+        let label_box = LabelBox {
+            x: self.width - 4.0 - width,
+            y: top,
+            width,
+            height: FONT_SIZE,
+        };
+        Some((y, label, label_box))
+    }
 
-                    for i in 0..scaled_points.len() {
-                        let current = scaled_points[i];
-                        let next = if i + 1 < scaled_points.len() {
-                            scaled_points[i + 1]
-                        } else {
-                            // Last point - extrapolate one step, but clamp to graph width
-                            // so the final block does not overflow past the right Y-axis.
-                            let step = current.x - if i > 0 { scaled_points[i - 1].x } else { 0.0 };
-                            Point {
-                                x: (current.x + step).min(self.width),
-                                y: current.y,
-                            }
-                        };
+    /// Highlighted 0°C / 32°F guideline across the plot, labelled at its
+    /// right end. Empty unless `freezing_point` is set and the left axis
+    /// crosses it; must run after [`Self::draw_graph`].
+    pub fn draw_freezing_line(&self) -> String {
+        let Some((y, label, label_box)) = self.freezing_line_layout() else {
+            return String::new();
+        };
+        // Dash-dot on limited panels, where it would otherwise share the
+        // feels-like curve's colour and near enough its dashes.
+        let dashes = if self.colour_profile.is_limited() {
+            "10,4,2,4"
+        } else {
+            "10,4"
+        };
+        format!(
+            r#"<line x1="0" y1="{y:.2}" x2="{width}" y2="{y:.2}" stroke="{colour}" stroke-width="2" stroke-dasharray="{dashes}"/><text x="{text_x:.2}" y="{text_y:.2}" fill="{colour}" font-size="{font_size}" text-anchor="end" stroke="{background}" stroke-width="3" stroke-linejoin="round" paint-order="stroke">{label}</text>"#,
+            width = self.width,
+            colour = self.freezing_colour,
+            background = self.background_colour,
+            text_x = label_box.x + label_box.width,
+            text_y = label_box.y + label_box.height * 0.85,
+            font_size = label_box.height,
+        )
+    }
 
-                        // Get original precipitation chance percentage and snow flag for pattern selection
-                        let precip_chance = precipitation_data.points[i].chance;
-                        let is_snow = precipitation_data.points[i].is_primarily_snow;
-                        let pattern = Self::select_precipitation_pattern(is_snow);
+    /// Labels the highest and lowest points of the actual temperature curve
+    /// with their value and hour, for reading the day's range at a glance.
+    ///
+    /// Must run after [`Self::draw_graph`], which settles the bounds the
+    /// curve is scaled by. Returns an empty string when the curve is flat.
+    pub fn draw_temperature_extremes(&self, current_hour: f32) -> String {
+        self.place_temperature_extremes(current_hour)
+            .iter()
+            .map(|label| {
+                let centre_x = label.label_box.x + label.label_box.width / 2.0;
+                let value_y = label.label_box.y + TEMP_EXTREME_FONT_SIZE * 0.8;
+                let time_y = value_y + TEMP_EXTREME_FONT_SIZE + 2.0;
+                // A background-coloured outline keeps the text legible over
+                // precipitation patterns and guidelines.
+                let halo = format!(
+                    r#"stroke="{}" stroke-width="3" stroke-linejoin="round" paint-order="stroke""#,
+                    self.background_colour
+                );
+                format!(
+                    r#"<circle cx="{px:.2}" cy="{py:.2}" r="3.5" fill="{colour}" stroke="{background}" stroke-width="1.5"/><text x="{centre_x:.2}" y="{value_y:.2}" fill="{colour}" font-size="{TEMP_EXTREME_FONT_SIZE}" text-anchor="middle" {halo}>{value}</text><text x="{centre_x:.2}" y="{time_y:.2}" fill="{colour}" font-size="{TEMP_EXTREME_FONT_SIZE}" text-anchor="middle" {halo}>{time}</text>"#,
+                    px = label.point.x,
+                    py = label.point.y,
+                    colour = self.text_colour,
+                    background = self.background_colour,
+                    value = label.value,
+                    time = label.time,
+                )
+            })
+            .collect()
+    }
+
+    /// Finds the max and min of the actual temperature curve and a spot for
+    /// each label that stays inside the plot (clear of the axis labels
+    /// around it), off both temperature curves, off the tomorrow marker and
+    /// freezing line label, and off the other label.
+    ///
+    /// Candidates are tried nearest first: beside, then centred on, the
+    /// point on the side away from the curve (above the max, below the min),
+    /// then the other side, each stepping further out; at every height the
+    /// label may also slide sideways. When every candidate
+    /// touches a curve, the first one clear of everything else is used; a
+    /// label with no room at all is left off.
+    pub(crate) fn place_temperature_extremes(
+        &self,
+        current_hour: f32,
+    ) -> Vec<TemperatureExtremeLabel> {
+        let Some(actual) = self.curves.iter().find_map(|curve| match curve {
+            CurveType::ActualTemp(data) => Some(&data.points),
+            _ => None,
+        }) else {
+            return vec![];
+        };
+        let range_y = self.max_y - self.min_y;
+        if actual.len() < 2 || self.ending_x <= 0.0 || !range_y.is_finite() || range_y <= 0.0 {
+            return vec![];
+        }
+
+        // Same mapping as the flipped temperature paths in `draw_graph`,
+        // but in unflipped chart coordinates.
+        let xfactor = self.width / self.ending_x;
+        let to_chart = |point: &Point| Point {
+            x: point.x * xfactor,
+            y: self.height - (point.y - self.min_y) * (self.height / range_y),
+        };
+
+        let max_point = actual.iter().fold(
+            actual[0],
+            |best, point| if point.y > best.y { *point } else { best },
+        );
+        let min_point = actual.iter().fold(
+            actual[0],
+            |best, point| if point.y < best.y { *point } else { best },
+        );
+        if max_point.y <= min_point.y {
+            return vec![];
+        }
+
+        let curve_segments: Vec<(Point, Point)> = self
+            .curves
+            .iter()
+            .filter_map(|curve| match curve {
+                CurveType::ActualTemp(data) | CurveType::TempFeelLike(data) => Some(&data.points),
+                _ => None,
+            })
+            .flat_map(|points| {
+                let chart_points: Vec<Point> = points.iter().map(to_chart).collect();
+                chart_points
+                    .windows(2)
+                    .map(|pair| (pair[0], pair[1]))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // Same position as `create_axis_with_labels` gives the tomorrow
+        // line; its rotated day name sits just to the right of it.
+        let mut obstacles = vec![];
+        if current_hour != 0.0 {
+            let range_x = self.ending_x - self.starting_x + 1.0;
+            let tomorrow_x = (24.0 - current_hour - self.starting_x) * (self.width / range_x);
+            obstacles.push(LabelBox {
+                x: tomorrow_x - 4.0,
+                y: 0.0,
+                width: 28.0,
+                height: self.height,
+            });
+        }
+
+        if let Some((_, _, freezing_label)) = self.freezing_line_layout() {
+            obstacles.push(freezing_label);
+        }
+
+        let mut labels = vec![];
+        for (point, is_max) in [(max_point, true), (min_point, false)] {
+            let value = format_temperature_extreme(point.y);
+            let hour = (current_hour + point.x) % 24.0;
+            let time = format_hour_label(hour, self.hour_format, self.language);
+            let measure = |text: &str| {
+                measure_text_width(text, TEMP_EXTREME_FONT_FAMILY, TEMP_EXTREME_FONT_SIZE)
+                    .unwrap_or(TEMP_EXTREME_FONT_SIZE * 0.6 * text.chars().count() as f32)
+            };
+            let width = measure(&value).max(measure(&time));
+            let height = TEMP_EXTREME_FONT_SIZE * 2.0 + 2.0;
+            let anchor = to_chart(&point);
+
+            let Some(label_box) = self.temperature_label_box(
+                anchor,
+                width,
+                height,
+                is_max,
+                &obstacles,
+                &curve_segments,
+            ) else {
+                continue;
+            };
+            obstacles.push(label_box);
+            labels.push(TemperatureExtremeLabel {
+                point: anchor,
+                value,
+                time,
+                label_box,
+            });
+        }
+        labels
+    }
+
+    fn temperature_label_box(
+        &self,
+        anchor: Point,
+        width: f32,
+        height: f32,
+        is_max: bool,
+        obstacles: &[LabelBox],
+        curve_segments: &[(Point, Point)],
+    ) -> Option<LabelBox> {
+        let above = |step: f32| anchor.y - TEMP_EXTREME_GAP - height - step;
+        let below = |step: f32| anchor.y + TEMP_EXTREME_GAP + step;
+        // Beside and centred first, then sliding further out sideways to
+        // clear the tomorrow marker; the dot still marks the exact point.
+        let right = anchor.x + TEMP_EXTREME_GAP;
+        let left = anchor.x - TEMP_EXTREME_GAP - width;
+        let lefts = [
+            right,
+            left,
+            anchor.x - width / 2.0,
+            right + width / 2.0,
+            left - width / 2.0,
+            right + width,
+            left - width,
+        ];
+
+        let mut candidates = vec![];
+        for side in [is_max, !is_max] {
+            for step in [0.0, height / 2.0, height, height * 1.5] {
+                let y = if side { above(step) } else { below(step) };
+                candidates.extend(lefts.iter().map(|&x| LabelBox {
+                    x,
+                    y,
+                    width,
+                    height,
+                }));
+            }
+        }
+
+        let in_plot = |candidate: &LabelBox| {
+            candidate.x >= TEMP_EXTREME_CLEARANCE
+                && candidate.y >= TEMP_EXTREME_CLEARANCE
+                && candidate.x + candidate.width <= self.width - TEMP_EXTREME_CLEARANCE
+                && candidate.y + candidate.height <= self.height - TEMP_EXTREME_CLEARANCE
+        };
+        let mut fallback = None;
+        for candidate in candidates {
+            if !in_plot(&candidate) || obstacles.iter().any(|o| o.overlaps(&candidate)) {
+                continue;
+            }
+            let padded = candidate.inflate(TEMP_EXTREME_CLEARANCE);
+            if !curve_segments.iter().any(|&(a, b)| padded.crossed_by(a, b)) {
+                return Some(candidate);
+            }
+            fallback.get_or_insert(candidate);
+        }
+        fallback
+    }
+
+    /// Select precipitation pattern for an hour. A WMO code naming a
+    /// distinct type (thunderstorm, hail, freezing rain/drizzle, drizzle)
+    /// wins; otherwise it comes down to whether the hour is primarily snow
+    /// (pre-computed from `Precipitation::is_primarily_snow()`).
+    /// TODO: consider adding precipitation chance as a parameter to allow for more nuanced pattern selection (e.g., light snow vs. heavy snow).
+    pub(crate) fn select_precipitation_pattern(
+        is_snow: bool,
+        weather_code: Option<WmoWeatherCode>,
+    ) -> PrecipitationPattern {
+        use WmoWeatherCode as W;
+        match weather_code {
+            Some(W::Thunderstorm) => PrecipitationPattern::Thunderstorm,
+            Some(W::ThunderstormHailSlight | W::ThunderstormHailHeavy) => {
+                PrecipitationPattern::Hail
+            }
+            Some(
+                W::FreezingDrizzleLight
+                | W::FreezingDrizzleDense
+                | W::FreezingRainLight
+                | W::FreezingRainHeavy,
+            ) => PrecipitationPattern::FreezingRain,
+            Some(W::DrizzleLight | W::DrizzleModerate | W::DrizzleDense) => {
+                PrecipitationPattern::Drizzle
+            }
+            _ if is_snow => PrecipitationPattern::Snow,
+            _ => PrecipitationPattern::Rain,
+        }
+    }
+
+    pub fn draw_graph(&mut self) -> Result<Vec<GraphDataPath>, Error> {
+        // Calculate the minimum and maximum x values from the points
+        let mut data_path = vec![];
+
+        self.initialize_x_y_bounds();
+        for curve in &self.curves {
+            // println!("Data: {:?}", data);
+            // Calculate scaling factors for x and y to fit the graph within the given width and height
+            let xfactor = self.width / self.ending_x;
+            let yfactor = match curve {
+                CurveType::PrecipitationChance(_) => self.height / self.right_axis_max,
+                // Cloud cover has no y axis of its own.
+                CurveType::CloudCover(_) => self.height / 100.0,
+                CurveType::WindSpeed(data) => self.height / wind_scale_max(&data.points),
+                CurveType::ActualTemp(_) | CurveType::TempFeelLike(_) => {
+                    if self.max_y >= 0.0 && self.min_y < 0.0 {
+                        self.height / (self.max_y + self.min_y.abs())
+                    } else if self.min_y < 0.0 && self.max_y < 0.0 {
+                        // both are negative - use the absolute difference
+                        self.height / (self.min_y.abs() - self.max_y.abs())
+                    } else {
+                        // when both are positive
+                        self.height / (self.max_y - self.min_y)
+                    }
+                }
+            };
+
+            // println!("X factor: {}, Y factor: {}", xfactor, yfactor);
+
+            match curve {
+                CurveType::PrecipitationChance(precipitation_data) => {
+                    let plots_amount = self.precipitation_mode != PrecipitationMode::Chance;
+                    // Scale precipitation points according to the calculated factors.
+                    let scaled_points: Vec<Point> = precipitation_data
+                        .points
+                        .iter()
+                        .map(|val| Point {
+                            x: val.x * xfactor,
+                            y: if plots_amount { val.amount } else { val.chance } * yfactor,
+                        })
+                        .collect();
+
+                    // Generate individual blocks for each hour with stepped path segments
+                    let mut blocks = Vec::new();
+
+                    for i in 0..scaled_points.len() {
+                        let current = scaled_points[i];
+                        let next = match scaled_points.get(i + 1) {
+                            // Amounts are per-hour totals, so they're drawn as flat bars
+                            // rather than blended into the next hour.
+                            Some(&next) if plots_amount => Point {
+                                x: next.x,
+                                y: current.y,
+                            },
+                            Some(&next) => next,
+                            None => Point {
+                                x: self.last_block_end_x(&scaled_points),
+                                y: current.y,
+                            },
+                        };
+
+                        // Chance drives fill opacity and glyph density; in amount-only
+                        // mode every wet hour is drawn at full strength.
+                        let point = &precipitation_data.points[i];
+                        let precip_chance = match self.precipitation_mode {
+                            PrecipitationMode::Chance | PrecipitationMode::Both => point.chance,
+                            PrecipitationMode::Amount if point.amount > 0.0 => 100.0,
+                            PrecipitationMode::Amount => 0.0,
+                        };
+                        let is_snow = precipitation_data.points[i].is_primarily_snow;
+                        let pattern = Self::select_precipitation_pattern(
+                            is_snow,
+                            precipitation_data.points[i].weather_code,
+                        );
 
                         // Interpolated block: top-left -> bottom-left -> bottom-right -> top-right
                         // Bottom edge tapers from current.y to next.y, smoothly blending between hours.
@@ -962,33 +2040,7 @@ impl HourlyForecastGraph {
                         .collect();
 
                     // Generate the SVG path data for temperature curves
-                    let path = if data.smooth {
-                        catmull_rom_to_bezier(scaled_points)
-                            .iter()
-                            .enumerate()
-                            .map(|(i, val)| {
-                                if i == 0 {
-                                    format!("M {:.4} {:.4}", val.c1.x, val.c1.y)
-                                } else {
-                                    val.to_svg()
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join("")
-                    } else {
-                        scaled_points
-                            .iter()
-                            .enumerate()
-                            .map(|(i, val)| {
-                                if i == 0 {
-                                    format!("M {:.4} {:.4}", val.x, val.y)
-                                } else {
-                                    val.to_svg()
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join("")
-                    };
+                    let path = points_to_path(scaled_points, data.smooth);
 
                     match curve {
                         CurveType::ActualTemp(_) => {
@@ -1000,10 +2052,146 @@ impl HourlyForecastGraph {
                         _ => unreachable!(),
                     }
                 }
+                CurveType::WindSpeed(wind_data) => {
+                    data_path.push(GraphDataPath::Wind(
+                        self.draw_wind_series(wind_data, xfactor, yfactor),
+                    ));
+                }
+                CurveType::CloudCover(cloud_data) => {
+                    data_path.push(GraphDataPath::CloudCover(
+                        self.draw_cloud_cover_strip(cloud_data, xfactor),
+                    ));
+                }
             }
         }
         Ok(data_path)
     }
+
+    /// Right edge of the last hourly block: one step past the final point
+    /// (the same step as between the previous two), clamped to the graph
+    /// width so the block does not overflow past the right Y-axis.
+    fn last_block_end_x(&self, scaled_points: &[Point]) -> f32 {
+        match scaled_points {
+            [] => 0.0,
+            [only] => (only.x + only.x).min(self.width),
+            [.., previous, last] => (last.x + (last.x - previous.x)).min(self.width),
+        }
+    }
+
+    /// Renders hourly cloud cover as a thin strip above the UV bar, one cell
+    /// per hour spanning the same x range as that hour's precipitation block.
+    ///
+    /// Cover is bucketed into five steps — clear, few, scattered, broken,
+    /// overcast — each a denser stipple, with overcast solid. Fixed dot
+    /// patterns survive e-paper dithering better than opacity ramps. Hours
+    /// without data are left blank; with no data at all (BOM) the strip is
+    /// omitted.
+    fn draw_cloud_cover_strip(&self, cloud_data: &CloudCoverData, xfactor: f32) -> String {
+        if cloud_data.points.iter().all(|point| point.cover.is_none()) {
+            return String::new();
+        }
+
+        let scaled_x: Vec<Point> = cloud_data
+            .points
+            .iter()
+            .map(|val| Point {
+                x: val.x * xfactor,
+                y: 0.0,
+            })
+            .collect();
+        let colour = &self.cloud_colour;
+        let (y, height) = (CLOUD_STRIP_Y, CLOUD_STRIP_HEIGHT);
+
+        let mut cells = String::new();
+        for (i, point) in cloud_data.points.iter().enumerate() {
+            let Some(cover) = point.cover else {
+                continue;
+            };
+            let fill = match cloud_cover_level(cover) {
+                0 => continue,
+                4 => format!(r#"fill="{colour}""#),
+                level => format!(r#"fill="url(#cloudCover{level})""#),
+            };
+            let x_start = scaled_x[i].x;
+            let x_end = scaled_x
+                .get(i + 1)
+                .map_or_else(|| self.last_block_end_x(&scaled_x), |next| next.x);
+            if x_end <= x_start {
+                continue;
+            }
+            cells.push_str(&format!(
+                r#"<rect x="{x_start:.2}" y="{y}" width="{width:.2}" height="{height}" {fill}/>"#,
+                width = x_end - x_start,
+            ));
+        }
+
+        format!(
+            r#"<g>
+            <defs>
+                <pattern id="cloudCover1" patternUnits="userSpaceOnUse" width="4" height="4"><circle cx="2" cy="2" r="0.7" fill="{colour}"/></pattern>
+                <pattern id="cloudCover2" patternUnits="userSpaceOnUse" width="4" height="4"><circle cx="1" cy="1" r="0.7" fill="{colour}"/><circle cx="3" cy="3" r="0.7" fill="{colour}"/></pattern>
+                <pattern id="cloudCover3" patternUnits="userSpaceOnUse" width="2" height="2"><circle cx="1" cy="1" r="0.7" fill="{colour}"/></pattern>
+            </defs>
+            {cells}
+            <rect x="0" y="{y}" width="{width}" height="{height}" fill="none" stroke="{outline}" stroke-width="0.5"/>
+        </g>"#,
+            width = self.width,
+            outline = self.text_colour,
+        )
+    }
+
+    /// Renders the wind series as a dotted curve (in the same flipped space
+    /// as the temperature curves) plus, every `wind_arrow_interval` hours, a
+    /// direction arrow sitting on the curve with the speed printed above it.
+    ///
+    /// Arrows and labels are drawn in unflipped chart coordinates so the
+    /// text reads the right way up and arrow rotations stay clockwise.
+    fn draw_wind_series(&self, wind_data: &WindData, xfactor: f32, yfactor: f32) -> String {
+        let scaled_points: Vec<Point> = wind_data
+            .points
+            .iter()
+            .map(|val| Point {
+                x: val.x * xfactor,
+                y: val.speed * yfactor,
+            })
+            .collect();
+
+        let interval = usize::from(self.wind_arrow_interval.max(1));
+        let mut markers = String::new();
+        for (i, (point, scaled)) in wind_data.points.iter().zip(&scaled_points).enumerate() {
+            if i % interval != interval / 2 {
+                continue;
+            }
+            // Keep the label clear of the UV bar and the arrow off the x-axis.
+            let y = (self.height - scaled.y).clamp(30.0, self.height - 10.0);
+            if let Some(direction) = point.direction_deg {
+                markers.push_str(&wind_direction_arrow_svg(
+                    direction,
+                    scaled.x,
+                    y,
+                    9.0,
+                    &self.wind_colour,
+                ));
+            }
+            markers.push_str(&format!(
+                r#"<text x="{x:.2}" y="{label_y:.2}" fill="{colour}" font-size="14" text-anchor="middle">{speed:.0}</text>"#,
+                x = scaled.x,
+                label_y = y - 14.0,
+                colour = self.text_colour,
+                speed = point.speed,
+            ));
+        }
+
+        format!(
+            r#"<g>
+            <path stroke="{colour}" stroke-width="3" stroke-dasharray="1,6" stroke-linecap="round" fill="none" transform="translate(0, {height}) scale(1, -1)" d="{path}"/>
+            {markers}
+        </g>"#,
+            colour = self.wind_colour,
+            height = self.height,
+            path = points_to_path(scaled_points, true),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1095,7 +2283,7 @@ mod tests {
         #[test]
         fn snow_flag_selects_snow_pattern() {
             assert!(matches!(
-                HourlyForecastGraph::select_precipitation_pattern(true),
+                HourlyForecastGraph::select_precipitation_pattern(true, None),
                 PrecipitationPattern::Snow
             ));
         }
@@ -1103,10 +2291,175 @@ mod tests {
         #[test]
         fn non_snow_flag_selects_rain_pattern() {
             assert!(matches!(
-                HourlyForecastGraph::select_precipitation_pattern(false),
+                HourlyForecastGraph::select_precipitation_pattern(false, None),
                 PrecipitationPattern::Rain
             ));
         }
+
+        #[test]
+        fn weather_code_selects_distinct_patterns() {
+            let select =
+                |code| HourlyForecastGraph::select_precipitation_pattern(false, Some(code));
+            assert_eq!(
+                select(WmoWeatherCode::Thunderstorm),
+                PrecipitationPattern::Thunderstorm
+            );
+            assert_eq!(
+                select(WmoWeatherCode::ThunderstormHailHeavy),
+                PrecipitationPattern::Hail
+            );
+            assert_eq!(
+                select(WmoWeatherCode::FreezingDrizzleLight),
+                PrecipitationPattern::FreezingRain
+            );
+            assert_eq!(
+                select(WmoWeatherCode::FreezingRainHeavy),
+                PrecipitationPattern::FreezingRain
+            );
+            assert_eq!(
+                select(WmoWeatherCode::DrizzleDense),
+                PrecipitationPattern::Drizzle
+            );
+        }
+
+        #[test]
+        fn ordinary_codes_fall_back_to_snow_flag() {
+            assert_eq!(
+                HourlyForecastGraph::select_precipitation_pattern(
+                    true,
+                    Some(WmoWeatherCode::SnowHeavy)
+                ),
+                PrecipitationPattern::Snow
+            );
+            assert_eq!(
+                HourlyForecastGraph::select_precipitation_pattern(
+                    false,
+                    Some(WmoWeatherCode::RainShowersViolent)
+                ),
+                PrecipitationPattern::Rain
+            );
+        }
+    }
+
+    mod precipitation_glyph_tests {
+        use super::*;
+
+        fn block(pattern: PrecipitationPattern, x_start: f32, chance: f32) -> PrecipitationBlock {
+            PrecipitationBlock {
+                path: format!(
+                    "M {x_start} 0 L {x_start} 200 L {} 200 L {} 0 Z",
+                    x_start + 100.0,
+                    x_start + 100.0
+                ),
+                pattern,
+                x_start,
+                x_end: x_start + 100.0,
+                height_left: 200.0,
+                height_right: 200.0,
+                max_height: 200.0,
+                chance,
+            }
+        }
+
+        fn render(blocks: &[PrecipitationBlock]) -> String {
+            generate_unified_precipitation_svg(blocks, "blue", "cyan", 300.0, 0.4, 0.6, false)
+        }
+
+        #[test]
+        fn each_pattern_draws_its_own_glyph() {
+            let svg = render(&[block(PrecipitationPattern::Thunderstorm, 0.0, 100.0)]);
+            assert!(svg.contains("M1,-8 L-4,1"));
+            let svg = render(&[block(PrecipitationPattern::Hail, 0.0, 100.0)]);
+            assert!(svg.contains(r#"r="2.6" fill="none""#));
+            let svg = render(&[block(PrecipitationPattern::FreezingRain, 0.0, 100.0)]);
+            assert!(svg.contains("M0,3 L0,9 M-3,0 L3,0"));
+            let svg = render(&[block(PrecipitationPattern::Drizzle, 0.0, 100.0)]);
+            assert!(svg.contains(r#"r="1.2""#));
+        }
+
+        #[test]
+        fn only_snow_uses_snow_colour() {
+            let svg = render(&[
+                block(PrecipitationPattern::Hail, 0.0, 50.0),
+                block(PrecipitationPattern::Snow, 100.0, 50.0),
+            ]);
+            assert!(svg.contains(r#"offset="0.00%" stop-color="blue""#));
+            assert!(svg.contains(r#"offset="50.00%" stop-color="cyan""#));
+        }
+
+        #[test]
+        fn hatched_blocks_use_a_pattern_per_colour() {
+            let blocks = [
+                block(PrecipitationPattern::Rain, 0.0, 50.0),
+                block(PrecipitationPattern::Snow, 100.0, 50.0),
+            ];
+            let svg =
+                generate_unified_precipitation_svg(&blocks, "black", "red", 300.0, 0.4, 0.6, true);
+            assert!(!svg.contains("precipBg"));
+            assert!(svg.contains(r#"<line x1="0" y1="0" x2="0" y2="6" stroke="black""#));
+            assert!(svg.contains(r#"<path d="M0,0 V8 M0,0 H8" stroke="red""#));
+            assert!(svg.contains(&format!(
+                r#"{}" fill="url(#precipHatchRain)"/>"#,
+                blocks[0].path
+            )));
+            assert!(svg.contains(&format!(
+                r#"{}" fill="url(#precipHatchSnow)"/>"#,
+                blocks[1].path
+            )));
+
+            let icon = |code: WmoWeatherCode| format!("icons/{}", code.icon_name(false));
+            let legend = precipitation_legend_svg(
+                &[block(PrecipitationPattern::Drizzle, 0.0, 80.0)],
+                "black",
+                "white",
+                true,
+                icon,
+            );
+            assert!(
+                legend.contains(r#"fill="url(#precipHatchRain)"/>"#),
+                "{legend}"
+            );
+        }
+
+        #[test]
+        fn snow_separation_rule_is_preserved() {
+            assert_eq!(
+                PrecipitationPattern::Rain.separation_from(PrecipitationPattern::Snow),
+                (8.0, 8.0)
+            );
+            assert_eq!(
+                PrecipitationPattern::Rain.separation_from(PrecipitationPattern::Rain),
+                (7.0, 14.0)
+            );
+            assert_eq!(
+                PrecipitationPattern::Rain.separation_from(PrecipitationPattern::Thunderstorm),
+                (14.0, 20.0)
+            );
+        }
+
+        #[test]
+        fn legend_lists_only_special_patterns_present() {
+            let icon = |code: WmoWeatherCode| format!("icons/{}", code.icon_name(false));
+            let plain = [
+                block(PrecipitationPattern::Rain, 0.0, 80.0),
+                block(PrecipitationPattern::Snow, 100.0, 80.0),
+            ];
+            assert!(precipitation_legend_svg(&plain, "blue", "white", false, icon).is_empty());
+
+            let mixed = [
+                block(PrecipitationPattern::Drizzle, 0.0, 80.0),
+                block(PrecipitationPattern::Thunderstorm, 100.0, 80.0),
+                // Zero chance: drawn as nothing, so not worth a legend entry.
+                block(PrecipitationPattern::Hail, 200.0, 0.0),
+            ];
+            let svg = precipitation_legend_svg(&mixed, "blue", "white", false, icon);
+            assert_eq!(svg.matches("<image").count(), 2);
+            // Legend order puts thunderstorms before drizzle.
+            let thunder = svg.find("thunderstorms-day.svg").unwrap();
+            let drizzle = svg.find("drizzle.svg").unwrap();
+            assert!(thunder < drizzle);
+            assert!(!svg.contains("hail"));
+        }
     }
 
     mod initialize_x_y_bounds_tests {
@@ -1191,12 +2544,16 @@ mod tests {
                         PrecipitationPoint {
                             x: 2.0,
                             chance: 10.0,
+                            amount: 0.0,
                             is_primarily_snow: false,
+                            weather_code: None,
                         },
                         PrecipitationPoint {
                             x: 8.0,
                             chance: 90.0,
+                            amount: 4.0,
                             is_primarily_snow: true,
+                            weather_code: None,
                         },
                     ],
                 })],
@@ -1210,4 +2567,554 @@ mod tests {
             assert_eq!(graph.max_y, -f32::INFINITY);
         }
     }
+
+    mod precipitation_amount_tests {
+        use super::*;
+        use crate::clock::FixedClock;
+
+        fn graph(mode: PrecipitationMode, hours: &[(f32, f32)]) -> HourlyForecastGraph {
+            let mut data = PrecipitationData { points: vec![] };
+            for (i, &(chance, amount)) in hours.iter().enumerate() {
+                data.add_point(i as f32, chance, amount, false, None);
+            }
+            HourlyForecastGraph {
+                curves: vec![CurveType::PrecipitationChance(data)],
+                precipitation_mode: mode,
+                ..Default::default()
+            }
+        }
+
+        fn blocks(graph: &mut HourlyForecastGraph) -> Vec<PrecipitationBlock> {
+            match graph.draw_graph().unwrap().pop() {
+                Some(GraphDataPath::Precipitation(blocks)) => blocks,
+                _ => panic!("expected precipitation blocks"),
+            }
+        }
+
+        #[test]
+        fn amount_scale_rounds_up_to_nice_values() {
+            let mm = PrecipitationUnit::Mm;
+            assert_eq!(precipitation_amount_scale_max(0.0, mm), 1.0);
+            assert_eq!(precipitation_amount_scale_max(1.0, mm), 1.0);
+            assert_eq!(precipitation_amount_scale_max(2.5, mm), 2.5);
+            assert_eq!(precipitation_amount_scale_max(3.0, mm), 5.0);
+            assert_eq!(precipitation_amount_scale_max(12.0, mm), 20.0);
+            assert_eq!(precipitation_amount_scale_max(40.0, mm), 50.0);
+        }
+
+        #[test]
+        fn amount_scale_floor_depends_on_unit() {
+            let inches = PrecipitationUnit::In;
+            assert!((precipitation_amount_scale_max(0.0, inches) - 0.1).abs() < 1e-6);
+            assert!((precipitation_amount_scale_max(0.3, inches) - 0.5).abs() < 1e-6);
+        }
+
+        #[test]
+        fn chance_mode_keeps_percentage_axis() {
+            let mut graph = graph(PrecipitationMode::Chance, &[(90.0, 0.0), (10.0, 40.0)]);
+            let blocks = blocks(&mut graph);
+            assert_eq!(graph.right_axis_max, 100.0);
+            assert_eq!(blocks[0].chance, 90.0);
+            assert_eq!(blocks[0].height_left, 270.0);
+        }
+
+        #[test]
+        fn amount_mode_draws_flat_bars_at_full_strength() {
+            // 90% of nothing vs 10% of 4 mm: only the second hour shows.
+            let mut graph = graph(
+                PrecipitationMode::Amount,
+                &[(90.0, 0.0), (10.0, 4.0), (0.0, 0.0)],
+            );
+            let blocks = blocks(&mut graph);
+            assert_eq!(graph.right_axis_max, 5.0);
+            assert_eq!(blocks[0].max_height, 0.0);
+            assert_eq!(blocks[0].chance, 0.0);
+            assert_eq!(blocks[1].height_left, 240.0);
+            assert_eq!(blocks[1].height_right, 240.0);
+            assert_eq!(blocks[1].chance, 100.0);
+        }
+
+        #[test]
+        fn both_mode_keeps_chance_for_opacity() {
+            let mut graph = graph(PrecipitationMode::Both, &[(30.0, 2.0), (60.0, 1.0)]);
+            let blocks = blocks(&mut graph);
+            assert_eq!(graph.right_axis_max, 2.0);
+            assert_eq!(blocks[0].chance, 30.0);
+            assert_eq!(blocks[0].height_left, 300.0);
+        }
+
+        #[test]
+        fn right_axis_labels_follow_mode() {
+            let clock = FixedClock::from_rfc3339("2025-10-25T01:00:00Z").unwrap();
+            let mut chance = graph(PrecipitationMode::Chance, &[(50.0, 1.0), (50.0, 1.0)]);
+            chance.draw_graph().unwrap();
+            let labels = chance.create_axis_with_labels(0.0, &clock).y_right_labels;
+            assert!(labels.contains(">100%</text>"));
+
+            let mut amount = graph(PrecipitationMode::Amount, &[(50.0, 1.0), (50.0, 0.0)]);
+            amount.draw_graph().unwrap();
+            let labels = amount.create_axis_with_labels(0.0, &clock).y_right_labels;
+            assert!(labels.contains(">0.2</text>"), "{labels}");
+            assert!(labels.contains(">1mm</text>"), "{labels}");
+            assert!(!labels.contains('%'));
+        }
+    }
+
+    mod cloud_cover_tests {
+        use super::*;
+
+        fn graph_with_cover(cover: &[Option<u16>]) -> HourlyForecastGraph {
+            let mut data = CloudCoverData::default();
+            for (i, &c) in cover.iter().enumerate() {
+                data.add_point(i as f32, c);
+            }
+            HourlyForecastGraph {
+                curves: vec![CurveType::CloudCover(data)],
+                ..Default::default()
+            }
+        }
+
+        fn strip(cover: &[Option<u16>]) -> String {
+            let mut graph = graph_with_cover(cover);
+            match graph.draw_graph().unwrap().pop() {
+                Some(GraphDataPath::CloudCover(svg)) => svg,
+                _ => panic!("expected a cloud cover fragment"),
+            }
+        }
+
+        #[test]
+        fn levels_bucket_percentages() {
+            assert_eq!(cloud_cover_level(0), 0);
+            assert_eq!(cloud_cover_level(12), 0);
+            assert_eq!(cloud_cover_level(13), 1);
+            assert_eq!(cloud_cover_level(50), 2);
+            assert_eq!(cloud_cover_level(87), 3);
+            assert_eq!(cloud_cover_level(88), 4);
+            assert_eq!(cloud_cover_level(u16::MAX), 4);
+        }
+
+        #[test]
+        fn no_data_omits_the_strip() {
+            assert!(strip(&[None, None, None]).is_empty());
+        }
+
+        #[test]
+        fn cells_follow_the_precipitation_x_mapping() {
+            // 4 points over 600px: 200px per hour, last cell clamped to the width.
+            let svg = strip(&[Some(100), Some(50), Some(0), Some(30)]);
+            assert!(
+                svg.contains(r#"<rect x="0.00" y="-26" width="200.00" height="8" fill="black"/>"#)
+            );
+            assert!(svg.contains(
+                r#"x="200.00" y="-26" width="200.00" height="8" fill="url(#cloudCover2)""#
+            ));
+            // Clear hour is left blank, and so is the last hour, whose block
+            // is clamped to zero width at the right axis.
+            assert!(!svg.contains(r#"x="400.00""#));
+            assert!(!svg.contains(r#"x="600.00""#));
+        }
+
+        #[test]
+        fn missing_hours_are_left_blank() {
+            let svg = strip(&[Some(100), None, Some(100)]);
+            assert!(svg.contains(r#"<rect x="0.00""#));
+            assert!(!svg.contains(r#"<rect x="300.00""#));
+        }
+    }
+
+    mod night_bands_tests {
+        use super::*;
+
+        fn graph(shading: NightShading, bands: Vec<(f32, f32)>) -> HourlyForecastGraph {
+            HourlyForecastGraph {
+                night_shading: shading,
+                night_bands: bands,
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn disabled_shading_draws_nothing() {
+            assert!(graph(NightShading::None, vec![(2.0, 10.0)])
+                .draw_night_bands()
+                .is_empty());
+            assert!(graph(NightShading::Hatched, vec![])
+                .draw_night_bands()
+                .is_empty());
+        }
+
+        #[test]
+        fn band_is_scaled_to_chart_width() {
+            // 600px over 23 hours: hour 0 → 0px, hour 23 → 600px.
+            let svg = graph(NightShading::Solid, vec![(0.0, 23.0)]).draw_night_bands();
+            assert!(svg.contains(r#"<rect x="0.00" y="0" width="600.00" height="300""#));
+            assert!(svg.contains(r#"fill-opacity="0.15""#));
+        }
+
+        #[test]
+        fn patterned_shading_defines_the_pattern() {
+            let svg = graph(NightShading::Hatched, vec![(2.0, 10.0)]).draw_night_bands();
+            assert!(svg.contains(r#"<pattern id="nightShading""#));
+            assert!(svg.contains(r#"fill="url(#nightShading)""#));
+            let svg = graph(NightShading::Dotted, vec![(2.0, 10.0)]).draw_night_bands();
+            assert!(svg.contains("<circle cx=\"3\""));
+        }
+
+        #[test]
+        fn interior_band_gets_moon_and_sun() {
+            let svg = graph(NightShading::Solid, vec![(2.0, 10.0)]).draw_night_bands();
+            assert!(svg.contains(" A 7.0 7.0 "), "moon missing: {svg}");
+            assert!(svg.contains(r#"r="3.5""#), "sun missing: {svg}");
+        }
+
+        #[test]
+        fn clipped_edges_get_no_glyph() {
+            // Already dark when the window opens: no moon, but a sunrise.
+            let svg = graph(NightShading::Solid, vec![(0.0, 6.0)]).draw_night_bands();
+            assert!(!svg.contains(" A 7.0 7.0 "));
+            assert!(svg.contains(r#"r="3.5""#));
+            // Still dark when the window closes: moon only.
+            let svg = graph(NightShading::Solid, vec![(18.0, 23.0)]).draw_night_bands();
+            assert!(svg.contains(" A 7.0 7.0 "));
+            assert!(!svg.contains(r#"r="3.5""#));
+        }
+
+        #[test]
+        fn narrow_band_skips_glyphs() {
+            let svg = graph(NightShading::Solid, vec![(5.0, 5.5)]).draw_night_bands();
+            assert!(svg.contains("<rect"));
+            assert!(!svg.contains("<path"));
+            assert!(!svg.contains("<circle"));
+        }
+    }
+
+    mod wind_series_tests {
+        use super::*;
+
+        fn wind_points(speeds: &[f32]) -> Vec<WindPoint> {
+            speeds
+                .iter()
+                .enumerate()
+                .map(|(i, &speed)| WindPoint {
+                    x: i as f32,
+                    speed,
+                    direction_deg: Some(90),
+                })
+                .collect()
+        }
+
+        #[test]
+        fn arrow_points_downwind() {
+            // A northerly blows *to* the south, so the upward-pointing glyph
+            // is turned half a revolution.
+            let svg = wind_direction_arrow_svg(0, 10.0, 20.0, 7.0, "black");
+            assert!(svg.contains("translate(10.00,20.00) rotate(180)"));
+            let svg = wind_direction_arrow_svg(270, 0.0, 0.0, 7.0, "black");
+            assert!(svg.contains("rotate(90)"));
+        }
+
+        #[test]
+        fn arrow_rotation_wraps_out_of_range_directions() {
+            let svg = wind_direction_arrow_svg(360, 0.0, 0.0, 7.0, "black");
+            assert!(svg.contains("rotate(180)"));
+        }
+
+        #[test]
+        fn scale_rounds_peak_up_to_next_ten() {
+            assert_eq!(wind_scale_max(&wind_points(&[5.0, 31.0, 12.0])), 40.0);
+            assert_eq!(wind_scale_max(&wind_points(&[50.0])), 50.0);
+        }
+
+        #[test]
+        fn scale_has_a_floor_for_calm_days() {
+            assert_eq!(wind_scale_max(&wind_points(&[0.0, 3.0])), 20.0);
+            assert_eq!(wind_scale_max(&[]), 20.0);
+        }
+
+        #[test]
+        fn wind_curve_produces_arrows_at_configured_interval() {
+            let mut graph = HourlyForecastGraph {
+                curves: vec![CurveType::WindSpeed(WindData {
+                    points: wind_points(&[10.0; 24]),
+                })],
+                wind_arrow_interval: 6,
+                ..Default::default()
+            };
+            let paths = graph.draw_graph().unwrap();
+            let svg = match paths.as_slice() {
+                [GraphDataPath::Wind(svg)] => svg,
+                _ => panic!("expected a single wind fragment"),
+            };
+            // Hours 3, 9, 15 and 21 of 24.
+            assert_eq!(svg.matches("rotate(270)").count(), 4);
+            assert_eq!(svg.matches("<text").count(), 4);
+        }
+
+        #[test]
+        fn calm_hours_get_a_label_but_no_arrow() {
+            let mut points = wind_points(&[0.0; 3]);
+            points[1].direction_deg = None;
+            let mut graph = HourlyForecastGraph {
+                curves: vec![CurveType::WindSpeed(WindData { points })],
+                ..Default::default()
+            };
+            let paths = graph.draw_graph().unwrap();
+            let GraphDataPath::Wind(svg) = &paths[0] else {
+                panic!("expected a wind fragment");
+            };
+            assert!(!svg.contains("rotate("));
+            assert_eq!(svg.matches("<text").count(), 1);
+        }
+    }
+
+    mod temp_extremes_tests {
+        use super::*;
+
+        /// Graph with an actual and feels-like curve (the latter 3° cooler)
+        /// over 24 hourly points, with bounds already settled.
+        fn graph(temps: &[f32]) -> HourlyForecastGraph {
+            let points = |offset: f32| {
+                temps
+                    .iter()
+                    .enumerate()
+                    .map(|(x, &y)| Point {
+                        x: x as f32,
+                        y: y + offset,
+                    })
+                    .collect()
+            };
+            let mut graph = HourlyForecastGraph {
+                curves: vec![
+                    CurveType::ActualTemp(GraphData {
+                        points: points(0.0),
+                        smooth: true,
+                    }),
+                    CurveType::TempFeelLike(GraphData {
+                        points: points(-3.0),
+                        smooth: true,
+                    }),
+                ],
+                ..Default::default()
+            };
+            graph.draw_graph().unwrap();
+            graph
+        }
+
+        /// A day peaking at 20° (first reached at hour 7) and bottoming out
+        /// at 10° (first reached at hour 19), in whole degrees.
+        fn day() -> Vec<f32> {
+            (0..24)
+                .map(|h| 15.0 + 5.0 * ((h as f32 - 2.0) * std::f32::consts::PI / 12.0).sin())
+                .map(f32::round)
+                .collect()
+        }
+
+        #[test]
+        fn labels_highest_and_lowest_point_with_value_and_time() {
+            let labels = graph(&day()).place_temperature_extremes(6.0);
+            assert_eq!(labels.len(), 2);
+            assert_eq!(labels[0].value, "20°");
+            assert_eq!(labels[0].time, "1pm");
+            assert_eq!(labels[1].value, "10°");
+            assert_eq!(labels[1].time, "1am");
+        }
+
+        #[test]
+        fn fractional_forecasts_keep_one_decimal() {
+            assert_eq!(format_temperature_extreme(18.5), "18.5°");
+            assert_eq!(format_temperature_extreme(21.0), "21°");
+            assert_eq!(format_temperature_extreme(-0.02), "0°");
+        }
+
+        #[test]
+        fn flat_curve_is_not_annotated() {
+            let graph = graph(&[12.0; 24]);
+            assert!(graph.place_temperature_extremes(6.0).is_empty());
+            assert!(graph.draw_temperature_extremes(6.0).is_empty());
+        }
+
+        #[test]
+        fn labels_stay_inside_the_plot_and_apart() {
+            let graph = graph(&day());
+            let labels = graph.place_temperature_extremes(6.0);
+            for label in &labels {
+                let b = label.label_box;
+                assert!(b.x >= 0.0 && b.x + b.width <= graph.width, "{b:?}");
+                assert!(b.y >= 0.0 && b.y + b.height <= graph.height, "{b:?}");
+            }
+            assert!(!labels[0].label_box.overlaps(&labels[1].label_box));
+        }
+
+        #[test]
+        fn labels_avoid_the_tomorrow_marker() {
+            // Midnight lands on hour 18 when the window starts at 6am.
+            let graph = graph(&day());
+            let range_x = graph.ending_x - graph.starting_x + 1.0;
+            let tomorrow_x = 18.0 * graph.width / range_x;
+            for label in graph.place_temperature_extremes(6.0) {
+                let b = label.label_box;
+                assert!(
+                    b.x + b.width < tomorrow_x - 4.0 || b.x > tomorrow_x + 24.0,
+                    "{b:?} overlaps the marker at {tomorrow_x}"
+                );
+            }
+        }
+
+        #[test]
+        fn labels_avoid_both_curves_when_there_is_room() {
+            let graph = graph(&day());
+            let segments: Vec<(Point, Point)> = graph
+                .curves
+                .iter()
+                .filter_map(|curve| match curve {
+                    CurveType::ActualTemp(data) | CurveType::TempFeelLike(data) => {
+                        Some(&data.points)
+                    }
+                    _ => None,
+                })
+                .flat_map(|points| {
+                    let range_y = graph.max_y - graph.min_y;
+                    let chart: Vec<Point> = points
+                        .iter()
+                        .map(|p| Point {
+                            x: p.x * graph.width / graph.ending_x,
+                            y: graph.height - (p.y - graph.min_y) * graph.height / range_y,
+                        })
+                        .collect();
+                    chart.windows(2).map(|w| (w[0], w[1])).collect::<Vec<_>>()
+                })
+                .collect();
+            for label in graph.place_temperature_extremes(6.0) {
+                assert!(
+                    !segments
+                        .iter()
+                        .any(|&(a, b)| label.label_box.crossed_by(a, b)),
+                    "{label:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn svg_marks_each_point_and_follows_hour_format() {
+            let mut graph = graph(&day());
+            graph.hour_format = HourFormat::TwentyFour;
+            let svg = graph.draw_temperature_extremes(6.0);
+            assert_eq!(svg.matches("<circle").count(), 2);
+            assert!(svg.contains(">20°</text>"), "{svg}");
+            assert!(svg.contains(">13:00</text>"), "{svg}");
+        }
+    }
+
+    mod temp_axis_tests {
+        use super::*;
+        use crate::clock::FixedClock;
+
+        fn graph(scale: TempAxisScale, temps: &[f32]) -> HourlyForecastGraph {
+            let mut graph = HourlyForecastGraph {
+                curves: vec![CurveType::ActualTemp(GraphData {
+                    points: temps
+                        .iter()
+                        .enumerate()
+                        .map(|(x, &y)| Point { x: x as f32, y })
+                        .collect(),
+                    smooth: true,
+                })],
+                temp_axis_scale: scale,
+                temp_axis_fixed_range: TempRange {
+                    min: 0.0,
+                    max: 30.0,
+                },
+                ..Default::default()
+            };
+            graph.draw_graph().unwrap();
+            graph
+        }
+
+        #[test]
+        fn nice_step_picks_one_two_or_five() {
+            assert_eq!(nice_step(0.7), 1.0);
+            assert_eq!(nice_step(1.0), 1.0);
+            assert_eq!(nice_step(1.3), 2.0);
+            assert_eq!(nice_step(3.0), 5.0);
+            assert_eq!(nice_step(6.0), 10.0);
+            assert_eq!(nice_step(12.0), 20.0);
+        }
+
+        #[test]
+        fn auto_hugs_the_data() {
+            let graph = graph(TempAxisScale::Auto, &[12.3, 18.7, 15.0]);
+            assert_eq!((graph.min_y, graph.max_y), (12.3, 18.7));
+        }
+
+        #[test]
+        fn nice_rounds_out_to_whole_steps() {
+            // 11.2..23.9 spans 12.7°, so five steps of 5, centred on the data.
+            assert_eq!(nice_temp_bounds(11.2, 23.9, 5, 10.0), (5.0, 30.0));
+            // 18..21.5 is short, so the 10° minimum sets steps of 2, centred.
+            assert_eq!(nice_temp_bounds(18.0, 21.5, 5, 10.0), (14.0, 24.0));
+        }
+
+        #[test]
+        fn nice_respects_the_minimum_span_and_centres_the_data() {
+            // A flat-ish 14..16 day is shown over 10°, centred.
+            let graph = graph(TempAxisScale::Nice, &[14.0, 16.0, 15.0]);
+            assert_eq!((graph.min_y, graph.max_y), (10.0, 20.0));
+        }
+
+        #[test]
+        fn nice_is_stable_across_similar_days() {
+            let a = nice_temp_bounds(13.0, 19.0, 5, 10.0);
+            let b = nice_temp_bounds(12.0, 20.0, 5, 10.0);
+            assert_eq!(a, b);
+        }
+
+        #[test]
+        fn nice_handles_sub_zero_ranges() {
+            let (min, max) = nice_temp_bounds(-17.5, -6.2, 5, 10.0);
+            assert!(min <= -17.5 && max >= -6.2, "{min}..{max}");
+            assert_eq!(min % 1.0, 0.0);
+        }
+
+        #[test]
+        fn fixed_uses_the_season_and_widens_for_outliers() {
+            let mild = graph(TempAxisScale::Fixed, &[12.0, 18.0]);
+            assert_eq!((mild.min_y, mild.max_y), (0.0, 30.0));
+            let heatwave = graph(TempAxisScale::Fixed, &[25.0, 38.0]);
+            assert_eq!((heatwave.min_y, heatwave.max_y), (0.0, 38.0));
+        }
+
+        #[test]
+        fn whole_step_labels_drop_the_decimal() {
+            let clock = FixedClock::from_rfc3339("2025-10-25T01:00:00Z").unwrap();
+            let nice = graph(TempAxisScale::Nice, &[14.0, 16.0]);
+            let labels = nice.create_axis_with_labels(0.0, &clock).y_left_labels;
+            assert!(labels.contains(">12°</text>"), "{labels}");
+            let auto = graph(TempAxisScale::Auto, &[14.0, 16.5]);
+            let labels = auto.create_axis_with_labels(0.0, &clock).y_left_labels;
+            assert!(labels.contains(">14.5°</text>"), "{labels}");
+        }
+
+        #[test]
+        fn freezing_line_only_when_the_axis_crosses_it() {
+            let mut graph = graph(TempAxisScale::Auto, &[-5.0, 5.0]);
+            assert!(graph.draw_freezing_line().is_empty());
+
+            graph.freezing_point = Some(0.0);
+            let svg = graph.draw_freezing_line();
+            assert!(svg.contains(r#"y1="150.00""#), "{svg}");
+            assert!(svg.contains(">0°</text>"), "{svg}");
+
+            graph.freezing_point = Some(32.0);
+            assert!(graph.draw_freezing_line().is_empty());
+        }
+
+        #[test]
+        fn freezing_line_in_fahrenheit() {
+            let mut graph = graph(TempAxisScale::Auto, &[20.0, 40.0]);
+            graph.freezing_point = Some(32.0);
+            let svg = graph.draw_freezing_line();
+            assert!(svg.contains(r#"y1="120.00""#), "{svg}");
+            assert!(svg.contains(">32°</text>"), "{svg}");
+        }
+    }
 }
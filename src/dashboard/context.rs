@@ -1,24 +1,41 @@
 use crate::{
     clock::Clock,
-    configs::settings::DashboardSettings,
+    configs::settings::{Colours, DailyLayout, DashboardSettings, NightSchedule, TemperatureUnit},
     constants::{not_available_icon_path, NOT_AVAILABLE},
-    dashboard::chart::{GraphDataPath, HourlyForecastGraph},
-    domain::models::{DailyForecast, HourlyForecast},
+    dashboard::{
+        chart::{format_hour_label, GraphDataPath, HourlyForecastGraph},
+        daily_range::{range_bar_svg, week_scale, DailyRange},
+        data_age::{AgeLevel, DataAge},
+        outlook::{self, OutlookDay, OUTLOOK_DAYS},
+        summary,
+        widgets::layouts,
+    },
+    domain::models::{Astronomical, DailyForecast, HourlyForecast, Temperature},
     errors::{DashboardError, Description},
     i18n::{format_localized_date, translate, weekday_short, Language, TranslationKey},
     logger,
     utils::{
-        find_max_item_between_dates, measure_label_to_number_gap_dx, measure_stacked_label_dx,
-        total_between_dates, weekday_after_days,
+        escape_xml_text, find_max_item_between_dates, measure_label_to_number_gap_dx,
+        measure_stacked_label_dx, total_between_dates, weekday_after_days,
     },
     weather::icons::{HumidityIconName, Icon, IconContext, SunPositionIconName, UVIndexIcon},
 };
-use chrono::{DateTime, NaiveDate, Timelike, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Timelike, Utc};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::chart::{generate_unified_precipitation_svg, CurveType, ElementVisibility, FontStyle};
+use super::chart::{
+    generate_unified_precipitation_svg, precipitation_legend_svg, wind_direction_arrow_svg,
+    CloudCoverData, CurveType, ElementVisibility, FontStyle, WindData,
+};
+
+/// Position and size of the direction arrow under the current wind speed,
+/// in the template's wind-row `<svg>` coordinates (the speed text is
+/// centred at x=100) — must stay in sync with the template.
+const CURRENT_WIND_ARROW_X: f32 = 100.0;
+const CURRENT_WIND_ARROW_Y: f32 = 60.0;
+const CURRENT_WIND_ARROW_SIZE: f32 = 8.0;
 
 // =============================================================================
 // TEMPORARY WORKAROUND — remove once resvg/usvg supports tspan `text-anchor`
@@ -86,6 +103,88 @@ fn feels_like_tspan_and_number_dx(language: Language) -> (String, String) {
 
 // ============================= END WORKAROUND ==============================
 
+/// One day of the daily forecast, for templates that loop over `days`
+/// with `{{ for day in days }}`. String fields are ready to print ("N/A"
+/// when missing); the `*_value` fields carry the raw numbers.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct DayCard {
+    /// Local calendar date, `YYYY-MM-DD`.
+    pub date: String,
+    /// Localised short weekday, e.g. "Mon".
+    pub name: String,
+    pub is_today: bool,
+    pub icon: String,
+    pub min_temp: String,
+    pub max_temp: String,
+    pub min_temp_value: Option<f32>,
+    pub max_temp_value: Option<f32>,
+    pub precipitation_chance: Option<u16>,
+    /// Expected amount in `precipitation_unit`.
+    pub precipitation_amount: Option<f32>,
+    /// Range bar fragment; empty for today and unless `daily_layout = "range_bars"`.
+    pub range_bar: String,
+}
+
+/// One hour of the chart window, for templates that loop over `hours`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct HourCell {
+    /// Hour label in the configured `hour_format`, e.g. "3pm" or "15:00".
+    pub time: String,
+    /// Local hour of day, 0–23.
+    pub hour: u32,
+    pub is_night: bool,
+    pub icon: String,
+    pub temperature: String,
+    pub feels_like: String,
+    pub temperature_value: f32,
+    pub feels_like_value: f32,
+    pub precipitation_chance: Option<u16>,
+    /// Expected amount in `precipitation_unit`.
+    pub precipitation_amount: f32,
+    /// Wind (or gust, per `use_gust_instead_of_wind`) in `wind_speed_unit`.
+    pub wind_speed: u16,
+    pub wind_direction_deg: Option<u16>,
+    pub uv_index: u16,
+    pub relative_humidity: u16,
+}
+
+/// Unformatted values for conditional styling in templates, e.g.
+/// `{{ if values.has_snow }}` or `{values.temperature | temperature}` (see
+/// `dashboard::formatters`). Numbers are in the configured units; `None`
+/// (printed as "N/A" by the formatters) when the forecast lacks them.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct RenderValues {
+    /// Current hour.
+    pub temperature: Option<f32>,
+    pub feels_like: Option<f32>,
+    pub precipitation_chance: Option<u16>,
+    pub precipitation_amount: Option<f32>,
+    pub wind_speed: Option<u16>,
+    pub wind_direction_deg: Option<u16>,
+    pub uv_index: Option<u16>,
+    pub relative_humidity: Option<u16>,
+    /// WMO weather code, when the provider supplies one (Open-Meteo).
+    pub weather_code: Option<u8>,
+    pub is_night: bool,
+    /// At or above 35°C / 95°F.
+    pub is_hot: bool,
+    /// At or below 0°C / 32°F.
+    pub is_freezing: bool,
+    /// Today's daily forecast.
+    pub today_max_temp: Option<f32>,
+    pub today_min_temp: Option<f32>,
+    /// Across the chart's 24 hours.
+    pub total_precipitation: Option<f32>,
+    pub max_wind_speed: Option<u16>,
+    pub max_uv_index: Option<u16>,
+    pub has_precipitation: bool,
+    pub has_snow: bool,
+    /// A diagnostic (stale data, failed update, …) is being shown.
+    pub has_warning: bool,
+    /// How long ago the forecast shown was fetched.
+    pub data_age_minutes: i64,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Context {
     // colours
@@ -112,6 +211,7 @@ pub struct Context {
     pub current_hour_weather_icon: String,
     pub current_hour_feels_like: String,
     pub current_hour_wind_speed: String,
+    pub current_hour_wind_direction_arrow: String,
     pub current_hour_wind_icon: String,
     pub current_hour_uv_index: String,
     pub current_hour_uv_index_icon: String,
@@ -136,6 +236,12 @@ pub struct Context {
     pub actual_temp_curve_data: String,
     pub feel_like_curve_data: String,
     pub rain_curve_data: String,
+    pub wind_series_svg: String,
+    pub night_bands_svg: String,
+    pub cloud_cover_svg: String,
+    pub temp_extremes_svg: String,
+    pub freezing_line_svg: String,
+    pub precipitation_legend: String,
     pub x_axis_path: String,
     pub x_axis_guideline_path: String,
     pub y_left_axis_path: String,
@@ -170,11 +276,36 @@ pub struct Context {
     pub day7_maxtemp: String,
     pub day7_icon: String,
     pub day7_name: String,
+    // typed values for template conditionals and formatters
+    pub values: RenderValues,
+    // iterable daily forecast (today first) and chart hours; the flat
+    // dayN_* fields above mirror days[1..] for existing templates
+    pub days: Vec<DayCard>,
+    pub hours: Vec<HourCell>,
+    // daily range bars (empty unless daily_layout = "range_bars")
+    pub daily_numbers_visibility: String,
+    pub day2_range_bar: String,
+    pub day3_range_bar: String,
+    pub day4_range_bar: String,
+    pub day5_range_bar: String,
+    pub day6_range_bar: String,
+    pub day7_range_bar: String,
+    // 14-day outlook page (empty unless the outlook template is rendered)
+    pub outlook_grid_svg: String,
+    pub outlook_trend_svg: String,
     // warning message
     pub diagnostic_message: String,
     pub diagnostic_visibility: String,
     // cascading diagnostic icons (SVG fragments for multiple stacked icons)
     pub diagnostic_icons_svg: String,
+    // "Updated 14:05" / "Data 2 d old", larger and bolder as the data ages
+    pub data_age_label: String,
+    pub data_age_font_size: String,
+    pub data_age_font_weight: String,
+    pub data_age_visibility: String,
+    // One-line forecast summary under the date, XML-escaped
+    pub forecast_summary: String,
+    pub forecast_summary_visibility: String,
     // Debug information (displayed when debugging enabled)
     pub debug_info_visibility: String,
     pub debug_version: String,
@@ -184,27 +315,27 @@ pub struct Context {
 }
 
 impl Context {
-    fn new(settings: &DashboardSettings, today: NaiveDate) -> Self {
+    /// `colours` is the palette in use, day or night.
+    fn new(settings: &DashboardSettings, colours: &Colours, today: NaiveDate) -> Self {
         let icon_ctx = IconContext::from_settings(settings, today);
         let not_available_icon_path = not_available_icon_path(settings)
             .to_string_lossy()
             .to_string();
-        let colours = settings.colours.clone();
         let render_options = settings.render_options.clone();
         let language = render_options.language;
         let graph_height = "300".to_string();
         let graph_width = "600".to_string();
         let (label_like_tspan, feels_like_number_dx) = feels_like_tspan_and_number_dx(language);
-        Self {
-            background_colour: colours.background_colour.to_string(),
-            text_colour: colours.text_colour.to_string(),
-            x_axis_colour: colours.x_axis_colour.to_string(),
-            y_left_axis_colour: colours.y_left_axis_colour.to_string(),
-            y_right_axis_colour: colours.y_right_axis_colour.to_string(),
-            actual_temp_colour: colours.actual_temp_colour.to_string(),
-            feels_like_colour: colours.feels_like_colour.to_string(),
-            rain_colour: colours.rain_colour.to_string(),
-            snow_colour: colours.snow_colour.to_string(),
+        let mut context = Self {
+            background_colour: String::new(),
+            text_colour: String::new(),
+            x_axis_colour: String::new(),
+            y_left_axis_colour: String::new(),
+            y_right_axis_colour: String::new(),
+            actual_temp_colour: String::new(),
+            feels_like_colour: String::new(),
+            rain_colour: String::new(),
+            snow_colour: String::new(),
             max_uv_index: NOT_AVAILABLE.to_string(),
             max_uv_index_font_style: FontStyle::Normal.to_string(),
             max_gust_speed: NOT_AVAILABLE.to_string(),
@@ -218,6 +349,7 @@ impl Context {
             current_hour_weather_icon: not_available_icon_path.clone(),
             current_hour_feels_like: NOT_AVAILABLE.to_string(),
             current_hour_wind_speed: NOT_AVAILABLE.to_string(),
+            current_hour_wind_direction_arrow: String::new(),
             current_hour_wind_icon: not_available_icon_path.clone(),
             current_hour_uv_index: NOT_AVAILABLE.to_string(),
             current_hour_uv_index_icon: not_available_icon_path.clone(),
@@ -240,6 +372,12 @@ impl Context {
             actual_temp_curve_data: String::new(),
             feel_like_curve_data: String::new(),
             rain_curve_data: String::new(),
+            wind_series_svg: String::new(),
+            night_bands_svg: String::new(),
+            cloud_cover_svg: String::new(),
+            temp_extremes_svg: String::new(),
+            freezing_line_svg: String::new(),
+            precipitation_legend: String::new(),
             x_axis_path: String::new(),
             x_axis_guideline_path: String::new(),
             y_left_axis_path: String::new(),
@@ -273,15 +411,86 @@ impl Context {
             day7_maxtemp: NOT_AVAILABLE.to_string(),
             day7_icon: not_available_icon_path.clone(),
             day7_name: NOT_AVAILABLE.to_string(),
+            values: RenderValues::default(),
+            days: Vec::new(),
+            hours: Vec::new(),
+            daily_numbers_visibility: ElementVisibility::Visible.to_string(),
+            day2_range_bar: String::new(),
+            day3_range_bar: String::new(),
+            day4_range_bar: String::new(),
+            day5_range_bar: String::new(),
+            day6_range_bar: String::new(),
+            day7_range_bar: String::new(),
+            outlook_grid_svg: String::new(),
+            outlook_trend_svg: String::new(),
             diagnostic_message: NOT_AVAILABLE.to_string(),
             diagnostic_visibility: ElementVisibility::Hidden.to_string(),
             diagnostic_icons_svg: String::new(),
+            data_age_label: String::new(),
+            data_age_font_size: AgeLevel::Fresh.font_size().to_string(),
+            data_age_font_weight: AgeLevel::Fresh.font_weight().to_string(),
+            data_age_visibility: ElementVisibility::Hidden.to_string(),
+            forecast_summary: String::new(),
+            forecast_summary_visibility: ElementVisibility::Hidden.to_string(),
             debug_version: String::new(),
             debug_info_visibility: ElementVisibility::Hidden.to_string(),
             debug_provider: String::new(),
             debug_location: String::new(),
             debug_timezone: String::new(),
-        }
+        };
+        context.apply_palette(colours);
+        context
+    }
+
+    fn apply_palette(&mut self, colours: &Colours) {
+        self.background_colour = colours.background_colour.to_string();
+        self.text_colour = colours.text_colour.to_string();
+        self.x_axis_colour = colours.x_axis_colour.to_string();
+        self.y_left_axis_colour = colours.y_left_axis_colour.to_string();
+        self.y_right_axis_colour = colours.y_right_axis_colour.to_string();
+        self.actual_temp_colour = colours.actual_temp_colour.to_string();
+        self.feels_like_colour = colours.feels_like_colour.to_string();
+        self.rain_colour = colours.rain_colour.to_string();
+        self.snow_colour = colours.snow_colour.to_string();
+    }
+}
+
+/// Whether `now` is between a sunset and the following sunrise, judged by
+/// the closest sunrise or sunset at or before `now`, or failing that the
+/// first one after it (before sunrise means it's still night). False when
+/// there are none.
+fn is_between_sunset_and_sunrise(astronomical: &[Astronomical], now: NaiveDateTime) -> bool {
+    // (time, is_sunset)
+    let mut events: Vec<(NaiveDateTime, bool)> = astronomical
+        .iter()
+        .flat_map(|astro| {
+            [
+                astro.sunrise_time.map(|t| (t, false)),
+                astro.sunset_time.map(|t| (t, true)),
+            ]
+        })
+        .flatten()
+        .collect();
+    events.sort_by_key(|&(time, _)| time);
+    match events.iter().rev().find(|&&(time, _)| time <= now) {
+        Some(&(_, is_sunset)) => is_sunset,
+        None => events.first().is_some_and(|&(_, is_sunset)| !is_sunset),
+    }
+}
+
+/// 0°C / 32°F in `unit`.
+fn freezing_point(unit: TemperatureUnit) -> f32 {
+    match unit {
+        TemperatureUnit::C => 0.0,
+        TemperatureUnit::F => 32.0,
+    }
+}
+
+/// Where `RenderValues::is_hot` starts: 35°C / 95°F in `unit`.
+fn hot_threshold(unit: TemperatureUnit) -> f32 {
+    match unit {
+        TemperatureUnit::C => 35.0,
+        TemperatureUnit::F => 95.0,
     }
 }
 
@@ -309,13 +518,28 @@ pub struct ContextBuilder<'a> {
     icon_ctx: IconContext<'a>,
     pub context: Context,
     diagnostics: Vec<DashboardError>,
+    /// Sunrise/sunset times from the daily forecast, kept so the hourly
+    /// chart can shade the night.
+    astronomical: Vec<Astronomical>,
+    /// The palette in use: `[colours]`, or its `[colours.night]` variant
+    /// while the night schedule applies.
+    colours: Colours,
 }
 
 impl<'a> ContextBuilder<'a> {
     pub fn new(settings: &'a DashboardSettings, clock: &dyn Clock) -> Self {
         let today = clock.now_local(settings.misc.timezone).date_naive();
         let icon_ctx = IconContext::from_settings(settings, today);
-        let mut context = Context::new(settings, today);
+        // A sunset schedule is decided once the daily forecast brings
+        // sunrise and sunset; see `use_sunset_schedule`.
+        let local_hour = clock.now_local(settings.misc.timezone).hour();
+        let colours = if settings.colours.night.fixed_schedule_covers(local_hour) {
+            logger::detail("Using the night palette (fixed schedule)");
+            settings.colours.for_night()
+        } else {
+            settings.colours.clone()
+        };
+        let mut context = Context::new(settings, &colours, today);
 
         if settings.dev.enable_debug_logs {
             context.debug_info_visibility = ElementVisibility::Visible.to_string();
@@ -338,7 +562,33 @@ impl<'a> ContextBuilder<'a> {
             icon_ctx,
             context,
             diagnostics: Vec::new(),
+            astronomical: Vec::new(),
+            colours,
+        }
+    }
+
+    /// With `schedule = "sunset"`, switches to the night palette when the
+    /// clock is between a sunset and the next sunrise in the daily forecast,
+    /// and back to the day palette otherwise. Without any sunrise or sunset
+    /// (polar day or night, or a provider that omits them) it stays on the
+    /// day palette.
+    fn use_sunset_schedule(&mut self, daily_forecast_data: &[DailyForecast], clock: &dyn Clock) {
+        let colours = &self.settings.colours;
+        if colours.night.schedule != NightSchedule::Sunset {
+            return;
         }
+        let astronomical: Vec<Astronomical> = daily_forecast_data
+            .iter()
+            .filter_map(|forecast| forecast.astronomical)
+            .collect();
+        let now = clock.now_local(self.settings.misc.timezone).naive_local();
+        self.colours = if is_between_sunset_and_sunrise(&astronomical, now) {
+            logger::detail("Using the night palette (after sunset)");
+            colours.for_night()
+        } else {
+            colours.clone()
+        };
+        self.context.apply_palette(&self.colours);
     }
 
     /// Updates the warning display fields based on the highest priority diagnostic.
@@ -349,6 +599,7 @@ impl<'a> ContextBuilder<'a> {
             self.context.diagnostic_message =
                 highest_priority_error.short_description().to_string();
             self.context.diagnostic_visibility = ElementVisibility::Visible.to_string();
+            self.context.values.has_warning = true;
 
             // Generate cascading icons SVG for all diagnostics (sorted by priority)
             self.context.diagnostic_icons_svg = self.generate_cascading_icons_svg();
@@ -356,6 +607,7 @@ impl<'a> ContextBuilder<'a> {
             // No diagnostics - hide warning
             self.context.diagnostic_visibility = ElementVisibility::Hidden.to_string();
             self.context.diagnostic_icons_svg = String::new();
+            self.context.values.has_warning = false;
         }
     }
 
@@ -389,10 +641,10 @@ impl<'a> ContextBuilder<'a> {
             .join("\n        ")
     }
 
-    /// Defines the 7-day forecast window starting from today.
-    /// Returns a vector of NaiveDate representing [today, today+1, ..., today+6]
-    fn define_daily_forecast_window(today: NaiveDate) -> Vec<NaiveDate> {
-        (0..7)
+    /// Defines a forecast window of `days` days starting from today.
+    /// Returns a vector of NaiveDate representing [today, today+1, ..., today+days-1]
+    fn define_daily_forecast_window(today: NaiveDate, days: u64) -> Vec<NaiveDate> {
+        (0..days)
             .map(|offset| today + chrono::Days::new(offset))
             .collect()
     }
@@ -411,23 +663,51 @@ impl<'a> ContextBuilder<'a> {
             .collect()
     }
 
-    /// Assigns daily forecast data to the appropriate context fields.
-    /// Handles missing data by setting "N/A" defaults.
-    fn assign_day_data(&mut self, day_index: i32, forecast: Option<&DailyForecast>) {
-        let min_temp_value = forecast
-            .and_then(|f| f.temp_min)
-            .map_or(NOT_AVAILABLE.to_string(), |temp| temp.to_string());
-        let max_temp_value = forecast
-            .and_then(|f| f.temp_max)
-            .map_or(NOT_AVAILABLE.to_string(), |temp| temp.to_string());
-        let icon_value = forecast.map_or_else(
-            || {
-                not_available_icon_path(self.settings)
-                    .to_string_lossy()
-                    .to_string()
-            },
-            |f| f.icon_path(&self.icon_ctx),
-        );
+    /// Builds the iterable card for one day of the window. Handles missing
+    /// data by setting "N/A" defaults.
+    fn day_card(
+        &self,
+        date: NaiveDate,
+        today: NaiveDate,
+        forecast: Option<&DailyForecast>,
+    ) -> DayCard {
+        let range =
+            DailyRange::from_forecast(forecast, self.settings.render_options.precipitation_unit);
+        let display = |temp: Option<Temperature>| {
+            temp.map_or(NOT_AVAILABLE.to_string(), |temp| temp.to_string())
+        };
+        DayCard {
+            date: date.format("%Y-%m-%d").to_string(),
+            name: weekday_short(date.weekday(), self.settings.render_options.language).to_string(),
+            is_today: date == today,
+            icon: forecast.map_or_else(
+                || {
+                    not_available_icon_path(self.settings)
+                        .to_string_lossy()
+                        .to_string()
+                },
+                |f| f.icon_path(&self.icon_ctx),
+            ),
+            min_temp: display(range.min),
+            max_temp: display(range.max),
+            min_temp_value: range.min.map(|t| *t),
+            max_temp_value: range.max.map(|t| *t),
+            precipitation_chance: range.precipitation_chance,
+            precipitation_amount: range.precipitation_amount,
+            range_bar: String::new(),
+        }
+    }
+
+    /// Assigns daily forecast data to the appropriate flat context fields.
+    fn assign_day_data(
+        &mut self,
+        day_index: i32,
+        card: &DayCard,
+        forecast: Option<&DailyForecast>,
+    ) {
+        let min_temp_value = card.min_temp.clone();
+        let max_temp_value = card.max_temp.clone();
+        let icon_value = card.icon.clone();
 
         match day_index {
             0 => {
@@ -481,11 +761,86 @@ impl<'a> ContextBuilder<'a> {
         }
     }
 
+    /// Swaps the numeric max | min under each day card for range bars on a
+    /// scale shared by the whole strip. `day_ranges` starts at tomorrow.
+    fn assign_range_bars(&mut self, day_ranges: &[DailyRange]) {
+        let scale = week_scale(day_ranges);
+        let text_colour = self.colours.text_colour.to_string();
+        let bar_colour = self.colours.actual_temp_colour.to_string();
+        let unit = self.settings.render_options.precipitation_unit;
+        let context = &mut self.context;
+        context.daily_numbers_visibility = ElementVisibility::Hidden.to_string();
+        let slots = [
+            &mut context.day2_range_bar,
+            &mut context.day3_range_bar,
+            &mut context.day4_range_bar,
+            &mut context.day5_range_bar,
+            &mut context.day6_range_bar,
+            &mut context.day7_range_bar,
+        ];
+        for (slot, day) in slots.into_iter().zip(day_ranges) {
+            *slot = range_bar_svg(day, scale, unit, &text_colour, &bar_colour);
+        }
+    }
+
+    /// Builds the 14-day outlook page from the same daily forecast. Days the
+    /// provider doesn't cover (BOM stops after about a week) become "N/A"
+    /// cards; that's expected, so unlike the 7-day strip it raises no
+    /// incomplete-data warning.
+    pub fn with_outlook_data(
+        &mut self,
+        daily_forecast_data: &[DailyForecast],
+        clock: &dyn Clock,
+    ) -> &mut Self {
+        self.use_sunset_schedule(daily_forecast_data, clock);
+        let today = clock.now_local(self.settings.misc.timezone).date_naive();
+        let forecast_map = Self::build_date_to_forecast_map(daily_forecast_data);
+        let language = self.settings.render_options.language;
+        let unit = self.settings.render_options.precipitation_unit;
+
+        let days: Vec<OutlookDay> = Self::define_daily_forecast_window(today, OUTLOOK_DAYS as u64)
+            .into_iter()
+            .map(|date| {
+                let forecast = forecast_map.get(&date).copied();
+                OutlookDay {
+                    name: format!("{} {}", weekday_short(date.weekday(), language), date.day()),
+                    icon: forecast.map_or_else(
+                        || {
+                            not_available_icon_path(self.settings)
+                                .to_string_lossy()
+                                .to_string()
+                        },
+                        |f| f.icon_path(&self.icon_ctx),
+                    ),
+                    is_today: date == today,
+                    range: DailyRange::from_forecast(forecast, unit),
+                }
+            })
+            .collect();
+
+        let covered = days.iter().filter(|d| d.range.bounds().is_some()).count();
+        logger::detail(format!(
+            "Outlook covers {covered} of {OUTLOOK_DAYS} days from {today}"
+        ));
+
+        let colours = &self.colours;
+        self.context.outlook_grid_svg =
+            outlook::grid_svg(&days, unit, &colours.text_colour.to_string());
+        self.context.outlook_trend_svg = outlook::trend_svg(
+            &days,
+            &colours.actual_temp_colour.to_string(),
+            &colours.text_colour.to_string(),
+        );
+        self
+    }
+
     pub fn with_daily_forecast_data(
         &mut self,
         daily_forecast_data: Vec<DailyForecast>,
         clock: &dyn Clock,
     ) -> &mut Self {
+        self.use_sunset_schedule(&daily_forecast_data, clock);
+
         // Get today's local date for comparison
         let today_local_date = clock.now_local(self.settings.misc.timezone).date_naive();
 
@@ -497,12 +852,20 @@ impl<'a> ContextBuilder<'a> {
         self.initialize_day_names(clock.now_local(self.settings.misc.timezone));
 
         // Define the 7-day forecast window (today through +6 days)
-        let forecast_window = Self::define_daily_forecast_window(today_local_date);
+        let forecast_window = Self::define_daily_forecast_window(today_local_date, 7);
 
         let forecast_map = Self::build_date_to_forecast_map(&daily_forecast_data);
 
+        self.astronomical = daily_forecast_data
+            .iter()
+            .filter_map(|forecast| forecast.astronomical)
+            .collect();
+
         // Track how many days are missing
         let mut missing_days_count = 0;
+        let precipitation_unit = self.settings.render_options.precipitation_unit;
+        let mut day_ranges = Vec::with_capacity(forecast_window.len() - 1);
+        let mut days = Vec::with_capacity(forecast_window.len());
 
         // Iterate over expected window dates and map to forecasts
         for (day_index, expected_date) in forecast_window.iter().enumerate() {
@@ -542,9 +905,37 @@ impl<'a> ContextBuilder<'a> {
             }
 
             // Assign data (handles missing data with "N/A" defaults)
-            self.assign_day_data(day_index as i32, forecast.copied());
+            let card = self.day_card(*expected_date, today_local_date, forecast.copied());
+            if card.is_today {
+                self.context.values.today_max_temp = card.max_temp_value;
+                self.context.values.today_min_temp = card.min_temp_value;
+            }
+            self.assign_day_data(day_index as i32, &card, forecast.copied());
+            if day_index > 0 {
+                day_ranges.push(DailyRange::from_forecast(
+                    forecast.copied(),
+                    precipitation_unit,
+                ));
+            }
+            days.push(card);
         }
 
+        if self.settings.render_options.daily_layout == DailyLayout::RangeBars {
+            self.assign_range_bars(&day_ranges);
+            let bars = [
+                &self.context.day2_range_bar,
+                &self.context.day3_range_bar,
+                &self.context.day4_range_bar,
+                &self.context.day5_range_bar,
+                &self.context.day6_range_bar,
+                &self.context.day7_range_bar,
+            ];
+            for (card, bar) in days.iter_mut().skip(1).zip(bars) {
+                card.range_bar = bar.clone();
+            }
+        }
+        self.context.days = days;
+
         // Raise single IncompleteData error if any days are missing
         if missing_days_count > 0 {
             let details = format!(
@@ -620,15 +1011,57 @@ impl<'a> ContextBuilder<'a> {
 
         // println!("Day end: {:?}", day_end);
 
+        let metrics = layouts::chart_metrics(self.settings.display.model);
         let mut graph = HourlyForecastGraph {
+            width: metrics.width,
+            height: metrics.height,
+            x_ticks: metrics.x_ticks,
+            y_left_ticks: metrics.y_ticks,
+            y_right_ticks: metrics.y_ticks,
             x_axis_always_at_min: self.settings.render_options.x_axis_always_at_min,
-            text_colour: self.settings.colours.text_colour.to_string(),
-            background_colour: self.settings.colours.background_colour.to_string(),
+            text_colour: self.colours.text_colour.to_string(),
+            background_colour: self.colours.background_colour.to_string(),
             tz: self.settings.misc.timezone,
             language: self.settings.render_options.language,
             hour_format: self.settings.render_options.hour_format,
+            wind_colour: self.colours.wind_colour.to_string(),
+            wind_arrow_interval: self
+                .settings
+                .render_options
+                .wind_arrow_interval_hours
+                .into_inner(),
+            night_shading: self.settings.render_options.night_shading,
+            night_colour: self.colours.night_colour.to_string(),
+            cloud_colour: self.colours.cloud_colour.to_string(),
+            precipitation_mode: self.settings.render_options.precipitation_mode,
+            precipitation_unit: self.settings.render_options.precipitation_unit,
+            temp_axis_scale: self.settings.render_options.temp_axis_scale,
+            temp_axis_min_span: self.settings.render_options.temp_axis_min_span.into_inner(),
+            temp_axis_fixed_range: self
+                .settings
+                .render_options
+                .temp_axis_fixed_ranges
+                .for_month(
+                    local_forecast_window_start.month(),
+                    self.settings.api.latitude.into_inner(),
+                ),
+            freezing_point: self
+                .settings
+                .render_options
+                .show_freezing_line
+                .then_some(freezing_point(self.settings.render_options.temp_unit)),
+            freezing_colour: self.colours.freezing_colour.to_string(),
+            colour_profile: self.settings.display.profile,
             ..Default::default()
         };
+        if self.settings.render_options.show_wind_series {
+            graph.curves.push(CurveType::WindSpeed(WindData::default()));
+        }
+        if self.settings.render_options.show_cloud_cover {
+            graph
+                .curves
+                .push(CurveType::CloudCover(CloudCoverData::default()));
+        }
 
         Self::populate_graph_data(
             self,
@@ -653,15 +1086,64 @@ impl<'a> ContextBuilder<'a> {
                 .render_options
                 .precipitation_opacity_max
                 .into_inner(),
+            self.settings.display.profile.is_limited(),
         );
         self.context.graph_height = graph.height.to_string();
         self.context.graph_width = graph.width.to_string();
         self.context.actual_temp_curve_data = temp_curve_data;
         self.context.feel_like_curve_data = feel_like_curve_data;
         self.context.rain_curve_data = rain_curve_data;
+        self.context.wind_series_svg = svg_result
+            .iter()
+            .filter_map(|path| match path {
+                GraphDataPath::Wind(svg) => Some(svg.as_str()),
+                _ => None,
+            })
+            .collect();
+        self.context.precipitation_legend = svg_result
+            .iter()
+            .filter_map(|path| match path {
+                GraphDataPath::Precipitation(blocks) => Some(precipitation_legend_svg(
+                    blocks,
+                    &self.context.rain_colour,
+                    &self.context.background_colour,
+                    self.settings.display.profile.is_limited(),
+                    |code| self.icon_ctx.path_for(&code.icon_name(false)),
+                )),
+                _ => None,
+            })
+            .collect();
+        self.context.cloud_cover_svg = svg_result
+            .iter()
+            .filter_map(|path| match path {
+                GraphDataPath::CloudCover(svg) => Some(svg.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        let window_is_night: Vec<bool> = hourly_forecast_data
+            .iter()
+            .filter(|forecast| {
+                forecast.time >= local_forecast_window_start
+                    && forecast.time < local_forecast_window_end
+            })
+            .take(24)
+            .map(|forecast| forecast.is_night)
+            .collect();
+        graph.night_bands = Self::compute_night_bands(
+            &self.astronomical,
+            &window_is_night,
+            local_forecast_window_start.naive_local(),
+        );
+        self.context.night_bands_svg = graph.draw_night_bands();
 
         let axis_data_path =
             graph.create_axis_with_labels(local_forecast_window_start.hour() as f32, clock);
+        self.context.freezing_line_svg = graph.draw_freezing_line();
+        if self.settings.render_options.show_temp_extremes {
+            self.context.temp_extremes_svg =
+                graph.draw_temperature_extremes(local_forecast_window_start.hour() as f32);
+        }
 
         self.context.x_axis_path = axis_data_path.x_axis_path;
         self.context.y_left_axis_path = axis_data_path.y_left_axis_path;
@@ -682,18 +1164,101 @@ impl<'a> ContextBuilder<'a> {
             local_forecast_window_end,
         );
 
-        self.context.total_rain_today = (total_between_dates(
+        let total_rain = total_between_dates(
             &hourly_forecast_data,
             &local_forecast_window_start,
             &local_forecast_window_end,
             |item: &HourlyForecast| item.precipitation.amount(),
             |item| item.time.with_timezone(&self.settings.misc.timezone),
-        ))
-        .to_string();
+        );
+        self.context.total_rain_today = total_rain.to_string();
+        self.context.values.total_precipitation = Some(
+            self.settings
+                .render_options
+                .precipitation_unit
+                .from_mm(total_rain),
+        );
 
         self
     }
 
+    /// Night spans across the chart window as `(start, end)` hour offsets
+    /// from `window_start`, clipped to the window.
+    ///
+    /// Prefers the daily sunrise/sunset times, which give sub-hour edges.
+    /// When none are available (or the provider sent none), falls back to
+    /// runs of the hourly `is_night` flag, each hour covering ±half an hour
+    /// around its point.
+    fn compute_night_bands(
+        astronomical: &[Astronomical],
+        window_is_night: &[bool],
+        window_start: NaiveDateTime,
+    ) -> Vec<(f32, f32)> {
+        let window_end = window_is_night.len().saturating_sub(1) as f32;
+        if window_end <= 0.0 {
+            return vec![];
+        }
+        let offset_hours =
+            |time: NaiveDateTime| (time - window_start).num_seconds() as f32 / 3600.0;
+
+        // (offset, is_sunset)
+        let mut events: Vec<(f32, bool)> = astronomical
+            .iter()
+            .flat_map(|astro| {
+                [
+                    astro.sunrise_time.map(|t| (offset_hours(t), false)),
+                    astro.sunset_time.map(|t| (offset_hours(t), true)),
+                ]
+            })
+            .flatten()
+            .collect();
+        events.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut bands = Vec::new();
+        if events.is_empty() {
+            let mut run_start: Option<f32> = None;
+            for (i, &is_night) in window_is_night.iter().enumerate() {
+                let hour = i as f32;
+                match (is_night, run_start) {
+                    (true, None) => run_start = Some((hour - 0.5).max(0.0)),
+                    (false, Some(start)) => {
+                        bands.push((start, hour - 0.5));
+                        run_start = None;
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(start) = run_start {
+                bands.push((start, window_end));
+            }
+            return bands;
+        }
+
+        // Dark at the window start if the sun last set, or — with nothing
+        // before the window — if the next thing it does is rise.
+        let mut dark = match events.iter().rev().find(|(offset, _)| *offset <= 0.0) {
+            Some(&(_, is_sunset)) => is_sunset,
+            None => !events[0].1,
+        };
+        let mut start = 0.0;
+        for &(offset, is_sunset) in events
+            .iter()
+            .filter(|(offset, _)| *offset > 0.0 && *offset < window_end)
+        {
+            if is_sunset && !dark {
+                start = offset;
+                dark = true;
+            } else if !is_sunset && dark {
+                bands.push((start, offset));
+                dark = false;
+            }
+        }
+        if dark {
+            bands.push((start, window_end));
+        }
+        bands
+    }
+
     fn find_forecast_window(
         hourly_forecast_data: &[HourlyForecast],
         clock: &dyn Clock,
@@ -747,6 +1312,7 @@ impl<'a> ContextBuilder<'a> {
         graph_height: f32,
         opacity_min: f32,
         opacity_max: f32,
+        hatched: bool,
     ) -> (String, String, String) {
         svg_result.iter().fold(
             (String::new(), String::new(), String::new()),
@@ -762,8 +1328,11 @@ impl<'a> ContextBuilder<'a> {
                             graph_height,
                             opacity_min,
                             opacity_max,
+                            hatched,
                         ));
                     }
+                    // Self-contained fragments, picked up separately.
+                    GraphDataPath::Wind(_) | GraphDataPath::CloudCover(_) => {}
                 }
                 (temp_acc, feel_like_acc, rain_acc)
             },
@@ -778,6 +1347,9 @@ impl<'a> ContextBuilder<'a> {
         graph: &mut HourlyForecastGraph,
         clock: &dyn Clock,
     ) {
+        let use_gust = self.settings.render_options.use_gust_instead_of_wind;
+        let wind_speed_unit = self.settings.render_options.wind_speed_unit;
+        let precipitation_unit = self.settings.render_options.precipitation_unit;
         let mut x = 0;
         hourly_forecast_data
             .iter()
@@ -794,6 +1366,7 @@ impl<'a> ContextBuilder<'a> {
                     );
                     return;
                 }
+                let weather_code = forecast.weather_code.and_then(Result::ok);
                 // we won't push the actual hour right now
                 // we can calculate it later
                 // we push this index to make scaling graph easier
@@ -808,16 +1381,31 @@ impl<'a> ContextBuilder<'a> {
                         CurveType::PrecipitationChance(curve) => curve.add_point(
                             x as f32,
                             forecast.precipitation.chance.unwrap_or(0) as f32,
+                            precipitation_unit.from_mm(forecast.precipitation.amount()),
                             forecast.precipitation.is_primarily_snow(),
+                            weather_code,
+                        ),
+                        CurveType::WindSpeed(curve) => curve.add_point(
+                            x as f32,
+                            f32::from(forecast.wind.speed_in_unit(use_gust, wind_speed_unit)),
+                            forecast.wind.direction_deg,
                         ),
+                        CurveType::CloudCover(curve) => {
+                            curve.add_point(x as f32, forecast.cloud_cover)
+                        }
                     }
                 }
                 graph.uv_data[x] = forecast.uv_index;
+                let hour_cell = self.hour_cell(forecast);
+                self.context.hours.push(hour_cell);
 
                 let chance = forecast.precipitation.chance.unwrap_or(0);
                 let precip_mm = forecast.precipitation.amount();
                 let is_snow = forecast.precipitation.is_primarily_snow();
-                let pattern = HourlyForecastGraph::select_precipitation_pattern(is_snow);
+                self.context.values.has_snow |= is_snow;
+                self.context.values.has_precipitation |= precip_mm > 0.0;
+                let pattern =
+                    HourlyForecastGraph::select_precipitation_pattern(is_snow, weather_code);
                 logger::debug(format!(
                     "h{:02}: temp={:>5.1}° feels={:>5.1}° precip={:>3}% {:>5.2}mm  uv={:>2}  snow={:<5}  → {}",
                     x,
@@ -834,6 +1422,40 @@ impl<'a> ContextBuilder<'a> {
             });
     }
 
+    /// Builds the iterable cell for one hour of the chart window.
+    fn hour_cell(&self, forecast: &HourlyForecast) -> HourCell {
+        let render_options = &self.settings.render_options;
+        let hour = forecast
+            .time
+            .with_timezone(&self.settings.misc.timezone)
+            .hour();
+        HourCell {
+            time: format_hour_label(
+                hour as f32,
+                render_options.hour_format,
+                render_options.language,
+            ),
+            hour,
+            is_night: forecast.is_night,
+            icon: forecast.icon_path(&self.icon_ctx),
+            temperature: forecast.temperature.to_string(),
+            feels_like: forecast.apparent_temperature.to_string(),
+            temperature_value: *forecast.temperature,
+            feels_like_value: *forecast.apparent_temperature,
+            precipitation_chance: forecast.precipitation.chance,
+            precipitation_amount: render_options
+                .precipitation_unit
+                .from_mm(forecast.precipitation.amount()),
+            wind_speed: forecast.wind.speed_in_unit(
+                render_options.use_gust_instead_of_wind,
+                render_options.wind_speed_unit,
+            ),
+            wind_direction_deg: forecast.wind.direction_deg,
+            uv_index: forecast.uv_index,
+            relative_humidity: forecast.relative_humidity,
+        }
+    }
+
     fn with_current_hour_data(
         &mut self,
         current_hour: &HourlyForecast,
@@ -849,6 +1471,32 @@ impl<'a> ContextBuilder<'a> {
         );
         self.context.current_hour_rain_amount = current_hour.precipitation.amount().to_string();
 
+        let render_options = &self.settings.render_options;
+        let temperature = *current_hour.temperature;
+        let values = &mut self.context.values;
+        values.temperature = Some(temperature);
+        values.feels_like = Some(*current_hour.apparent_temperature);
+        values.precipitation_chance = current_hour.precipitation.chance;
+        values.precipitation_amount = Some(
+            render_options
+                .precipitation_unit
+                .from_mm(current_hour.precipitation.amount()),
+        );
+        values.wind_speed = Some(current_hour.wind.speed_in_unit(
+            render_options.use_gust_instead_of_wind,
+            render_options.wind_speed_unit,
+        ));
+        values.wind_direction_deg = current_hour.wind.direction_deg;
+        values.uv_index = Some(current_hour.uv_index);
+        values.relative_humidity = Some(current_hour.relative_humidity);
+        values.weather_code = current_hour.weather_code.map(|code| match code {
+            Ok(code) => code.code(),
+            Err(raw) => raw,
+        });
+        values.is_night = current_hour.is_night;
+        values.is_hot = temperature >= hot_threshold(render_options.temp_unit);
+        values.is_freezing = temperature <= freezing_point(render_options.temp_unit);
+
         self
     }
 
@@ -860,6 +1508,19 @@ impl<'a> ContextBuilder<'a> {
                 self.settings.render_options.wind_speed_unit,
             )
             .to_string();
+        self.context.current_hour_wind_direction_arrow = current_hour
+            .wind
+            .direction_deg
+            .map(|direction| {
+                wind_direction_arrow_svg(
+                    direction,
+                    CURRENT_WIND_ARROW_X,
+                    CURRENT_WIND_ARROW_Y,
+                    CURRENT_WIND_ARROW_SIZE,
+                    &self.context.text_colour,
+                )
+            })
+            .unwrap_or_default();
         self.context.current_hour_wind_icon = current_hour.wind.icon_path(&self.icon_ctx);
         self.context.current_hour_uv_index = current_hour.uv_index.to_string();
         self.context.current_hour_uv_index_icon =
@@ -930,6 +1591,7 @@ impl<'a> ContextBuilder<'a> {
                     self.settings.render_options.wind_speed_unit,
                 );
                 self.context.max_gust_speed = converted.to_string();
+                self.context.values.max_wind_speed = Some(converted);
                 if is_tomorrow {
                     self.context.max_gust_speed_font_style = FontStyle::Italic.to_string();
                 }
@@ -943,6 +1605,7 @@ impl<'a> ContextBuilder<'a> {
         match pick_today_or_tomorrow_max(max_uv_index_today, max_uv_index_tomorrow) {
             Some((value, is_tomorrow)) => {
                 self.context.max_uv_index = value.to_string();
+                self.context.values.max_uv_index = Some(value);
                 if is_tomorrow {
                     self.context.max_uv_index_font_style = FontStyle::Italic.to_string();
                 }
@@ -996,6 +1659,52 @@ impl<'a> ContextBuilder<'a> {
         self.update_warning_display();
         self
     }
+
+    /// Sets the data age label from when the forecast shown was fetched.
+    pub fn with_data_age(&mut self, data_age: &DataAge) -> &mut Self {
+        let settings = &self.settings.data_age;
+        let level = data_age.level(settings.stale_after_hours);
+        self.context.data_age_label = data_age.label(
+            self.settings.misc.timezone,
+            self.settings.render_options.language,
+        );
+        self.context.data_age_font_size = level.font_size().to_string();
+        self.context.data_age_font_weight = level.font_weight().to_string();
+        self.context.data_age_visibility = if settings.show_label {
+            ElementVisibility::Visible
+        } else {
+            ElementVisibility::Hidden
+        }
+        .to_string();
+        self.context.values.data_age_minutes = data_age.age.num_minutes();
+        self
+    }
+
+    /// Sets the summary line under the date, when `show_summary` is on,
+    /// fitted to `max_width`.
+    pub fn with_forecast_summary(
+        &mut self,
+        hourly_forecast_data: &[HourlyForecast],
+        daily_forecast_data: &[DailyForecast],
+        clock: &dyn Clock,
+        max_width: f32,
+    ) -> &mut Self {
+        if !self.settings.render_options.show_summary {
+            return self;
+        }
+        let summary = summary::forecast_summary(
+            hourly_forecast_data,
+            daily_forecast_data,
+            clock.now_utc(),
+            self.settings,
+            max_width,
+        );
+        if !summary.is_empty() {
+            self.context.forecast_summary = escape_xml_text(&summary);
+            self.context.forecast_summary_visibility = ElementVisibility::Visible.to_string();
+        }
+        self
+    }
 }
 
 #[cfg(test)]
@@ -1076,6 +1785,8 @@ mod tests {
                         }),
                         cloud_cover: None,
                         weather_code: None,
+                        summary: None,
+                        short_summary: None,
                     }
                 })
                 .collect()
@@ -1452,6 +2163,8 @@ mod tests {
                     }),
                     cloud_cover: None,
                     weather_code: None,
+                    summary: None,
+                    short_summary: None,
                 },
                 DailyForecast {
                     date: Some(NaiveDate::from_ymd_opt(2025, 12, 18).unwrap()),
@@ -1461,6 +2174,8 @@ mod tests {
                     astronomical: None,
                     cloud_cover: None,
                     weather_code: None,
+                    summary: None,
+                    short_summary: None,
                 },
                 DailyForecast {
                     date: Some(NaiveDate::from_ymd_opt(2025, 12, 19).unwrap()),
@@ -1470,6 +2185,8 @@ mod tests {
                     astronomical: None,
                     cloud_cover: None,
                     weather_code: None,
+                    summary: None,
+                    short_summary: None,
                 },
                 DailyForecast {
                     date: Some(NaiveDate::from_ymd_opt(2025, 12, 20).unwrap()),
@@ -1479,6 +2196,8 @@ mod tests {
                     astronomical: None,
                     cloud_cover: None,
                     weather_code: None,
+                    summary: None,
+                    short_summary: None,
                 },
                 DailyForecast {
                     date: Some(NaiveDate::from_ymd_opt(2025, 12, 21).unwrap()),
@@ -1488,6 +2207,8 @@ mod tests {
                     astronomical: None,
                     cloud_cover: None,
                     weather_code: None,
+                    summary: None,
+                    short_summary: None,
                 },
                 DailyForecast {
                     date: Some(NaiveDate::from_ymd_opt(2025, 12, 22).unwrap()),
@@ -1497,6 +2218,8 @@ mod tests {
                     astronomical: None,
                     cloud_cover: None,
                     weather_code: None,
+                    summary: None,
+                    short_summary: None,
                 },
                 DailyForecast {
                     date: Some(NaiveDate::from_ymd_opt(2025, 12, 23).unwrap()),
@@ -1506,6 +2229,8 @@ mod tests {
                     astronomical: None,
                     cloud_cover: None,
                     weather_code: None,
+                    summary: None,
+                    short_summary: None,
                 },
             ];
 
@@ -1565,6 +2290,8 @@ mod tests {
                 astronomical: None,
                 cloud_cover: None,
                 weather_code: None,
+                summary: None,
+                short_summary: None,
             })
             .collect();
 
@@ -1609,6 +2336,8 @@ mod tests {
                 astronomical: None,
                 cloud_cover: None,
                 weather_code: None,
+                summary: None,
+                short_summary: None,
             })
             .collect();
 
@@ -1626,4 +2355,634 @@ mod tests {
             assert_eq!(context.day7_maxtemp, "18"); // Dec 25 → day7
         }
     }
+
+    mod night_bands {
+        use super::*;
+        use chrono::{NaiveDate, TimeZone};
+
+        fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+            NaiveDate::from_ymd_opt(2025, 12, day)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap()
+        }
+
+        fn astro(day: u32) -> Astronomical {
+            Astronomical {
+                sunrise_time: Some(at(day, 6, 0)),
+                sunset_time: Some(at(day, 20, 30)),
+            }
+        }
+
+        #[test]
+        fn window_starting_in_daylight_has_one_interior_band() {
+            let bands = ContextBuilder::compute_night_bands(
+                &[astro(19), astro(20)],
+                &[false; 24],
+                at(19, 12, 0),
+            );
+            assert_eq!(bands, vec![(8.5, 18.0)]);
+        }
+
+        #[test]
+        fn window_starting_at_night_is_shaded_from_the_left_edge() {
+            let bands = ContextBuilder::compute_night_bands(
+                &[astro(19), astro(20)],
+                &[false; 24],
+                at(19, 2, 0),
+            );
+            assert_eq!(bands, vec![(0.0, 4.0), (18.5, 23.0)]);
+        }
+
+        #[test]
+        fn missing_astronomical_falls_back_to_is_night() {
+            let mut is_night = [false; 24];
+            is_night[..3].fill(true);
+            is_night[20..].fill(true);
+            let bands = ContextBuilder::compute_night_bands(&[], &is_night, at(19, 2, 0));
+            assert_eq!(bands, vec![(0.0, 2.5), (19.5, 23.0)]);
+        }
+
+        #[test]
+        fn shading_is_rendered_only_when_enabled() {
+            let start = Utc.with_ymd_and_hms(2025, 12, 19, 2, 0, 0).unwrap();
+            let clock = FixedClock::new(start);
+            let hourly: Vec<HourlyForecast> = (0..24)
+                .map(|h| HourlyForecast {
+                    time: start + chrono::Duration::hours(h),
+                    temperature: Temperature::celsius(15.0),
+                    apparent_temperature: Temperature::celsius(14.0),
+                    wind: crate::domain::models::Wind::new(10, 20),
+                    precipitation: crate::domain::models::Precipitation::new(
+                        Some(0),
+                        None,
+                        Some(0),
+                    ),
+                    uv_index: 0,
+                    relative_humidity: 50,
+                    is_night: h >= 12,
+                    cloud_cover: None,
+                    weather_code: None,
+                })
+                .collect();
+
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            let mut builder = ContextBuilder::new(&settings, &clock);
+            builder.with_hourly_forecast_data(hourly.clone(), &clock);
+            assert!(builder.context.night_bands_svg.is_empty());
+
+            settings.render_options.night_shading = crate::configs::settings::NightShading::Solid;
+            let mut builder = ContextBuilder::new(&settings, &clock);
+            builder.with_hourly_forecast_data(hourly, &clock);
+            assert!(builder.context.night_bands_svg.contains("<rect"));
+        }
+    }
+
+    mod cloud_cover {
+        use super::*;
+        use crate::domain::models::{Precipitation, Wind};
+        use chrono::TimeZone;
+
+        fn build(settings: &DashboardSettings, cloud_cover: Option<u16>) -> Context {
+            let start = Utc.with_ymd_and_hms(2025, 12, 19, 2, 0, 0).unwrap();
+            let clock = FixedClock::new(start);
+            let hourly = (0..24)
+                .map(|h| HourlyForecast {
+                    time: start + chrono::Duration::hours(h),
+                    temperature: Temperature::celsius(15.0),
+                    apparent_temperature: Temperature::celsius(14.0),
+                    wind: Wind::new(10, 20),
+                    precipitation: Precipitation::new(Some(0), None, Some(0)),
+                    uv_index: 0,
+                    relative_humidity: 50,
+                    is_night: false,
+                    cloud_cover,
+                    weather_code: None,
+                })
+                .collect();
+            let mut builder = ContextBuilder::new(settings, &clock);
+            builder.with_hourly_forecast_data(hourly, &clock);
+            builder.context
+        }
+
+        #[test]
+        fn strip_is_omitted_unless_enabled() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            assert!(!settings.render_options.show_cloud_cover);
+            assert!(build(&settings, Some(100)).cloud_cover_svg.is_empty());
+        }
+
+        #[test]
+        fn strip_is_drawn_when_enabled() {
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.render_options.show_cloud_cover = true;
+            let context = build(&settings, Some(100));
+            assert!(context
+                .cloud_cover_svg
+                .contains(r#"height="8" fill="black""#));
+        }
+
+        #[test]
+        fn strip_is_omitted_without_cloud_data() {
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.render_options.show_cloud_cover = true;
+            assert!(build(&settings, None).cloud_cover_svg.is_empty());
+        }
+    }
+
+    mod iterable_collections {
+        use super::*;
+        use crate::domain::models::{Precipitation, Wind};
+        use chrono::TimeZone;
+
+        #[test]
+        fn days_start_today_and_mirror_the_flat_fields() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            // Dec 17, 2025 12:00 Melbourne; only tomorrow has a forecast.
+            let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 12, 17, 1, 0, 0).unwrap());
+            let tomorrow = DailyForecast {
+                date: NaiveDate::from_ymd_opt(2025, 12, 18),
+                temp_max: Some(Temperature::celsius(24.6)),
+                temp_min: Some(Temperature::celsius(11.2)),
+                precipitation: Some(Precipitation::new(Some(40), None, Some(3))),
+                astronomical: None,
+                cloud_cover: None,
+                weather_code: None,
+                summary: None,
+                short_summary: None,
+            };
+            let mut builder = ContextBuilder::new(&settings, &clock);
+            builder.with_daily_forecast_data(vec![tomorrow], &clock);
+            let context = builder.context;
+
+            assert_eq!(context.days.len(), 7);
+            assert!(context.days[0].is_today);
+            assert_eq!(context.days[0].date, "2025-12-17");
+            assert_eq!(context.days[0].max_temp, NOT_AVAILABLE);
+
+            let card = &context.days[1];
+            assert_eq!(card.name, context.day2_name);
+            assert_eq!(card.max_temp, context.day2_maxtemp);
+            assert_eq!(card.min_temp, "11");
+            assert_eq!(card.max_temp_value, Some(24.6));
+            assert_eq!(card.precipitation_chance, Some(40));
+            assert_eq!(card.precipitation_amount, Some(3.0));
+            assert_eq!(context.days[6].min_temp_value, None);
+        }
+
+        #[test]
+        fn hours_follow_the_chart_window() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            let start = Utc.with_ymd_and_hms(2025, 12, 19, 2, 0, 0).unwrap();
+            let clock = FixedClock::new(start);
+            let hourly = (0..30)
+                .map(|h| HourlyForecast {
+                    time: start + chrono::Duration::hours(h),
+                    temperature: Temperature::celsius(10.0 + h as f32),
+                    apparent_temperature: Temperature::celsius(9.0),
+                    wind: Wind::new(10, 20),
+                    precipitation: Precipitation::new(Some(20), None, Some(1)),
+                    uv_index: 3,
+                    relative_humidity: 50,
+                    is_night: false,
+                    cloud_cover: None,
+                    weather_code: None,
+                })
+                .collect();
+            let mut builder = ContextBuilder::new(&settings, &clock);
+            builder.with_hourly_forecast_data(hourly, &clock);
+            let hours = builder.context.hours;
+
+            assert_eq!(hours.len(), 24);
+            // 02:00 UTC is 1pm in Melbourne (AEDT).
+            assert_eq!(hours[0].hour, 13);
+            assert_eq!(hours[0].time, "1pm");
+            assert_eq!(hours[0].temperature, "10");
+            assert_eq!(hours[23].temperature_value, 33.0);
+            assert_eq!(hours[0].wind_speed, 10);
+            assert_eq!(hours[0].precipitation_chance, Some(20));
+        }
+    }
+
+    mod typed_values {
+        use super::*;
+        use crate::domain::models::{Precipitation, Wind};
+        use crate::domain::weather_code::WmoWeatherCode;
+        use chrono::TimeZone;
+
+        /// 30 hours from 1pm Melbourne, starting at -2° and warming a degree
+        /// an hour; only the fourth hour snows.
+        fn build(start: DateTime<Utc>) -> Context {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            let clock = FixedClock::new(start);
+            let hourly = (0..30)
+                .map(|h| HourlyForecast {
+                    time: start + chrono::Duration::hours(h),
+                    temperature: Temperature::celsius(-2.0 + h as f32),
+                    apparent_temperature: Temperature::celsius(-5.0),
+                    wind: Wind::new(10 + h as u16, 30),
+                    precipitation: if h == 3 {
+                        Precipitation::new_with_snowfall(Some(70), Some(1), Some(1), Some(20))
+                    } else {
+                        Precipitation::new(Some(10), None, None)
+                    },
+                    uv_index: 2,
+                    relative_humidity: 80,
+                    is_night: false,
+                    cloud_cover: None,
+                    weather_code: Some(Ok(WmoWeatherCode::SnowSlight)),
+                })
+                .collect();
+            let mut builder = ContextBuilder::new(&settings, &clock);
+            builder.with_hourly_forecast_data(hourly, &clock);
+            builder.context
+        }
+
+        #[test]
+        fn current_hour_values_are_numbers() {
+            let context = build(Utc.with_ymd_and_hms(2025, 12, 19, 2, 0, 0).unwrap());
+            let values = &context.values;
+            assert_eq!(values.temperature, Some(-2.0));
+            assert_eq!(values.feels_like, Some(-5.0));
+            assert_eq!(values.precipitation_chance, Some(10));
+            assert_eq!(values.relative_humidity, Some(80));
+            assert_eq!(values.uv_index, Some(2));
+            assert_eq!(values.weather_code, Some(71));
+            assert!(values.is_freezing);
+            assert!(!values.is_hot);
+            assert!(!values.is_night);
+        }
+
+        #[test]
+        fn window_flags_cover_every_chart_hour() {
+            let context = build(Utc.with_ymd_and_hms(2025, 12, 19, 2, 0, 0).unwrap());
+            let values = &context.values;
+            assert!(values.has_snow);
+            assert!(values.has_precipitation);
+            assert!(values.total_precipitation.is_some_and(|total| total > 0.0));
+            assert_eq!(values.max_uv_index, Some(2));
+            assert!(values.max_wind_speed.is_some());
+        }
+
+        #[test]
+        fn has_warning_follows_the_diagnostics() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 12, 19, 2, 0, 0).unwrap());
+            let mut builder = ContextBuilder::new(&settings, &clock);
+            assert!(!builder.context.values.has_warning);
+
+            builder.with_warning(DashboardError::IncompleteData {
+                details: "Only 5 days available".to_string(),
+            });
+            assert!(builder.context.values.has_warning);
+        }
+    }
+
+    mod outlook_page {
+        use super::*;
+        use chrono::TimeZone;
+
+        #[test]
+        fn outlook_fills_forecast_days_and_leaves_the_rest_not_available() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            // Dec 17, 2025 12:00 Melbourne.
+            let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 12, 17, 1, 0, 0).unwrap());
+            let daily: Vec<DailyForecast> = (17..=19)
+                .map(|d| DailyForecast {
+                    date: NaiveDate::from_ymd_opt(2025, 12, d),
+                    temp_max: Some(Temperature::celsius(25.0)),
+                    temp_min: Some(Temperature::celsius(12.0)),
+                    precipitation: None,
+                    astronomical: None,
+                    cloud_cover: None,
+                    weather_code: None,
+                    summary: None,
+                    short_summary: None,
+                })
+                .collect();
+
+            let mut builder = ContextBuilder::new(&settings, &clock);
+            builder.with_outlook_data(&daily, &clock);
+            let grid = &builder.context.outlook_grid_svg;
+
+            assert!(grid.contains(">Wed 17</text>"), "{grid}");
+            assert!(grid.contains(">Tue 30</text>"), "{grid}");
+            assert_eq!(grid.matches(">25° | 12°</text>").count(), 3);
+            assert_eq!(grid.matches(">N/A</text>").count(), 11);
+            assert!(builder.context.outlook_trend_svg.contains("<polyline"));
+            assert!(builder.diagnostics.is_empty());
+        }
+    }
+
+    mod daily_range_bars {
+        use super::*;
+        use crate::configs::settings::DailyLayout;
+        use crate::domain::models::Precipitation;
+        use chrono::TimeZone;
+
+        /// Tomorrow and the day after only; the rest of the week is missing.
+        fn build(settings: &DashboardSettings) -> Context {
+            let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 12, 17, 1, 0, 0).unwrap());
+            let day = |d: u32, min: f32, max: f32, chance: u16, mm: u16| DailyForecast {
+                date: NaiveDate::from_ymd_opt(2025, 12, d),
+                temp_max: Some(Temperature::celsius(max)),
+                temp_min: Some(Temperature::celsius(min)),
+                precipitation: Some(Precipitation::new(Some(chance), None, Some(mm))),
+                astronomical: None,
+                cloud_cover: None,
+                weather_code: None,
+                summary: None,
+                short_summary: None,
+            };
+            let mut builder = ContextBuilder::new(settings, &clock);
+            builder.with_daily_forecast_data(
+                vec![day(18, 10.0, 20.0, 60, 3), day(19, 15.0, 30.0, 0, 0)],
+                &clock,
+            );
+            builder.context
+        }
+
+        #[test]
+        fn numbers_layout_leaves_range_bars_empty() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            assert_eq!(settings.render_options.daily_layout, DailyLayout::Numbers);
+            let context = build(&settings);
+            assert_eq!(context.daily_numbers_visibility, "visible");
+            assert!(context.day2_range_bar.is_empty());
+        }
+
+        #[test]
+        fn range_bars_share_one_scale_and_hide_numbers() {
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.render_options.daily_layout = DailyLayout::RangeBars;
+            let context = build(&settings);
+            assert_eq!(context.daily_numbers_visibility, "hidden");
+            // Week spans 10..30: tomorrow fills the first half, the next
+            // day the last three quarters.
+            assert!(
+                context
+                    .day2_range_bar
+                    .contains(r#"x="20.00" y="142" width="32.00""#),
+                "{}",
+                context.day2_range_bar
+            );
+            assert!(context.day2_range_bar.contains(">60% 3mm</text>"));
+            assert!(
+                context
+                    .day3_range_bar
+                    .contains(r#"x="36.00" y="142" width="48.00""#),
+                "{}",
+                context.day3_range_bar
+            );
+            assert!(context.day7_range_bar.contains(">N/A</text>"));
+        }
+    }
+
+    mod temp_extremes {
+        use super::*;
+        use crate::domain::models::{Precipitation, Wind};
+        use chrono::TimeZone;
+
+        fn build(settings: &DashboardSettings) -> Context {
+            let start = Utc.with_ymd_and_hms(2025, 12, 19, 2, 0, 0).unwrap();
+            let clock = FixedClock::new(start);
+            let hourly = (0..24)
+                .map(|h| HourlyForecast {
+                    time: start + chrono::Duration::hours(h),
+                    temperature: Temperature::celsius(10.0 + (h % 12) as f32),
+                    apparent_temperature: Temperature::celsius(9.0 + (h % 12) as f32),
+                    wind: Wind::new(10, 20),
+                    precipitation: Precipitation::new(Some(0), None, Some(0)),
+                    uv_index: 0,
+                    relative_humidity: 50,
+                    is_night: false,
+                    cloud_cover: None,
+                    weather_code: None,
+                })
+                .collect();
+            let mut builder = ContextBuilder::new(settings, &clock);
+            builder.with_hourly_forecast_data(hourly, &clock);
+            builder.context
+        }
+
+        #[test]
+        fn extremes_are_labelled_by_default() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            assert!(settings.render_options.show_temp_extremes);
+            let svg = build(&settings).temp_extremes_svg;
+            assert!(svg.contains(">21°</text>"), "{svg}");
+            assert!(svg.contains(">10°</text>"), "{svg}");
+        }
+
+        #[test]
+        fn extremes_are_omitted_when_disabled() {
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.render_options.show_temp_extremes = false;
+            assert!(build(&settings).temp_extremes_svg.is_empty());
+        }
+    }
+
+    mod wind_series {
+        use super::*;
+        use crate::domain::models::{Precipitation, Wind};
+        use chrono::TimeZone;
+
+        /// 24 hours of forecast from `start`, each with a steady westerly.
+        fn hourly_forecasts(start: DateTime<Utc>, direction: Option<u16>) -> Vec<HourlyForecast> {
+            (0..24)
+                .map(|h| HourlyForecast {
+                    time: start + chrono::Duration::hours(h),
+                    temperature: Temperature::celsius(15.0 + h as f32 / 4.0),
+                    apparent_temperature: Temperature::celsius(14.0),
+                    wind: Wind::new(20, 35).with_direction(direction),
+                    precipitation: Precipitation::new(Some(0), None, Some(0)),
+                    uv_index: 0,
+                    relative_humidity: 50,
+                    is_night: false,
+                    cloud_cover: None,
+                    weather_code: None,
+                })
+                .collect()
+        }
+
+        fn build(settings: &DashboardSettings, direction: Option<u16>) -> Context {
+            let start = Utc.with_ymd_and_hms(2025, 12, 19, 2, 0, 0).unwrap();
+            let clock = FixedClock::new(start);
+            let mut builder = ContextBuilder::new(settings, &clock);
+            builder.with_hourly_forecast_data(hourly_forecasts(start, direction), &clock);
+            builder.context
+        }
+
+        #[test]
+        fn current_hour_arrow_follows_direction() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            let context = build(&settings, Some(270));
+            assert!(context
+                .current_hour_wind_direction_arrow
+                .contains("rotate(90)"));
+        }
+
+        #[test]
+        fn current_hour_arrow_is_empty_without_direction() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            let context = build(&settings, None);
+            assert!(context.current_hour_wind_direction_arrow.is_empty());
+        }
+
+        #[test]
+        fn series_is_omitted_unless_enabled() {
+            let settings = DashboardSettings::load_test_config().unwrap();
+            assert!(!settings.render_options.show_wind_series);
+            let context = build(&settings, Some(270));
+            assert!(context.wind_series_svg.is_empty());
+        }
+
+        #[test]
+        fn series_uses_gust_speed_when_configured() {
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.render_options.show_wind_series = true;
+            settings.render_options.use_gust_instead_of_wind = true;
+            let context = build(&settings, Some(270));
+            assert!(context.wind_series_svg.contains(">35</text>"));
+            assert!(!context.wind_series_svg.contains(">20</text>"));
+        }
+    }
+
+    mod night_palette {
+        use super::*;
+        use crate::configs::settings::{Colour, HourOfDay, NightSchedule};
+        use crate::domain::models::{Precipitation, Wind};
+        use chrono::TimeZone;
+
+        /// Night swaps the background and text only.
+        fn settings(schedule: NightSchedule) -> DashboardSettings {
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            let night = &mut settings.colours.night;
+            night.schedule = schedule;
+            night.start_hour = HourOfDay::try_new(19).unwrap();
+            night.end_hour = HourOfDay::try_new(7).unwrap();
+            night.background_colour = Some(Colour::try_new("black").unwrap());
+            night.text_colour = Some(Colour::try_new("white").unwrap());
+            settings
+        }
+
+        /// Melbourne local time (AEDT, UTC+11) in December 2025.
+        fn local(day: u32, hour: u32, minute: u32) -> FixedClock {
+            FixedClock::new(
+                Utc.with_ymd_and_hms(2025, 12, day, hour, minute, 0)
+                    .unwrap()
+                    - chrono::Duration::hours(11),
+            )
+        }
+
+        /// Sunrise at 06:00 and sunset at 20:30 each day.
+        fn daily() -> Vec<DailyForecast> {
+            (17..=18)
+                .map(|day| {
+                    let date = NaiveDate::from_ymd_opt(2025, 12, day).unwrap();
+                    DailyForecast {
+                        date: Some(date),
+                        temp_max: Some(Temperature::celsius(25.0)),
+                        temp_min: Some(Temperature::celsius(12.0)),
+                        precipitation: None,
+                        astronomical: Some(Astronomical {
+                            sunrise_time: date.and_hms_opt(6, 0, 0),
+                            sunset_time: date.and_hms_opt(20, 30, 0),
+                        }),
+                        cloud_cover: None,
+                        weather_code: None,
+                        summary: None,
+                        short_summary: None,
+                    }
+                })
+                .collect()
+        }
+
+        fn build(settings: &DashboardSettings, clock: &FixedClock) -> Context {
+            let mut builder = ContextBuilder::new(settings, clock);
+            builder.with_daily_forecast_data(daily(), clock);
+            builder.context
+        }
+
+        fn background(settings: &DashboardSettings, clock: FixedClock) -> String {
+            build(settings, &clock).background_colour
+        }
+
+        #[test]
+        fn sunset_schedule_switches_at_sunset() {
+            let settings = settings(NightSchedule::Sunset);
+            assert_eq!(background(&settings, local(17, 20, 29)), "white");
+            let context = build(&settings, &local(17, 20, 30));
+            assert_eq!(context.background_colour, "black");
+            assert_eq!(context.text_colour, "white");
+            // Colours the night table leaves out keep their day value.
+            assert_eq!(
+                context.rain_colour,
+                settings.colours.rain_colour.to_string()
+            );
+        }
+
+        #[test]
+        fn sunset_schedule_switches_back_at_sunrise() {
+            let settings = settings(NightSchedule::Sunset);
+            assert_eq!(background(&settings, local(18, 5, 59)), "black");
+            assert_eq!(background(&settings, local(18, 6, 0)), "white");
+        }
+
+        #[test]
+        fn sunset_schedule_stays_on_the_day_palette_without_sun_times() {
+            let settings = settings(NightSchedule::Sunset);
+            let clock = local(17, 23, 0);
+            let mut builder = ContextBuilder::new(&settings, &clock);
+            let mut days = daily();
+            days.iter_mut().for_each(|day| day.astronomical = None);
+            builder.with_daily_forecast_data(days, &clock);
+            assert_eq!(builder.context.background_colour, "white");
+        }
+
+        #[test]
+        fn fixed_schedule_switches_at_both_hours() {
+            let settings = settings(NightSchedule::Fixed);
+            assert_eq!(background(&settings, local(17, 18, 59)), "white");
+            assert_eq!(background(&settings, local(17, 19, 0)), "black");
+            assert_eq!(background(&settings, local(18, 6, 59)), "black");
+            assert_eq!(background(&settings, local(18, 7, 0)), "white");
+        }
+
+        #[test]
+        fn off_schedule_keeps_the_day_palette() {
+            let settings = settings(NightSchedule::Off);
+            assert_eq!(background(&settings, local(17, 23, 0)), "white");
+        }
+
+        #[test]
+        fn chart_and_uv_colours_follow_the_night_background() {
+            let settings = settings(NightSchedule::Sunset);
+            let clock = local(17, 21, 0);
+            let start = clock.now_utc();
+            let hourly = (0..30)
+                .map(|h| HourlyForecast {
+                    time: start + chrono::Duration::hours(h),
+                    temperature: Temperature::celsius(15.0),
+                    apparent_temperature: Temperature::celsius(14.0),
+                    wind: Wind::new(10, 20),
+                    precipitation: Precipitation::new(Some(10), None, None),
+                    uv_index: 0,
+                    relative_humidity: 60,
+                    is_night: true,
+                    cloud_cover: None,
+                    weather_code: None,
+                })
+                .collect();
+            let mut builder = ContextBuilder::new(&settings, &clock);
+            builder
+                .with_daily_forecast_data(daily(), &clock)
+                .with_hourly_forecast_data(hourly, &clock);
+            let context = builder.context;
+            // No UV overnight, so the gradient blends into the night page.
+            assert!(context.uv_gradient.contains(r#"stop-color="black""#));
+            assert!(!context.uv_gradient.contains(r#"stop-color="white""#));
+            assert!(context.x_labels.contains("white"), "{}", context.x_labels);
+        }
+    }
 }
@@ -0,0 +1,226 @@
+//! Range-bar layout for the 6-day forecast strip: each day's min–max drawn
+//! as a bar on a scale shared by the whole week, so trends read at a glance,
+//! with that day's precipitation chance and amount underneath.
+
+use crate::{
+    configs::settings::PrecipitationUnit,
+    constants::NOT_AVAILABLE,
+    domain::models::{DailyForecast, Temperature},
+};
+
+/// Horizontal extent of the bar track, in a day card's own `<svg>`
+/// coordinates — centred on the card's x=52 like the numeric layout's
+/// tspans, and narrow enough that neighbouring cards' labels (81px apart)
+/// don't touch. Must stay in sync with the template.
+const TRACK_X: f32 = 20.0;
+const TRACK_WIDTH: f32 = 64.0;
+/// Vertical layout in card coordinates: min/max labels, then the bar, then
+/// the precipitation line, all between the icon and the hourly chart.
+const LABEL_Y: f32 = 138.0;
+const BAR_Y: f32 = 142.0;
+const BAR_HEIGHT: f32 = 6.0;
+const PRECIPITATION_Y: f32 = 162.0;
+const LABEL_FONT_SIZE: u16 = 14;
+const PRECIPITATION_FONT_SIZE: u16 = 13;
+
+/// What a day card's range bar needs from the daily forecast.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DailyRange {
+    pub min: Option<Temperature>,
+    pub max: Option<Temperature>,
+    pub precipitation_chance: Option<u16>,
+    /// Expected amount, already in the configured precipitation unit.
+    pub precipitation_amount: Option<f32>,
+}
+
+impl DailyRange {
+    pub fn from_forecast(forecast: Option<&DailyForecast>, unit: PrecipitationUnit) -> Self {
+        let Some(forecast) = forecast else {
+            return Self::default();
+        };
+        Self {
+            min: forecast.temp_min,
+            max: forecast.temp_max,
+            precipitation_chance: forecast.precipitation.as_ref().and_then(|p| p.chance),
+            precipitation_amount: forecast
+                .precipitation
+                .as_ref()
+                .map(|p| unit.from_mm(p.amount())),
+        }
+    }
+
+    pub(crate) fn bounds(&self) -> Option<(f32, f32)> {
+        let (min, max) = (*self.min?, *self.max?);
+        Some((min.min(max), min.max(max)))
+    }
+}
+
+/// Lowest min and highest max across every day with both values, or `None`
+/// when no day has a complete range.
+pub fn week_scale(days: &[DailyRange]) -> Option<(f32, f32)> {
+    days.iter()
+        .filter_map(DailyRange::bounds)
+        .fold(None, |scale, (min, max)| match scale {
+            None => Some((min, max)),
+            Some((lo, hi)) => Some((min.min(lo), max.max(hi))),
+        })
+}
+
+/// Formats an amount for the precipitation line: whole millimetres (one
+/// decimal below 1mm, so a wet day never reads "0mm"), or two decimals of
+/// an inch.
+pub(crate) fn format_amount(amount: f32, unit: PrecipitationUnit) -> String {
+    match unit {
+        PrecipitationUnit::Mm if amount < 1.0 => format!("{amount:.1}{unit}"),
+        PrecipitationUnit::Mm => format!("{amount:.0}{unit}"),
+        PrecipitationUnit::In => format!("{amount:.2}{unit}"),
+    }
+}
+
+/// "60% 3mm"-style line for a day: chance, plus the amount when it's wet.
+/// `None` when the forecast has neither.
+pub fn precipitation_summary(day: &DailyRange, unit: PrecipitationUnit) -> Option<String> {
+    match (day.precipitation_chance, day.precipitation_amount) {
+        (Some(chance), Some(amount)) if amount > 0.0 => {
+            Some(format!("{chance}% {}", format_amount(amount, unit)))
+        }
+        (Some(chance), _) => Some(format!("{chance}%")),
+        (None, Some(amount)) if amount > 0.0 => Some(format_amount(amount, unit)),
+        _ => None,
+    }
+}
+
+/// Renders one day's range bar: min and max labels over a track spanning
+/// `scale`, the day's segment filled in `bar_colour`, and chance/amount
+/// below. The amount is left off on dry days. A day without a complete
+/// range gets an empty track and "N/A".
+pub fn range_bar_svg(
+    day: &DailyRange,
+    scale: Option<(f32, f32)>,
+    unit: PrecipitationUnit,
+    text_colour: &str,
+    bar_colour: &str,
+) -> String {
+    let centre_x = TRACK_X + TRACK_WIDTH / 2.0;
+    let track = format!(
+        r#"<rect x="{TRACK_X}" y="{BAR_Y}" width="{TRACK_WIDTH}" height="{BAR_HEIGHT}" rx="{r}" fill="none" stroke="{text_colour}" stroke-width="1"/>"#,
+        r = BAR_HEIGHT / 2.0,
+    );
+
+    let range = match (day.bounds(), day.min, day.max, scale) {
+        (Some((lo, hi)), Some(min), Some(max), Some((scale_lo, scale_hi))) => {
+            let span = scale_hi - scale_lo;
+            let to_x = |t: f32| {
+                if span > 0.0 {
+                    TRACK_X + (t - scale_lo) / span * TRACK_WIDTH
+                } else {
+                    TRACK_X
+                }
+            };
+            let x_start = to_x(lo);
+            // A flat week still shows a full bar rather than nothing.
+            let width = if span > 0.0 {
+                (to_x(hi) - x_start).max(BAR_HEIGHT)
+            } else {
+                TRACK_WIDTH
+            };
+            let x_start = x_start.min(TRACK_X + TRACK_WIDTH - width);
+            format!(
+                r#"<rect x="{x_start:.2}" y="{BAR_Y}" width="{width:.2}" height="{BAR_HEIGHT}" rx="{r}" fill="{bar_colour}"/><text x="{TRACK_X}" y="{LABEL_Y}" font-size="{LABEL_FONT_SIZE}" fill="{text_colour}" text-anchor="start">{min}°</text><text x="{track_end}" y="{LABEL_Y}" font-size="{LABEL_FONT_SIZE}" fill="{text_colour}" text-anchor="end">{max}°</text>"#,
+                r = BAR_HEIGHT / 2.0,
+                track_end = TRACK_X + TRACK_WIDTH,
+            )
+        }
+        _ => format!(
+            r#"<text x="{centre_x}" y="{LABEL_Y}" font-size="{LABEL_FONT_SIZE}" fill="{text_colour}" text-anchor="middle">{NOT_AVAILABLE}</text>"#
+        ),
+    };
+
+    let precipitation = precipitation_summary(day, unit).map_or_else(String::new, |summary| {
+        format!(
+            r#"<text x="{centre_x}" y="{PRECIPITATION_Y}" font-size="{PRECIPITATION_FONT_SIZE}" fill="{text_colour}" text-anchor="middle">{summary}</text>"#
+        )
+    });
+
+    format!("<g>{track}{range}{precipitation}</g>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(min: f32, max: f32, chance: Option<u16>, amount: Option<f32>) -> DailyRange {
+        DailyRange {
+            min: Some(Temperature::celsius(min)),
+            max: Some(Temperature::celsius(max)),
+            precipitation_chance: chance,
+            precipitation_amount: amount,
+        }
+    }
+
+    fn render(day: &DailyRange, scale: Option<(f32, f32)>) -> String {
+        range_bar_svg(day, scale, PrecipitationUnit::Mm, "black", "red")
+    }
+
+    #[test]
+    fn scale_spans_the_whole_week() {
+        let days = [
+            day(8.0, 17.0, None, None),
+            DailyRange::default(),
+            day(5.0, 12.0, None, None),
+            day(10.0, 21.0, None, None),
+        ];
+        assert_eq!(week_scale(&days), Some((5.0, 21.0)));
+        assert_eq!(week_scale(&[DailyRange::default()]), None);
+    }
+
+    #[test]
+    fn segment_is_placed_on_the_shared_scale() {
+        // 10..15 on a 5..25 scale: starts a quarter in, a quarter wide.
+        let svg = render(&day(10.0, 15.0, None, None), Some((5.0, 25.0)));
+        assert!(
+            svg.contains(r#"<rect x="36.00" y="142" width="16.00""#),
+            "{svg}"
+        );
+        assert!(svg.contains(">10°</text>"));
+        assert!(svg.contains(">15°</text>"));
+    }
+
+    #[test]
+    fn flat_week_fills_the_track() {
+        let svg = render(&day(12.0, 12.0, None, None), Some((12.0, 12.0)));
+        assert!(
+            svg.contains(r#"<rect x="20.00" y="142" width="64.00""#),
+            "{svg}"
+        );
+    }
+
+    #[test]
+    fn precipitation_line_shows_chance_and_wet_amounts() {
+        let scale = Some((0.0, 20.0));
+        assert!(render(&day(5.0, 10.0, Some(60), Some(3.0)), scale).contains(">60% 3mm</text>"));
+        assert!(render(&day(5.0, 10.0, Some(10), Some(0.0)), scale).contains(">10%</text>"));
+        assert!(render(&day(5.0, 10.0, Some(30), Some(0.4)), scale).contains(">30% 0.4mm</text>"));
+        assert_eq!(
+            render(&day(5.0, 10.0, None, None), scale)
+                .matches("<text")
+                .count(),
+            2
+        );
+        let inches = range_bar_svg(
+            &day(5.0, 10.0, Some(40), Some(0.12)),
+            scale,
+            PrecipitationUnit::In,
+            "black",
+            "red",
+        );
+        assert!(inches.contains(">40% 0.12in</text>"), "{inches}");
+    }
+
+    #[test]
+    fn missing_day_shows_not_available() {
+        let svg = render(&DailyRange::default(), Some((0.0, 20.0)));
+        assert!(svg.contains(">N/A</text>"));
+        assert_eq!(svg.matches("<rect").count(), 1);
+    }
+}
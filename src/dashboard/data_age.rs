@@ -0,0 +1,146 @@
+//! How old the forecast on the dashboard is (`[data_age]`): the "Updated
+//! 14:05" / "Data 2 d old" label, and whether cached data is old enough to
+//! show the stale-data diagnostic.
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+
+use crate::i18n::{translate, Language, TranslationKey};
+
+/// How prominently the label is drawn, escalating with age.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AgeLevel {
+    Fresh,
+    /// At least `stale_after_hours` (and an hour) old.
+    Stale,
+    /// A day old or more.
+    Old,
+}
+
+impl AgeLevel {
+    pub fn font_size(self) -> u16 {
+        match self {
+            Self::Fresh => 12,
+            Self::Stale => 14,
+            Self::Old => 18,
+        }
+    }
+
+    pub fn font_weight(self) -> &'static str {
+        match self {
+            Self::Fresh => "normal",
+            Self::Stale | Self::Old => "bold",
+        }
+    }
+}
+
+/// When the forecast shown was fetched, and how long before now that was.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DataAge {
+    pub updated_at: DateTime<Utc>,
+    pub age: Duration,
+}
+
+impl DataAge {
+    /// An `updated_at` ahead of `now` (a clock set back, or a fixed clock in
+    /// a simulation) counts as fetched just now.
+    pub fn new(updated_at: DateTime<Utc>, now: DateTime<Utc>) -> Self {
+        let updated_at = updated_at.min(now);
+        Self {
+            updated_at,
+            age: now - updated_at,
+        }
+    }
+
+    /// Whether cached data this old shows the stale-data diagnostic.
+    pub fn is_stale(&self, stale_after_hours: u32) -> bool {
+        self.age >= Duration::hours(i64::from(stale_after_hours))
+    }
+
+    pub fn level(&self, stale_after_hours: u32) -> AgeLevel {
+        if self.age >= Duration::days(1) {
+            AgeLevel::Old
+        } else if self.age >= Duration::hours(i64::from(stale_after_hours.max(1))) {
+            AgeLevel::Stale
+        } else {
+            AgeLevel::Fresh
+        }
+    }
+
+    /// Whether the label shows when the data was fetched rather than how
+    /// many days old it is.
+    pub fn shows_fetch_time(&self) -> bool {
+        self.age < Duration::days(1)
+    }
+
+    /// "Updated 14:05" in `timezone`, or "Data 2 d old" from a day on.
+    pub fn label(&self, timezone: Tz, language: Language) -> String {
+        if self.shows_fetch_time() {
+            format!(
+                "{} {}",
+                translate(TranslationKey::Updated, language),
+                self.updated_at.with_timezone(&timezone).format("%H:%M")
+            )
+        } else {
+            translate(TranslationKey::DataDaysOld, language)
+                .replace("{days}", &self.age.num_days().to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339).unwrap().to_utc()
+    }
+
+    fn aged(updated_at: &str) -> DataAge {
+        DataAge::new(time(updated_at), time("2025-10-25T10:00:00Z"))
+    }
+
+    #[test]
+    fn future_update_counts_as_now() {
+        let age = aged("2026-01-01T00:00:00Z");
+
+        assert_eq!(age.updated_at, time("2025-10-25T10:00:00Z"));
+        assert_eq!(age.age, Duration::zero());
+    }
+
+    #[test]
+    fn stale_from_the_threshold() {
+        assert!(!aged("2025-10-25T07:01:00Z").is_stale(3));
+        assert!(aged("2025-10-25T07:00:00Z").is_stale(3));
+        assert!(aged("2025-10-25T10:00:00Z").is_stale(0));
+    }
+
+    #[test]
+    fn level_escalates_with_age() {
+        assert_eq!(aged("2025-10-25T09:30:00Z").level(0), AgeLevel::Fresh);
+        assert_eq!(aged("2025-10-25T08:00:00Z").level(3), AgeLevel::Fresh);
+        assert_eq!(aged("2025-10-25T07:00:00Z").level(3), AgeLevel::Stale);
+        assert_eq!(aged("2025-10-24T10:00:00Z").level(3), AgeLevel::Old);
+        assert_eq!(aged("2025-10-24T10:00:00Z").level(48), AgeLevel::Old);
+    }
+
+    #[test]
+    fn label_shows_the_local_time_then_days() {
+        let melbourne = chrono_tz::Australia::Melbourne;
+
+        assert_eq!(
+            aged("2025-10-25T03:05:00Z").label(melbourne, Language::En),
+            "Updated 14:05"
+        );
+        assert!(aged("2025-10-24T10:01:00Z").shows_fetch_time());
+        assert!(!aged("2025-10-24T10:00:00Z").shows_fetch_time());
+        assert_eq!(
+            aged("2025-10-23T03:05:00Z").label(melbourne, Language::En),
+            "Data 2 d old"
+        );
+        assert_eq!(
+            aged("2025-10-23T03:05:00Z").label(melbourne, Language::Fr),
+            "Données de 2 j"
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Full-screen error page, written in place of the dashboard when it can't
+//! be generated at all (no template, no forecast and no cache, ...).
+//!
+//! It needs no template, icons or forecast, and is drawn in black on white
+//! so every colour profile can show it. It deliberately shows no current
+//! time, so an unchanged failure renders the same page each run.
+
+use std::fs;
+use std::io::ErrorKind;
+
+use chrono::{DateTime, Utc};
+
+use crate::configs::settings::DashboardSettings;
+use crate::constants::{DAILY_CACHE_SUFFIX, HOURLY_CACHE_SUFFIX};
+use crate::dashboard::widgets::fit_to_canvas;
+use crate::i18n::{format_localized_date, translate, TranslationKey};
+use crate::providers::factory::create_provider;
+use crate::utils::escape_xml_text;
+
+/// Longest reason line, in characters, at the reason's font size.
+const LINE_LENGTH: usize = 64;
+/// Reason lines shown before eliding the rest.
+const MAX_REASON_LINES: usize = 5;
+
+/// The error page for `error`, sized for the configured display.
+pub fn render(settings: &DashboardSettings, error: &anyhow::Error) -> String {
+    let language = settings.render_options.language;
+    let reason_lines = wrap(&format!("{error:#}"), LINE_LENGTH, MAX_REASON_LINES);
+    let last_good_data = match last_good_data(settings) {
+        Some(time) => format_localized_date(
+            time.with_timezone(&settings.misc.timezone),
+            "%a %-d %b %H:%M",
+            language,
+        ),
+        None => translate(TranslationKey::NoData, language).to_string(),
+    };
+
+    let reason = reason_lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            format!(
+                r#"<text x="60" y="{y}" font-size="22">{line}</text>"#,
+                y = 200 + i * 30,
+                line = escape_xml_text(line),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ");
+
+    let svg = format!(
+        r#"<svg width="800" height="480" font-family="Roboto, sans-serif" xmlns="http://www.w3.org/2000/svg">
+    <rect width="800" height="480" fill="white" />
+    <rect x="12" y="12" width="776" height="456" fill="none" stroke="black" stroke-width="8" />
+    <circle cx="96" cy="96" r="40" fill="black" />
+    <text x="96" y="114" font-size="56" font-weight="bold" fill="white" text-anchor="middle">!</text>
+    <text x="160" y="112" font-size="48" font-weight="bold">{title}</text>
+    {reason}
+    <text x="60" y="390" font-size="24">{last_good_label}: {last_good_data}</text>
+    <text x="60" y="430" font-size="20">{hint}</text>
+</svg>
+"#,
+        title = escape_xml_text(translate(TranslationKey::UpdateFailed, language)),
+        last_good_label = escape_xml_text(translate(TranslationKey::LastGoodData, language)),
+        last_good_data = escape_xml_text(&last_good_data),
+        hint = escape_xml_text(translate(hint(error), language)),
+    );
+    fit_to_canvas(svg, settings.display.model, "white")
+}
+
+/// When the configured provider's forecast was last cached, from the newer
+/// of its cache files.
+pub fn last_good_data(settings: &DashboardSettings) -> Option<DateTime<Utc>> {
+    let provider = create_provider(settings).ok()?;
+    [HOURLY_CACHE_SUFFIX, DAILY_CACHE_SUFFIX]
+        .iter()
+        .filter_map(|suffix| {
+            let path = settings
+                .misc
+                .weather_data_cache_path
+                .join(provider.generate_cache_filename(suffix));
+            fs::metadata(path).and_then(|m| m.modified()).ok()
+        })
+        .max()
+        .map(DateTime::<Utc>::from)
+}
+
+/// What to check first for `error`.
+fn hint(error: &anyhow::Error) -> TranslationKey {
+    let missing_file = error.chain().any(|cause| {
+        cause
+            .downcast_ref::<std::io::Error>()
+            .is_some_and(|e| e.kind() == ErrorKind::NotFound)
+    });
+    if missing_file {
+        TranslationKey::MissingFileHint
+    } else {
+        TranslationKey::NetworkHint
+    }
+}
+
+/// Word-wraps `text` to `width` characters, keeping at most `max_lines` and
+/// ending the last one with "…" if anything was cut.
+fn wrap(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= width => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.chars().take(width).collect()),
+        }
+    }
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            last.push('…');
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod wrap {
+        use super::*;
+
+        #[test]
+        fn breaks_between_words() {
+            assert_eq!(
+                wrap("no cached weather data", 10, 5),
+                ["no cached", "weather", "data"]
+            );
+        }
+
+        #[test]
+        fn elides_past_the_line_limit() {
+            assert_eq!(wrap("a b c d", 1, 2), ["a", "b…"]);
+        }
+
+        #[test]
+        fn cuts_words_longer_than_a_line() {
+            assert_eq!(wrap("/very/long/path", 6, 5), ["/very/"]);
+        }
+    }
+
+    mod render {
+        use super::*;
+
+        fn settings(cache: &std::path::Path) -> DashboardSettings {
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.misc.weather_data_cache_path = cache.to_path_buf();
+            settings
+        }
+
+        #[test]
+        fn shows_the_reason_and_missing_file_hint() {
+            let cache = tempfile::tempdir().unwrap();
+            let error = anyhow::Error::from(std::io::Error::from(ErrorKind::NotFound))
+                .context("Failed to read <template>");
+
+            let svg = render(&settings(cache.path()), &error);
+
+            assert!(svg.contains("Weather update failed"));
+            assert!(svg.contains("Failed to read &lt;template&gt;"));
+            assert!(svg.contains("A file is missing"));
+            assert!(svg.contains("Last good data: none"));
+        }
+
+        #[test]
+        fn other_failures_point_at_the_network_and_log() {
+            let cache = tempfile::tempdir().unwrap();
+            let error = anyhow::Error::msg("No cached weather data available");
+
+            let svg = render(&settings(cache.path()), &error);
+
+            assert!(svg.contains("Check the network connection"));
+        }
+
+        #[test]
+        fn follows_the_configured_language() {
+            let cache = tempfile::tempdir().unwrap();
+            let mut settings = settings(cache.path());
+            settings.render_options.language = crate::i18n::Language::De;
+
+            let svg = render(&settings, &anyhow::Error::msg("x"));
+
+            assert!(svg.contains("Update fehlgeschlagen"));
+            assert!(svg.contains("Letzte gültige Daten: keine"));
+            assert!(svg.contains("Netzwerk und Konfiguration prüfen"));
+        }
+
+        #[test]
+        fn last_good_data_is_the_newest_cache_file() {
+            let cache = tempfile::tempdir().unwrap();
+            let settings = settings(cache.path());
+            fs::write(cache.path().join("open_meteo_hourly_forecast.json"), "{}").unwrap();
+
+            let time = last_good_data(&settings).unwrap();
+
+            assert!((Utc::now() - time).num_seconds().abs() < 60);
+            assert!(!render(&settings, &anyhow::Error::msg("x")).contains("data: none"));
+        }
+
+        #[test]
+        fn fits_the_portrait_canvas() {
+            let cache = tempfile::tempdir().unwrap();
+            let mut settings = settings(cache.path());
+            settings.display.model = crate::configs::settings::DisplayModel::Impression73Portrait;
+
+            let svg = render(&settings, &anyhow::Error::msg("x"));
+
+            resvg::usvg::Tree::from_str(&svg, &resvg::usvg::Options::default()).unwrap();
+            assert!(svg.starts_with(r#"<svg width="480" height="800""#));
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Value formatters registered on the dashboard's `TinyTemplate`, so
+//! templates can print the raw numbers in `Context::values`, `days` and
+//! `hours` with units, e.g. `{values.temperature | temperature}`.
+//!
+//! Every formatter prints "N/A" for a missing (`null`) value, and falls back
+//! to TinyTemplate's own formatting for anything that isn't a number.
+
+use crate::{
+    configs::settings::{DashboardSettings, PrecipitationUnit},
+    constants::NOT_AVAILABLE,
+    dashboard::daily_range::format_amount,
+};
+use serde_json::Value;
+use tinytemplate::{error::Result, format_unescaped, TinyTemplate};
+
+/// Registers the formatters on `tt`. Unit-aware ones capture the units from
+/// `settings`.
+pub fn register(tt: &mut TinyTemplate, settings: &DashboardSettings) {
+    let precipitation_unit = settings.render_options.precipitation_unit;
+    let wind_speed_unit = settings.render_options.wind_speed_unit.to_string();
+
+    // Context floats are `f32`; printing them at that precision keeps 24.6
+    // from widening to 24.600000381469727.
+    tt.add_formatter("na", |value, out| {
+        with_number(value, out, |n| (n as f32).to_string())
+    });
+    tt.add_formatter("round", |value, out| {
+        with_number(value, out, |n| rounded(n).to_string())
+    });
+    tt.add_formatter("temperature", |value, out| {
+        with_number(value, out, |n| format!("{}°", rounded(n)))
+    });
+    tt.add_formatter("percent", |value, out| {
+        with_number(value, out, |n| format!("{}%", rounded(n)))
+    });
+    tt.add_formatter("precipitation", move |value, out| {
+        with_number(value, out, |n| precipitation(n, precipitation_unit))
+    });
+    tt.add_formatter("wind_speed", move |value, out| {
+        with_number(value, out, |n| format!("{} {wind_speed_unit}", rounded(n)))
+    });
+}
+
+/// Applies `format` to numbers, "N/A" to `null`, and TinyTemplate's default
+/// formatting to anything else.
+fn with_number(value: &Value, out: &mut String, format: impl Fn(f64) -> String) -> Result<()> {
+    match value {
+        Value::Null => out.push_str(NOT_AVAILABLE),
+        Value::Number(number) => match number.as_f64() {
+            Some(n) => out.push_str(&format(n)),
+            None => return format_unescaped(value, out),
+        },
+        _ => return format_unescaped(value, out),
+    }
+    Ok(())
+}
+
+/// Nearest whole number; going through an integer also avoids printing
+/// "-0" for small negatives.
+fn rounded(n: f64) -> i64 {
+    n.round() as i64
+}
+
+fn precipitation(amount: f64, unit: PrecipitationUnit) -> String {
+    format_amount(amount as f32, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Values {
+        temperature: Option<f32>,
+        chance: Option<u16>,
+        amount: f32,
+        wind: u16,
+        label: &'static str,
+    }
+
+    fn render(template: &str, values: &Values) -> String {
+        let settings = DashboardSettings::load_test_config().unwrap();
+        let mut tt = TinyTemplate::new();
+        register(&mut tt, &settings);
+        tt.set_default_formatter(&format_unescaped);
+        tt.add_template("t", template).unwrap();
+        tt.render("t", values).unwrap()
+    }
+
+    fn values() -> Values {
+        Values {
+            temperature: Some(-0.4),
+            chance: Some(40),
+            amount: 2.6,
+            wind: 12,
+            label: "x",
+        }
+    }
+
+    #[test]
+    fn formatters_add_units() {
+        assert_eq!(
+            render(
+                "{temperature | temperature} {chance | percent} {amount | precipitation} {wind | wind_speed}",
+                &values()
+            ),
+            "0° 40% 3mm 12 km/h"
+        );
+        assert_eq!(render("{amount | round}", &values()), "3");
+        assert_eq!(render("{amount | na}", &values()), "2.6");
+    }
+
+    #[test]
+    fn missing_values_print_not_available() {
+        let values = Values {
+            temperature: None,
+            chance: None,
+            ..values()
+        };
+        assert_eq!(
+            render("{temperature | temperature} {chance | na}", &values),
+            "N/A N/A"
+        );
+    }
+
+    #[test]
+    fn non_numbers_fall_back_to_default_formatting() {
+        assert_eq!(render("{label | temperature}", &values()), "x");
+    }
+}
@@ -1,2 +1,11 @@
 pub mod chart;
 pub mod context;
+pub mod daily_range;
+pub mod data_age;
+pub mod error_screen;
+pub mod formatters;
+pub mod outlook;
+pub mod renderer;
+pub mod self_contained;
+pub mod summary;
+pub mod widgets;
@@ -0,0 +1,242 @@
+//! 14-day outlook page: a two-row calendar grid of day cards and a
+//! temperature trend sparkline underneath, both rendered as SVG fragments
+//! for `dashboard-template-outlook.svg`.
+//!
+//! Providers that forecast fewer than 14 days (BOM) leave the trailing
+//! cards as "N/A" and break the sparkline where data stops.
+
+use crate::{
+    configs::settings::PrecipitationUnit, constants::NOT_AVAILABLE,
+    dashboard::daily_range::precipitation_summary, dashboard::daily_range::DailyRange,
+};
+
+/// Days shown on the outlook page, starting today.
+pub const OUTLOOK_DAYS: usize = 14;
+
+/// Grid geometry in page coordinates: 7 columns × 2 rows of cards between
+/// the date header and the trend. Must stay in sync with the template.
+const COLUMNS: usize = 7;
+const GRID_X: f32 = 8.0;
+const GRID_Y: f32 = 62.0;
+const CARD_WIDTH: f32 = 112.0;
+const CARD_HEIGHT: f32 = 145.0;
+const ROW_GAP: f32 = 6.0;
+
+/// Trend sparkline area in page coordinates; the left margin holds the
+/// high/low scale labels.
+const TREND_LEFT: f32 = 46.0;
+const TREND_RIGHT: f32 = 770.0;
+const TREND_TOP: f32 = 378.0;
+const TREND_BOTTOM: f32 = 462.0;
+const TREND_LABEL_FONT_SIZE: u16 = 14;
+
+/// One card of the outlook grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlookDay {
+    /// Short weekday and day of month, e.g. "Sun 19".
+    pub name: String,
+    pub icon: String,
+    pub is_today: bool,
+    pub range: DailyRange,
+}
+
+/// Renders the 7 × 2 grid of day cards. Today's card gets a heavier
+/// border; days without a forecast show "N/A".
+pub fn grid_svg(days: &[OutlookDay], unit: PrecipitationUnit, text_colour: &str) -> String {
+    days.iter()
+        .take(OUTLOOK_DAYS)
+        .enumerate()
+        .map(|(index, day)| {
+            let x = GRID_X + (index % COLUMNS) as f32 * CARD_WIDTH;
+            let y = GRID_Y + (index / COLUMNS) as f32 * (CARD_HEIGHT + ROW_GAP);
+            card_svg(day, x, y, unit, text_colour)
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ")
+}
+
+fn card_svg(
+    day: &OutlookDay,
+    x: f32,
+    y: f32,
+    unit: PrecipitationUnit,
+    text_colour: &str,
+) -> String {
+    let centre = CARD_WIDTH / 2.0;
+    let border_width = if day.is_today { 3 } else { 1 };
+    let temperatures = match (day.range.max, day.range.min) {
+        (Some(max), Some(min)) => format!("{max}° | {min}°"),
+        (Some(max), None) => format!("{max}° | {NOT_AVAILABLE}"),
+        (None, Some(min)) => format!("{NOT_AVAILABLE} | {min}°"),
+        (None, None) => NOT_AVAILABLE.to_string(),
+    };
+    let precipitation = precipitation_summary(&day.range, unit).map_or_else(String::new, |summary| {
+        format!(
+            r#"<text x="{centre}" y="137" font-size="13" fill="{text_colour}" text-anchor="middle">{summary}</text>"#
+        )
+    });
+    format!(
+        r#"<svg x="{x}" y="{y}" width="{CARD_WIDTH}" height="{CARD_HEIGHT}"><rect x="2" y="2" width="{w}" height="{h}" rx="6" fill="none" stroke="{text_colour}" stroke-width="{border_width}"/><text x="{centre}" y="22" font-size="17" fill="{text_colour}" text-anchor="middle">{name}</text><image x="26" y="24" width="60" height="80" href="{icon}"/><text x="{centre}" y="118" font-size="18" fill="{text_colour}" text-anchor="middle">{temperatures}</text>{precipitation}</svg>"#,
+        w = CARD_WIDTH - 4.0,
+        h = CARD_HEIGHT - 4.0,
+        name = day.name,
+        icon = day.icon,
+    )
+}
+
+/// Renders the daily max (solid) and min (dashed) as lines across the
+/// outlook, scaled to the fortnight's extremes, with those extremes
+/// labelled on the left. Missing days break the lines rather than being
+/// interpolated across. Empty when no day has a temperature.
+pub fn trend_svg(days: &[OutlookDay], line_colour: &str, text_colour: &str) -> String {
+    let values = |pick: fn(&DailyRange) -> Option<f32>| -> Vec<Option<f32>> {
+        days.iter()
+            .take(OUTLOOK_DAYS)
+            .map(|day| pick(&day.range))
+            .collect()
+    };
+    let maxima = values(|r| r.max.map(|t| *t));
+    let minima = values(|r| r.min.map(|t| *t));
+
+    let Some((low, high)) = maxima.iter().chain(&minima).flatten().fold(
+        None,
+        |acc: Option<(f32, f32)>, &t| match acc {
+            None => Some((t, t)),
+            Some((lo, hi)) => Some((lo.min(t), hi.max(t))),
+        },
+    ) else {
+        return String::new();
+    };
+
+    let step = (TREND_RIGHT - TREND_LEFT) / (OUTLOOK_DAYS - 1) as f32;
+    let to_point = |index: usize, t: f32| {
+        let y = if high > low {
+            TREND_BOTTOM - (t - low) / (high - low) * (TREND_BOTTOM - TREND_TOP)
+        } else {
+            (TREND_TOP + TREND_BOTTOM) / 2.0
+        };
+        (TREND_LEFT + index as f32 * step, y)
+    };
+
+    let series = |values: &[Option<f32>], dash: &str| {
+        let mut runs: Vec<Vec<(f32, f32)>> = Vec::new();
+        let mut current = Vec::new();
+        for (index, value) in values.iter().enumerate() {
+            match value {
+                Some(t) => current.push(to_point(index, *t)),
+                None if !current.is_empty() => runs.push(std::mem::take(&mut current)),
+                None => {}
+            }
+        }
+        if !current.is_empty() {
+            runs.push(current);
+        }
+        runs.iter()
+            .map(|run| {
+                let points = run
+                    .iter()
+                    .map(|(x, y)| format!("{x:.1},{y:.1}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let dots = run
+                    .iter()
+                    .map(|(x, y)| {
+                        format!(r#"<circle cx="{x:.1}" cy="{y:.1}" r="3" fill="{line_colour}"/>"#)
+                    })
+                    .collect::<String>();
+                format!(
+                    r#"<polyline points="{points}" fill="none" stroke="{line_colour}" stroke-width="2"{dash}/>{dots}"#
+                )
+            })
+            .collect::<String>()
+    };
+
+    let label = |t: f32, y: f32| {
+        format!(
+            r#"<text x="{x}" y="{y:.1}" font-size="{TREND_LABEL_FONT_SIZE}" fill="{text_colour}" text-anchor="end" dominant-baseline="middle">{t:.0}°</text>"#,
+            x = TREND_LEFT - 10.0,
+        )
+    };
+
+    format!(
+        "<g>{max_line}{min_line}{high_label}{low_label}</g>",
+        max_line = series(&maxima, ""),
+        min_line = series(&minima, r#" stroke-dasharray="6,4""#),
+        high_label = label(high, TREND_TOP),
+        low_label = label(low, TREND_BOTTOM),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Temperature;
+
+    fn day(index: usize, temps: Option<(f32, f32)>) -> OutlookDay {
+        OutlookDay {
+            name: format!("Day {index}"),
+            icon: "icon.svg".to_string(),
+            is_today: index == 0,
+            range: DailyRange {
+                min: temps.map(|(min, _)| Temperature::celsius(min)),
+                max: temps.map(|(_, max)| Temperature::celsius(max)),
+                precipitation_chance: temps.map(|_| 40),
+                precipitation_amount: temps.map(|_| 2.0),
+            },
+        }
+    }
+
+    /// Eight forecast days then six missing, like a BOM response.
+    fn bom_like_fortnight() -> Vec<OutlookDay> {
+        (0..OUTLOOK_DAYS)
+            .map(|i| day(i, (i < 8).then_some((10.0 + i as f32, 20.0 + i as f32))))
+            .collect()
+    }
+
+    #[test]
+    fn grid_lays_out_two_rows_of_seven() {
+        let svg = grid_svg(&bom_like_fortnight(), PrecipitationUnit::Mm, "black");
+        assert_eq!(svg.matches("<svg ").count(), OUTLOOK_DAYS);
+        assert!(svg.contains(r#"<svg x="680" y="62""#), "{svg}");
+        assert!(svg.contains(r#"<svg x="8" y="213""#), "{svg}");
+        assert_eq!(svg.matches(r#"stroke-width="3""#).count(), 1);
+    }
+
+    #[test]
+    fn missing_days_degrade_to_not_available_cards() {
+        let svg = grid_svg(&bom_like_fortnight(), PrecipitationUnit::Mm, "black");
+        assert!(svg.contains(">20° | 10°</text>"));
+        assert!(svg.contains(">40% 2mm</text>"));
+        assert_eq!(svg.matches(">N/A</text>").count(), 6);
+    }
+
+    #[test]
+    fn trend_breaks_at_missing_days() {
+        let mut days = bom_like_fortnight();
+        days[3] = day(3, None);
+        let svg = trend_svg(&days, "red", "black");
+        // Days 0–2 and 4–7 for each of max and min.
+        assert_eq!(svg.matches("<polyline").count(), 4);
+        assert_eq!(svg.matches("<circle").count(), 14);
+        assert!(svg.contains(">27°</text>"));
+        assert!(svg.contains(">10°</text>"));
+    }
+
+    #[test]
+    fn trend_spans_the_plot_for_a_full_fortnight() {
+        let days: Vec<_> = (0..OUTLOOK_DAYS)
+            .map(|i| day(i, Some((5.0, 15.0 + i as f32))))
+            .collect();
+        let svg = trend_svg(&days, "red", "black");
+        // Last max is the fortnight's high: top right corner.
+        assert!(svg.contains(r#"cx="770.0" cy="378.0""#), "{svg}");
+        // Flat minima sit on the bottom edge.
+        assert!(svg.contains(r#"cx="46.0" cy="462.0""#), "{svg}");
+    }
+
+    #[test]
+    fn trend_is_empty_without_temperatures() {
+        let days: Vec<_> = (0..OUTLOOK_DAYS).map(|i| day(i, None)).collect();
+        assert!(trend_svg(&days, "red", "black").is_empty());
+    }
+}
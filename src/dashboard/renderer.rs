@@ -0,0 +1,362 @@
+//! Renders the dashboard in memory, for embedding the crate in another
+//! program. The file-writing functions in [`crate::weather_dashboard`] are
+//! wrappers over [`DashboardRenderer`].
+
+use std::fs;
+
+use anyhow::Error;
+use chrono::Timelike;
+use tinytemplate::{format_unescaped, TinyTemplate};
+
+use crate::clock::Clock;
+use crate::configs::settings::{DashboardSettings, DisplayModel};
+use crate::dashboard::context::{Context, ContextBuilder};
+use crate::dashboard::data_age::DataAge;
+use crate::dashboard::formatters;
+use crate::dashboard::self_contained;
+use crate::dashboard::summary;
+use crate::dashboard::widgets::{self, layouts, ForecastData, WidgetInput, WidgetRegistry};
+use crate::domain::models::{DailyForecast, HourlyForecast};
+use crate::errors::{DashboardError, Description};
+use crate::logger;
+use crate::providers::factory::create_provider;
+use crate::update::read_last_update_status;
+use crate::utils::svg_to_png;
+use crate::weather::icons::apply_panel_icon_filter;
+
+/// Scale the PNG is rendered at, relative to the SVG canvas.
+pub const PNG_SCALE_FACTOR: f32 = 2.0;
+
+/// A rendered dashboard.
+#[derive(Debug, Clone)]
+pub struct RenderedDashboard {
+    pub svg: String,
+    /// The SVG as PNG, at [`PNG_SCALE_FACTOR`].
+    pub png: Vec<u8>,
+}
+
+/// Renders the dashboard without touching the output paths in `Misc`.
+///
+/// Templates default to `misc.template_path` / `misc.outlook_template_path`
+/// and forecasts to a fetch from the configured provider; either can be
+/// supplied instead.
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use pi_inky_weather_epd::clock::FixedClock;
+/// use pi_inky_weather_epd::configs::settings::DashboardSettings;
+/// use pi_inky_weather_epd::DashboardRenderer;
+///
+/// let settings = DashboardSettings::load()?;
+/// let clock = FixedClock::from_rfc3339("2025-10-09T22:00:00Z")?;
+/// let template = std::fs::read("my-template.svg")?;
+/// let rendered = DashboardRenderer::new(&settings, &clock)
+///     .with_template(template)
+///     .render()?;
+/// println!("{} bytes of PNG", rendered.png.len());
+/// # Ok(())
+/// # }
+/// ```
+pub struct DashboardRenderer<'a> {
+    settings: &'a DashboardSettings,
+    clock: &'a dyn Clock,
+    template: Option<Vec<u8>>,
+    outlook_template: Option<Vec<u8>>,
+    forecast: Option<ForecastData>,
+}
+
+impl<'a> DashboardRenderer<'a> {
+    pub fn new(settings: &'a DashboardSettings, clock: &'a dyn Clock) -> Self {
+        Self {
+            settings,
+            clock,
+            template: None,
+            outlook_template: None,
+            forecast: None,
+        }
+    }
+
+    /// Renders "today" from this template instead of `misc.template_path`.
+    /// It takes precedence over `[layout] widgets` and the built-in layouts
+    /// of the other display models, and is scaled to the model's canvas.
+    pub fn with_template(mut self, template: impl Into<Vec<u8>>) -> Self {
+        self.template = Some(template.into());
+        self
+    }
+
+    /// Renders the 14-day outlook from this template instead of
+    /// `misc.outlook_template_path`.
+    pub fn with_outlook_template(mut self, template: impl Into<Vec<u8>>) -> Self {
+        self.outlook_template = Some(template.into());
+        self
+    }
+
+    /// Renders these forecasts instead of fetching them from the provider.
+    /// Nothing is fetched or cached, no stale-data diagnostics are shown and
+    /// the data counts as fetched now.
+    pub fn with_forecast(mut self, hourly: Vec<HourlyForecast>, daily: Vec<DailyForecast>) -> Self {
+        self.forecast = Some(ForecastData { daily, hourly });
+        self
+    }
+
+    /// Renders the dashboard SVG and its PNG.
+    pub fn render(&self) -> Result<RenderedDashboard, Error> {
+        let svg = self.render_svg()?;
+        let png = svg_to_png(&svg, PNG_SCALE_FACTOR)?;
+        Ok(RenderedDashboard { svg, png })
+    }
+
+    /// Renders the dashboard SVG only.
+    pub fn render_svg(&self) -> Result<String, Error> {
+        self.render_svg_and_fetch_time_label().map(|(svg, _)| svg)
+    }
+
+    /// Renders the dashboard SVG, and returns the data age label's text with
+    /// it while the label shows the fetch time ("Updated 14:05"); empty once
+    /// it shows the age in days.
+    pub(crate) fn render_svg_and_fetch_time_label(&self) -> Result<(String, String), Error> {
+        let settings = self.settings;
+        let mut context_builder = ContextBuilder::new(settings, self.clock);
+        let local_hour = self.clock.now_local(settings.misc.timezone).hour();
+        let show_outlook = settings.render_options.shows_outlook_at(local_hour);
+        // The outlook is a page of its own; layouts only compose "today". A
+        // template given to the renderer wins; otherwise only the 7.3" has a
+        // template for it, other models use their built-in layout unless
+        // `[layout]` gives one.
+        let placements = if show_outlook || self.template.is_some() {
+            None
+        } else if !settings.layout.widgets.is_empty() {
+            Some(settings.layout.widgets.clone())
+        } else if settings.display.model != DisplayModel::Impression73 {
+            Some(layouts::built_in(settings.display.model))
+        } else {
+            None
+        };
+
+        // `None` composes the dashboard from `placements` instead.
+        let template_svg = if let Some(placements) = &placements {
+            logger::detail(format!(
+                "Composing {} widgets for {}",
+                placements.len(),
+                settings.display.model
+            ));
+            None
+        } else {
+            Some(self.template_svg(show_outlook)?)
+        };
+
+        let (forecast, warnings, data_age) = match &self.forecast {
+            Some(forecast) => {
+                let now = self.clock.now_utc();
+                (forecast.clone(), Vec::new(), DataAge::new(now, now))
+            }
+            None => fetch_forecast(settings, self.clock)?,
+        };
+        let summary_width = placements
+            .as_deref()
+            .map_or(summary::TEMPLATE_WIDTH, layouts::summary_width);
+        apply_forecast(
+            &mut context_builder,
+            &forecast,
+            warnings,
+            self.clock,
+            show_outlook,
+            summary_width,
+        );
+        context_builder.with_data_age(&data_age);
+
+        logger::subsection("Rendering dashboard to SVG");
+        let svg = if let Some(template_svg) = template_svg {
+            render_dashboard_template(&context_builder.context, settings, &template_svg)?
+        } else {
+            let placements = placements.unwrap_or_default();
+            let input = WidgetInput {
+                settings,
+                clock: self.clock,
+                forecast: &forecast,
+                context: &context_builder.context,
+            };
+            let composed = widgets::compose(&placements, &WidgetRegistry::with_built_ins(), &input)
+                .inspect_err(|e| logger::error(format!("Failed to compose layout: {e}")))?;
+            apply_panel_icon_filter(
+                composed,
+                settings.display.profile,
+                &context_builder.context.text_colour,
+                &context_builder.context.background_colour,
+            )
+        };
+        let fetch_time_label = if data_age.shows_fetch_time() {
+            context_builder.context.data_age_label
+        } else {
+            String::new()
+        };
+        Ok((finish_svg(svg, settings), fetch_time_label))
+    }
+
+    /// The template for the page shown now: the one given, or the
+    /// configured file.
+    fn template_svg(&self, show_outlook: bool) -> Result<String, Error> {
+        let (given, path) = if show_outlook {
+            logger::detail("Rendering the 14-day outlook");
+            (
+                &self.outlook_template,
+                &self.settings.misc.outlook_template_path,
+            )
+        } else {
+            (&self.template, &self.settings.misc.template_path)
+        };
+
+        if let Some(bytes) = given {
+            return String::from_utf8(bytes.clone())
+                .map_err(|e| Error::msg(format!("Template is not valid UTF-8: {e}")));
+        }
+        fs::read_to_string(path).map_err(|e| {
+            logger::error(format!("Failed to read template file: {e}"));
+            if let Ok(current_dir) = std::env::current_dir() {
+                logger::detail(format!("Current directory: {}", current_dir.display()));
+            }
+            logger::detail(format!("Template path: {}", path.display()));
+            Error::from(e).context(format!("Failed to read template {}", path.display()))
+        })
+    }
+}
+
+/// Fetches both forecasts from the configured provider, with a diagnostic
+/// for each one served from cache at least `stale_after_hours` old and for
+/// a failed self-update. The data age is that of the older forecast.
+fn fetch_forecast(
+    settings: &DashboardSettings,
+    clock: &dyn Clock,
+) -> Result<(ForecastData, Vec<DashboardError>, DataAge), Error> {
+    let provider = create_provider(settings)?;
+    let mut warnings: Vec<DashboardError> = Vec::new();
+
+    // Check if the last update failed and add warning if so
+    if let Some(error_details) = read_last_update_status() {
+        warnings.push(DashboardError::UpdateFailed {
+            details: error_details,
+        });
+    }
+
+    logger::subsection(format!("Using provider: {}", provider.provider_name()));
+
+    logger::subsection("Fetching daily forecast");
+    let daily_result = provider.fetch_daily_forecast(settings, clock)?;
+    let daily_age = DataAge::new(daily_result.updated_at, clock.now_utc());
+    if let Some(warning) = daily_result.warning {
+        stale_warning(settings, warning, &daily_age, &mut warnings);
+    } else {
+        logger::success("Daily forecast retrieved");
+    }
+
+    logger::subsection("Fetching hourly forecast");
+    let hourly_result = provider.fetch_hourly_forecast(settings, clock)?;
+    let hourly_age = DataAge::new(hourly_result.updated_at, clock.now_utc());
+    if let Some(warning) = hourly_result.warning {
+        stale_warning(settings, warning, &hourly_age, &mut warnings);
+    } else {
+        logger::success("Hourly forecast retrieved");
+    }
+
+    Ok((
+        ForecastData {
+            daily: daily_result.data,
+            hourly: hourly_result.data,
+        },
+        warnings,
+        if daily_age.age > hourly_age.age {
+            daily_age
+        } else {
+            hourly_age
+        },
+    ))
+}
+
+/// Adds `warning` for a forecast served from cache, unless the cache is
+/// younger than `[data_age] stale_after_hours`.
+fn stale_warning(
+    settings: &DashboardSettings,
+    warning: DashboardError,
+    data_age: &DataAge,
+    warnings: &mut Vec<DashboardError>,
+) {
+    logger::warning(format!(
+        "Using cached data from {} due to: {}",
+        data_age.updated_at.with_timezone(&settings.misc.timezone),
+        warning.long_description()
+    ));
+    if data_age.is_stale(settings.data_age.stale_after_hours) {
+        warnings.push(warning);
+    } else {
+        logger::detail(format!(
+            "Not flagging stale data younger than {} hours",
+            settings.data_age.stale_after_hours
+        ));
+    }
+}
+
+fn apply_forecast(
+    context_builder: &mut ContextBuilder,
+    forecast: &ForecastData,
+    warnings: Vec<DashboardError>,
+    clock: &dyn Clock,
+    show_outlook: bool,
+    summary_width: f32,
+) {
+    if show_outlook {
+        context_builder.with_outlook_data(&forecast.daily, clock);
+    }
+    context_builder.with_daily_forecast_data(forecast.daily.clone(), clock);
+    context_builder.with_hourly_forecast_data(forecast.hourly.clone(), clock);
+    context_builder.with_forecast_summary(&forecast.hourly, &forecast.daily, clock, summary_width);
+
+    // Add all accumulated warnings to the context
+    for warning in warnings {
+        context_builder.with_warning(warning);
+    }
+}
+
+fn render_dashboard_template(
+    context: &Context,
+    settings: &DashboardSettings,
+    dashboard_svg: &str,
+) -> Result<String, Error> {
+    let mut tt = TinyTemplate::new();
+    formatters::register(&mut tt, settings);
+    let tt_name = "dashboard";
+
+    if let Err(e) = tt.add_template(tt_name, dashboard_svg) {
+        logger::error(format!("Failed to add template: {e}"));
+        return Err(e.into());
+    }
+    tt.set_default_formatter(&format_unescaped);
+    // Attempt to render the template
+    match tt.render(tt_name, &context) {
+        Ok(rendered) => {
+            let rendered = apply_panel_icon_filter(
+                rendered,
+                settings.display.profile,
+                &context.text_colour,
+                &context.background_colour,
+            );
+            Ok(widgets::fit_to_canvas(
+                rendered,
+                settings.display.model,
+                &context.background_colour,
+            ))
+        }
+        Err(e) => {
+            logger::error(format!("Failed to render template: {e}"));
+            Err(e.into())
+        }
+    }
+}
+
+/// Makes the page self-contained when `misc.self_contained_svg` is set.
+fn finish_svg(svg: String, settings: &DashboardSettings) -> String {
+    if settings.misc.self_contained_svg {
+        self_contained::make_self_contained(&svg, settings.misc.embed_svg_fonts)
+    } else {
+        svg
+    }
+}
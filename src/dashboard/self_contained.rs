@@ -0,0 +1,453 @@
+//! Self-contained SVG output (`misc.self_contained_svg`).
+//!
+//! A rendered page refers to its icons by file path and to its fonts by
+//! family name, so it only renders properly next to `static/`. This inlines
+//! every referenced icon once, as a `<symbol>` drawn with `<use>`, and, unless
+//! `misc.embed_svg_fonts` is off, embeds the fonts the page uses as
+//! `@font-face` data URLs. Given such a page,
+//! [`convert_svg_to_png`](crate::utils::convert_svg_to_png) renders with the
+//! embedded fonts rather than the bundled font files, so the output no longer
+//! depends on the working directory.
+
+use crate::{
+    logger,
+    utils::{insert_after_root_tag, shared_font_db},
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use resvg::usvg::fontdb;
+use std::{collections::HashMap, fs};
+
+const FONT_DATA_URL: &str = "data:font/ttf;base64,";
+
+/// Inlines the page's icons and, with `with_fonts`, embeds its fonts. An
+/// icon that can't be read is left referenced by path, with a warning.
+pub fn make_self_contained(svg: &str, with_fonts: bool) -> String {
+    let svg = inline_icons(svg);
+    if with_fonts {
+        embed_fonts(&svg)
+    } else {
+        svg
+    }
+}
+
+/// Replaces each `<image>` of an SVG file with a `<use>` of a `<symbol>`
+/// holding that file's content. Each file becomes one symbol however often
+/// it's drawn; its internal ids are prefixed with the symbol's id so icons
+/// can't clash. Other images (data URLs, remote links) are left alone.
+pub fn inline_icons(svg: &str) -> String {
+    let mut symbols: Vec<String> = Vec::new();
+    // href → symbol id
+    let mut inlined: HashMap<String, String> = HashMap::new();
+    let mut out = String::with_capacity(svg.len());
+    let mut rest = svg;
+    while let Some(start) = rest.find("<image") {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let Some(end) = tail.find('>') else {
+            rest = tail;
+            break;
+        };
+        let tag = &tail[..=end];
+        rest = &tail[end + 1..];
+        match use_for_image(tag, &mut inlined, &mut symbols) {
+            Some(use_tag) => out.push_str(&use_tag),
+            None => out.push_str(tag),
+        }
+    }
+    out.push_str(rest);
+
+    if symbols.is_empty() {
+        return out;
+    }
+    insert_after_root_tag(&out, &format!("<defs>{}</defs>", symbols.concat()))
+}
+
+/// The `<use>` replacing a self-closing `<image>` tag, loading its icon into
+/// `symbols` the first time it's seen. `None` keeps the tag as it is.
+fn use_for_image(
+    tag: &str,
+    inlined: &mut HashMap<String, String>,
+    symbols: &mut Vec<String>,
+) -> Option<String> {
+    let body = tag.strip_prefix("<image")?.strip_suffix("/>")?;
+    let attributes = parse_attributes(body);
+    let href = attributes
+        .iter()
+        .find(|(name, _)| *name == "href" || *name == "xlink:href")
+        .map(|(_, value)| *value)?;
+    if href.starts_with("data:") || href.starts_with('#') || href.contains("://") {
+        return None;
+    }
+
+    let id = match inlined.get(href) {
+        Some(id) => id.clone(),
+        None => {
+            let id = unique_symbol_id(href, inlined);
+            match load_symbol(href, &id) {
+                Ok(symbol) => symbols.push(symbol),
+                Err(e) => {
+                    logger::warning(format!("Leaving icon {href} referenced by path: {e}"));
+                    return None;
+                }
+            }
+            inlined.insert(href.to_string(), id.clone());
+            id
+        }
+    };
+
+    let others: String = attributes
+        .iter()
+        .filter(|(name, _)| *name != "href" && *name != "xlink:href")
+        .map(|(name, value)| format!(r#" {name}="{value}""#))
+        .collect();
+    Some(format!(r##"<use href="#{id}"{others}/>"##))
+}
+
+/// `icon-` and the file stem, numbered when two files share a stem (the
+/// same icon from two icon directories).
+fn unique_symbol_id(href: &str, inlined: &HashMap<String, String>) -> String {
+    let stem = href
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or(href)
+        .trim_end_matches(".svg");
+    let stem: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    let base = format!("icon-{stem}");
+    let taken = |id: &str| inlined.values().any(|existing| existing == id);
+    if !taken(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{base}-{n}"))
+        .find(|id| !taken(id))
+        .expect("ids are unbounded")
+}
+
+/// An icon file as a `<symbol>`, its content under ids prefixed by `id`.
+///
+/// The content stays inside an `<svg>` with the icon's `viewBox` rather than
+/// moving that onto the symbol: resvg resolves percentages within a symbol
+/// against the `<use>` size, not the symbol's `viewBox`, which would shrink
+/// icons that size their parts in percent.
+fn load_symbol(path: &str, id: &str) -> Result<String, String> {
+    let icon = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let root_start = icon.find("<svg").ok_or("no <svg> element")?;
+    let root_end = root_start + icon[root_start..].find('>').ok_or("unclosed <svg> tag")?;
+    let content_end = icon.rfind("</svg>").ok_or("unclosed <svg> element")?;
+    let root_attributes = parse_attributes(&icon[root_start + "<svg".len()..root_end]);
+    if !root_attributes.iter().any(|(name, _)| *name == "viewBox") {
+        return Err("no viewBox on its <svg> element".to_string());
+    }
+    // The root's own attributes (its viewBox and any presentation
+    // attributes) carry over; its size and namespaces don't.
+    let carried: String = root_attributes
+        .iter()
+        .filter(|(name, _)| {
+            !matches!(*name, "width" | "height" | "x" | "y" | "id") && !name.starts_with("xmlns")
+        })
+        .map(|(name, value)| format!(r#" {name}="{value}""#))
+        .collect();
+    let content = prefix_ids(&icon[root_end + 1..content_end], id);
+    Ok(format!(
+        r#"<symbol id="{id}"><svg{carried}>{content}</svg></symbol>"#
+    ))
+}
+
+/// Prefixes every id in an icon's content, and the references to them, with
+/// `prefix`. `xlink:href` becomes plain `href`, as the page needn't declare
+/// the xlink namespace.
+fn prefix_ids(content: &str, prefix: &str) -> String {
+    let mut content = content
+        .replace("xlink:href=", "href=")
+        .replace("url(#", &format!("url(#{prefix}-"))
+        .replace(r##"href="#"##, &format!(r##"href="#{prefix}-"##));
+    for space in [' ', '\n', '\t', '\r'] {
+        content = content.replace(
+            &format!(r#"{space}id=""#),
+            &format!(r#"{space}id="{prefix}-"#),
+        );
+    }
+    content
+}
+
+/// `name="value"` pairs of a start tag's body, in order. Single quotes are
+/// accepted too.
+fn parse_attributes(body: &str) -> Vec<(&str, &str)> {
+    let mut attributes = Vec::new();
+    let mut rest = body;
+    while let Some(eq) = rest.find('=') {
+        let name = rest[..eq].trim();
+        let after = rest[eq + 1..].trim_start();
+        let Some(quote) = after.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+            break;
+        };
+        let Some(len) = after[1..].find(quote) else {
+            break;
+        };
+        attributes.push((name, &after[1..=len]));
+        rest = &after[len + 2..];
+    }
+    attributes
+}
+
+/// Embeds the bundled fonts the page uses as `@font-face` data URLs,
+/// replacing `@font-face` rules that point at font files. A font is used
+/// when the page names its family, or when it has glyphs for characters the
+/// named families lack (as resvg falls back to it for those).
+///
+/// Fonts are embedded whole, not subset to the page's glyphs: the default
+/// dashboard's Roboto faces come to about 1.5 MB of base64.
+pub fn embed_fonts(svg: &str) -> String {
+    let families = font_families(svg);
+    let db = shared_font_db();
+    let faces: Vec<(fontdb::ID, String, Vec<u8>, u32)> = db
+        .faces()
+        .filter_map(|face| {
+            let family = face.families.first()?.0.clone();
+            let (data, index) = db.with_face_data(face.id, |data, index| (data.to_vec(), index))?;
+            Some((face.id, family, data, index))
+        })
+        .collect();
+    let parsed: Vec<Option<ttf_parser::Face>> = faces
+        .iter()
+        .map(|(_, _, data, index)| ttf_parser::Face::parse(data, *index).ok())
+        .collect();
+
+    let mut used: Vec<bool> = faces
+        .iter()
+        .map(|(_, family, _, _)| families.contains(&family.to_lowercase()))
+        .collect();
+    let covers = |i: usize, c: char| {
+        parsed[i]
+            .as_ref()
+            .is_some_and(|face| face.glyph_index(c).is_some())
+    };
+    for c in text_chars(svg) {
+        if (0..faces.len()).any(|i| used[i] && covers(i, c)) {
+            continue;
+        }
+        if let Some(fallback) = (0..faces.len()).find(|&i| covers(i, c)) {
+            used[fallback] = true;
+        }
+    }
+
+    let rules: String = faces
+        .iter()
+        .zip(&parsed)
+        .zip(&used)
+        .filter(|(_, used)| **used)
+        .map(|(((id, family, data, _), face), _)| {
+            let style = match db.face(*id).map(|info| info.style) {
+                Some(fontdb::Style::Italic) => "italic",
+                Some(fontdb::Style::Oblique) => "oblique",
+                _ => "normal",
+            };
+            let weight = face
+                .as_ref()
+                .and_then(|face| {
+                    face.variation_axes()
+                        .into_iter()
+                        .find(|axis| axis.tag == ttf_parser::Tag::from_bytes(b"wght"))
+                })
+                .map_or_else(
+                    || db.face(*id).map_or(400, |info| info.weight.0).to_string(),
+                    |axis| format!("{} {}", axis.min_value, axis.max_value),
+                );
+            format!(
+                "@font-face {{ font-family: '{family}'; font-style: {style}; font-weight: {weight}; src: url({FONT_DATA_URL}{}) format('truetype'); }}",
+                STANDARD.encode(data)
+            )
+        })
+        .collect();
+    if rules.is_empty() {
+        return svg.to_string();
+    }
+    logger::detail(format!(
+        "Embedded {} font(s), {} KB",
+        used.iter().filter(|used| **used).count(),
+        rules.len() / 1024
+    ));
+    insert_after_root_tag(
+        &remove_font_file_rules(svg),
+        &format!("<style>{rules}</style>"),
+    )
+}
+
+/// Lowercased family names from `font-family` attributes and properties,
+/// without quotes.
+fn font_families(svg: &str) -> Vec<String> {
+    let mut families = Vec::new();
+    for (i, _) in svg.match_indices("font-family") {
+        let rest = &svg[i + "font-family".len()..];
+        let value = if let Some(rest) = rest.strip_prefix("=\"") {
+            rest.split('"').next()
+        } else if let Some(rest) = rest.trim_start().strip_prefix(':') {
+            rest.split([';', '}', '"']).next()
+        } else {
+            None
+        };
+        families.extend(value.into_iter().flat_map(|value| {
+            value
+                .split(',')
+                .map(|family| family.trim().trim_matches(['\'', '"']).to_lowercase())
+        }));
+    }
+    families
+}
+
+/// Non-ASCII characters of the page's text (ASCII is left to the named
+/// families), each once.
+fn text_chars(svg: &str) -> Vec<char> {
+    let mut chars = Vec::new();
+    let mut in_tag = false;
+    for c in svg.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag && !c.is_ascii() && !chars.contains(&c) => chars.push(c),
+            _ => {}
+        }
+    }
+    chars
+}
+
+/// Drops `@font-face` rules whose source isn't a data URL; in a copied page
+/// they'd point at files that aren't there.
+fn remove_font_file_rules(svg: &str) -> String {
+    let mut out = String::with_capacity(svg.len());
+    let mut rest = svg;
+    while let Some(start) = rest.find("@font-face") {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let rule = &rest[start..=start + len];
+        out.push_str(&rest[..start]);
+        if rule.contains("data:") {
+            out.push_str(rule);
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Font files embedded in the page by [`embed_fonts`].
+pub fn embedded_fonts(svg: &str) -> Vec<Vec<u8>> {
+    svg.match_indices(FONT_DATA_URL)
+        .filter_map(|(i, _)| {
+            let data = &svg[i + FONT_DATA_URL.len()..];
+            let end = data.find(')')?;
+            STANDARD.decode(&data[..end]).ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const ICON: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" viewBox="0 0 64 64" fill="none">
+  <defs><symbol id="b" viewBox="0 0 8 8"><circle cx="4" cy="4" r="4" fill="url(#g)"/></symbol></defs>
+  <use xlink:href="#b" width="64"/>
+</svg>"##;
+
+    fn write_icon(dir: &Path, name: &str) -> String {
+        let path = dir.join(name);
+        fs::write(&path, ICON).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    mod inline_icons {
+        use super::*;
+
+        #[test]
+        fn each_icon_becomes_one_symbol_with_prefixed_ids() {
+            let dir = tempfile::tempdir().unwrap();
+            let sun = write_icon(dir.path(), "clear-day.svg");
+            let page = format!(
+                r#"<svg width="800" height="480"><image x="1" width="75" height="75" href="{sun}" /><image filter="url(#panelIcons)" href="{sun}"/></svg>"#
+            );
+
+            let svg = inline_icons(&page);
+            assert!(!svg.contains("<image"), "{svg}");
+            assert_eq!(svg.matches("<symbol id=\"icon-clear-day\">").count(), 1);
+            assert!(svg.contains(r##"<use href="#icon-clear-day" x="1" width="75" height="75"/>"##));
+            assert!(svg.contains(r##"<use href="#icon-clear-day" filter="url(#panelIcons)"/>"##));
+            // The icon's own ids and references, xlink or not, are prefixed.
+            assert!(svg.contains(r#"<symbol id="icon-clear-day-b""#), "{svg}");
+            assert!(svg.contains(r##"fill="url(#icon-clear-day-g)""##));
+            assert!(svg.contains(r##"<use href="#icon-clear-day-b""##));
+            assert!(!svg.contains("xlink:"));
+            // The root's viewBox and presentation attributes carry over.
+            assert!(svg.contains(r#"<svg viewBox="0 0 64 64" fill="none">"#));
+        }
+
+        #[test]
+        fn icons_sharing_a_name_get_their_own_symbols() {
+            let fill = tempfile::tempdir().unwrap();
+            let line = tempfile::tempdir().unwrap();
+            let page = format!(
+                r#"<svg><image href="{}"/><image href="{}"/></svg>"#,
+                write_icon(fill.path(), "rain.svg"),
+                write_icon(line.path(), "rain.svg"),
+            );
+
+            let svg = inline_icons(&page);
+            assert!(svg.contains(r#"<symbol id="icon-rain">"#));
+            assert!(svg.contains(r#"<symbol id="icon-rain-2">"#));
+            assert!(svg.contains(r##"<symbol id="icon-rain-2-b""##));
+        }
+
+        #[test]
+        fn unreadable_and_embedded_images_are_left_alone() {
+            let page = r#"<svg><image href="missing/icon.svg"/><image href="data:image/png;base64,AAAA"/></svg>"#;
+            assert_eq!(inline_icons(page), page);
+        }
+    }
+
+    mod embed_fonts {
+        use super::*;
+
+        #[test]
+        fn named_families_and_fallbacks_are_embedded() {
+            let page = r#"<svg font-family="Roboto, sans-serif"><style>@font-face { font-family: 'Roboto'; src: url('static/fonts/Roboto-Regular.ttf'); }</style><text>日曜</text></svg>"#;
+
+            let svg = embed_fonts(page);
+            assert!(!svg.contains("static/fonts/"), "file rule left");
+            assert!(
+                svg.contains("font-family: 'Roboto'; font-style: normal; font-weight: 100 900;")
+            );
+            assert!(svg.contains("font-family: 'Roboto'; font-style: italic;"));
+            // Roboto has no kanji, so their fallback comes along.
+            assert!(svg.contains("font-family: 'Noto Sans CJK JP'"));
+            assert!(!svg.contains("font-family: 'Roboto-Regular-Dashed'"));
+            assert_eq!(embedded_fonts(&svg).len(), 3);
+        }
+
+        #[test]
+        fn embedded_fonts_round_trip() {
+            let svg =
+                embed_fonts(r#"<svg><text font-family="Roboto-Regular-Dashed">15</text></svg>"#);
+            let fonts = embedded_fonts(&svg);
+            assert_eq!(fonts.len(), 1);
+            assert!(ttf_parser::Face::parse(&fonts[0], 0).is_ok());
+        }
+
+        #[test]
+        fn fonts_are_optional() {
+            let page = r#"<svg font-family="Roboto"><text>15</text></svg>"#;
+            assert_eq!(make_self_contained(page, false), page);
+            assert!(make_self_contained(page, true).contains("@font-face"));
+        }
+
+        #[test]
+        fn pages_without_text_are_unchanged() {
+            let page = r#"<svg><rect width="10" height="10"/></svg>"#;
+            assert_eq!(embed_fonts(page), page);
+        }
+    }
+}
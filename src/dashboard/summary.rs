@@ -0,0 +1,869 @@
+//! One-line forecast summary under the date (`render_options.show_summary`).
+//!
+//! BOM's forecaster text is used when the dashboard is in English; otherwise
+//! the line is generated from the next 24 hours of the hourly series
+//! ("Showers developing after 3pm, clearing tonight. Gusty NW winds.") in
+//! the dashboard's language. Either way it is shortened, most detailed
+//! version first, until it fits between the current conditions and the
+//! diagnostics box.
+
+use chrono::{DateTime, Duration, DurationRound, Timelike, Utc};
+use chrono_tz::Tz;
+
+use crate::configs::settings::{DashboardSettings, HourFormat};
+use crate::dashboard::chart::format_hour_label;
+use crate::domain::models::{DailyForecast, HourlyForecast};
+use crate::domain::weather_code::WmoWeatherCode;
+use crate::i18n::Language;
+use crate::logger;
+use crate::utils::{fonts_cover, measure_text_width};
+
+const FONT_FAMILY: &str = "Roboto, sans-serif";
+const FONT_SIZE: f32 = 13.0;
+/// Width available to the line, centred under the date, in the 800×480
+/// templates. Composed layouts work theirs out from where the daily strip
+/// and diagnostics box are placed (see [`layouts::summary_width`]).
+///
+/// [`layouts::summary_width`]: crate::dashboard::widgets::layouts::summary_width
+pub const TEMPLATE_WIDTH: f32 = 340.0;
+/// Hourly precipitation chance from which an hour counts as wet.
+const WET_CHANCE: u16 = 50;
+/// Mean wind speed (km/h) from which the wind is "strong".
+const STRONG_WIND_KMH: u16 = 40;
+/// Gust speed (km/h) from which the wind is "gusty".
+const GUSTY_WIND_KMH: u16 = 50;
+/// Local hour from which the provider text for tomorrow is shown.
+const TOMORROW_FROM_HOUR: u32 = 18;
+
+/// The summary line for `now`, fitted to `max_width` under the date. Empty
+/// when there is no provider text and no hourly data to describe.
+pub fn forecast_summary(
+    hourly: &[HourlyForecast],
+    daily: &[DailyForecast],
+    now: DateTime<Utc>,
+    settings: &DashboardSettings,
+    max_width: f32,
+) -> String {
+    let timezone = settings.misc.timezone;
+    let language = settings.render_options.language;
+
+    let mut candidates = Vec::new();
+    if language == Language::En {
+        if let Some(day) = provider_day(daily, now, timezone) {
+            candidates.extend(provider_candidates(day));
+        }
+    }
+    if let Some(summary) = generate(hourly, now, timezone, settings.render_options.hour_format) {
+        candidates.extend(drawable_candidates(&summary, language, fonts_cover));
+    }
+
+    fit(&candidates, max_width, |text| {
+        measure_text_width(text, FONT_FAMILY, FONT_SIZE)
+            .unwrap_or(FONT_SIZE * 0.6 * text.chars().count() as f32)
+    })
+}
+
+/// `summary` in `language`, or in English if the bundled fonts can't draw
+/// it (the Japanese font is a subset, see `scripts/subset-japanese-font.sh`).
+fn drawable_candidates(
+    summary: &Summary,
+    language: Language,
+    covers: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    let candidates = summary.candidates(language);
+    if candidates.iter().all(|text| covers(text)) {
+        return candidates;
+    }
+    logger::warning(format!(
+        "The bundled fonts can't draw the {language} forecast summary, showing it in English"
+    ));
+    summary.candidates(Language::En)
+}
+
+/// Today's daily entry, or tomorrow's from the evening on.
+fn provider_day(
+    daily: &[DailyForecast],
+    now: DateTime<Utc>,
+    timezone: Tz,
+) -> Option<&DailyForecast> {
+    let local = now.with_timezone(&timezone);
+    let date = if local.hour() >= TOMORROW_FROM_HOUR {
+        local.date_naive().succ_opt()?
+    } else {
+        local.date_naive()
+    };
+    daily.iter().find(|day| day.date == Some(date))
+}
+
+/// The extended text, then with its trailing sentences dropped down to two,
+/// then the short text.
+fn provider_candidates(day: &DailyForecast) -> Vec<String> {
+    let mut candidates = Vec::new();
+    if let Some(text) = day.summary.as_deref().map(str::trim) {
+        let sentences: Vec<&str> = text
+            .split_inclusive(". ")
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .collect();
+        for count in (2..=sentences.len()).rev() {
+            candidates.push(sentences[..count].join(" "));
+        }
+        if sentences.len() == 1 {
+            candidates.push(text.to_string());
+        }
+    }
+    if let Some(text) = day.short_summary.as_deref().map(str::trim) {
+        if !text.is_empty() {
+            candidates.push(text.to_string());
+        }
+    }
+    candidates
+}
+
+/// The first candidate no wider than `max_width`, or the last one cut at a
+/// word (at a character for text without spaces, like Japanese) and ended
+/// with "…".
+fn fit(candidates: &[String], max_width: f32, width: impl Fn(&str) -> f32) -> String {
+    if let Some(text) = candidates.iter().find(|text| width(text) <= max_width) {
+        return text.clone();
+    }
+    let Some(last) = candidates.last() else {
+        return String::new();
+    };
+    let words: Vec<&str> = last.split_whitespace().collect();
+    let cuts: Vec<String> = if words.len() > 1 {
+        (1..words.len())
+            .rev()
+            .map(|count| words[..count].join(" "))
+            .collect()
+    } else {
+        let chars: Vec<char> = last.chars().collect();
+        (1..chars.len())
+            .rev()
+            .map(|count| chars[..count].iter().collect())
+            .collect()
+    };
+    cuts.into_iter()
+        .map(|text| format!("{}…", text.trim_end_matches(['.', ',', '。', '、'])))
+        .find(|text| width(text) <= max_width)
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PrecipitationKind {
+    Showers,
+    Rain,
+    Snow,
+    Storms,
+}
+
+/// When a change happens, in local time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum When {
+    At(u32),
+    /// 18:00-23:59
+    Tonight,
+    /// 00:00-05:59
+    Overnight,
+}
+
+impl When {
+    fn from_hour(hour: u32) -> Self {
+        match hour {
+            18..=23 => Self::Tonight,
+            0..=5 => Self::Overnight,
+            _ => Self::At(hour),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Outlook {
+    Dry,
+    /// Wet throughout the next 24 hours.
+    Continuing(PrecipitationKind),
+    /// Wet now, dry from `When`.
+    Clearing(PrecipitationKind, When),
+    /// Dry now, wet from the first `When` (until the second, if it clears).
+    Developing(PrecipitationKind, When, Option<When>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WindStrength {
+    Gusty,
+    Strong,
+}
+
+/// 8-point compass direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Compass(usize);
+
+impl Compass {
+    fn from_degrees(degrees: u16) -> Self {
+        Self(((f32::from(degrees) + 22.5) / 45.0) as usize % 8)
+    }
+
+    fn label(self, language: Language) -> &'static str {
+        let points = match language {
+            Language::Fr | Language::Es => ["N", "NE", "E", "SE", "S", "SO", "O", "NO"],
+            Language::De => ["N", "NO", "O", "SO", "S", "SW", "W", "NW"],
+            Language::En => ["N", "NE", "E", "SE", "S", "SW", "W", "NW"],
+            Language::Ja => ["北", "北東", "東", "南東", "南", "南西", "西", "北西"],
+        };
+        points[self.0]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Wind {
+    strength: WindStrength,
+    direction: Option<Compass>,
+}
+
+/// What the generated line describes, before it is put into words.
+#[derive(Debug, Clone, PartialEq)]
+struct Summary {
+    outlook: Outlook,
+    wind: Option<Wind>,
+    hour_format: HourFormat,
+}
+
+/// Describes the hours from the current one through the next 24.
+fn generate(
+    hourly: &[HourlyForecast],
+    now: DateTime<Utc>,
+    timezone: Tz,
+    hour_format: HourFormat,
+) -> Option<Summary> {
+    let start = now.duration_trunc(Duration::hours(1)).ok()?;
+    let end = start + Duration::hours(24);
+    let hours: Vec<&HourlyForecast> = hourly
+        .iter()
+        .filter(|hour| hour.time >= start && hour.time < end)
+        .collect();
+    if hours.is_empty() {
+        return None;
+    }
+
+    let local_hour = |hour: &HourlyForecast| hour.time.with_timezone(&timezone).hour();
+    let wet = |hour: &&HourlyForecast| hour.precipitation.chance.unwrap_or(0) >= WET_CHANCE;
+
+    let first_wet = hours.iter().position(&wet);
+    let last_wet = hours.iter().rposition(&wet);
+    let outlook = match (first_wet, last_wet) {
+        (Some(first), Some(last)) => {
+            let kind = precipitation_kind(hours.iter().copied().filter(wet));
+            let clears =
+                (last + 1 < hours.len()).then(|| When::from_hour(local_hour(hours[last + 1])));
+            match (first, clears) {
+                (0, None) => Outlook::Continuing(kind),
+                (0, Some(when)) => Outlook::Clearing(kind, when),
+                (first, clears) => {
+                    Outlook::Developing(kind, When::from_hour(local_hour(hours[first])), clears)
+                }
+            }
+        }
+        _ => Outlook::Dry,
+    };
+
+    Some(Summary {
+        outlook,
+        wind: wind(&hours),
+        hour_format,
+    })
+}
+
+/// Storms if any wet hour is thundery, then whichever of snow or steady rain
+/// most wet hours are, otherwise showers.
+fn precipitation_kind<'a>(
+    wet_hours: impl Iterator<Item = &'a HourlyForecast>,
+) -> PrecipitationKind {
+    let (mut total, mut snow, mut rain, mut storms) = (0, 0, 0, 0);
+    for hour in wet_hours {
+        total += 1;
+        let code = hour.weather_code.and_then(Result::ok);
+        match code {
+            Some(
+                WmoWeatherCode::Thunderstorm
+                | WmoWeatherCode::ThunderstormHailSlight
+                | WmoWeatherCode::ThunderstormHailHeavy,
+            ) => storms += 1,
+            Some(
+                WmoWeatherCode::SnowSlight
+                | WmoWeatherCode::SnowModerate
+                | WmoWeatherCode::SnowHeavy
+                | WmoWeatherCode::SnowGrains
+                | WmoWeatherCode::SnowShowersSlight
+                | WmoWeatherCode::SnowShowersHeavy,
+            ) => snow += 1,
+            _ if hour.precipitation.is_primarily_snow() => snow += 1,
+            Some(
+                WmoWeatherCode::DrizzleLight
+                | WmoWeatherCode::DrizzleModerate
+                | WmoWeatherCode::DrizzleDense
+                | WmoWeatherCode::FreezingDrizzleLight
+                | WmoWeatherCode::FreezingDrizzleDense
+                | WmoWeatherCode::RainSlight
+                | WmoWeatherCode::RainModerate
+                | WmoWeatherCode::RainHeavy
+                | WmoWeatherCode::FreezingRainLight
+                | WmoWeatherCode::FreezingRainHeavy,
+            ) => rain += 1,
+            _ => {}
+        }
+    }
+    if storms > 0 {
+        PrecipitationKind::Storms
+    } else if snow * 2 > total {
+        PrecipitationKind::Snow
+    } else if rain * 2 > total {
+        PrecipitationKind::Rain
+    } else {
+        PrecipitationKind::Showers
+    }
+}
+
+/// Strong or gusty wind over `hours`, from the direction at its peak.
+fn wind(hours: &[&HourlyForecast]) -> Option<Wind> {
+    let strongest = hours.iter().max_by_key(|hour| hour.wind.speed_kmh)?;
+    let gustiest = hours.iter().max_by_key(|hour| hour.wind.gust_speed_kmh)?;
+    let (strength, peak) = if strongest.wind.speed_kmh >= STRONG_WIND_KMH {
+        (WindStrength::Strong, strongest)
+    } else if gustiest.wind.gust_speed_kmh >= GUSTY_WIND_KMH {
+        (WindStrength::Gusty, gustiest)
+    } else {
+        return None;
+    };
+    Some(Wind {
+        strength,
+        direction: peak.wind.direction_deg.map(Compass::from_degrees),
+    })
+}
+
+impl Summary {
+    /// Precipitation and wind, then precipitation alone.
+    fn candidates(&self, language: Language) -> Vec<String> {
+        let outlook = sentence(&self.outlook_phrase(language), language);
+        match self.wind {
+            Some(wind) => {
+                let wind = sentence(&wind_phrase(wind, language), language);
+                let separator = if language == Language::Ja { "" } else { " " };
+                vec![format!("{outlook}{separator}{wind}"), outlook]
+            }
+            None => vec![outlook],
+        }
+    }
+
+    /// When precipitation starts: "after 3pm", "tonight".
+    fn when_phrase(&self, when: When, language: Language) -> String {
+        match when {
+            When::At(hour) => {
+                let time = format_hour_label(hour as f32, self.hour_format, language);
+                match language {
+                    Language::En => format!("after {time}"),
+                    Language::Fr => format!("après {time}"),
+                    Language::De => format!("ab {time}"),
+                    Language::Es => format!("a partir de las {time}"),
+                    Language::Ja => format!("{time}から"),
+                }
+            }
+            When::Tonight => match language {
+                Language::En => "tonight",
+                Language::Fr => "ce soir",
+                Language::De => "heute Abend",
+                Language::Es => "esta noche",
+                Language::Ja => "今夜から",
+            }
+            .to_string(),
+            When::Overnight => match language {
+                Language::En => "overnight",
+                Language::Fr => "dans la nuit",
+                Language::De => "in der Nacht",
+                Language::Es => "de madrugada",
+                Language::Ja => "夜間から",
+            }
+            .to_string(),
+        }
+    }
+
+    fn clearing_phrase(&self, when: When, language: Language) -> String {
+        match language {
+            Language::En => format!("clearing {}", self.when_phrase(when, language)),
+            Language::Fr => format!("puis éclaircies {}", self.when_phrase(when, language)),
+            Language::De => format!("{} abklingend", self.when_phrase(when, language)),
+            Language::Es => format!("despejando {}", self.when_phrase(when, language)),
+            Language::Ja => format!("{}には止む", self.japanese_until(when)),
+        }
+    }
+
+    /// Japanese says when precipitation stops differently from when it
+    /// starts: "15:00頃", "今夜".
+    fn japanese_until(&self, when: When) -> String {
+        match when {
+            When::At(hour) => {
+                let time = format_hour_label(hour as f32, self.hour_format, Language::Ja);
+                format!("{time}頃")
+            }
+            When::Tonight => "今夜".to_string(),
+            When::Overnight => "夜間".to_string(),
+        }
+    }
+
+    fn outlook_phrase(&self, language: Language) -> String {
+        match self.outlook {
+            Outlook::Dry => match language {
+                Language::En => "Staying dry",
+                Language::Fr => "Temps sec",
+                Language::De => "Trocken",
+                Language::Es => "Tiempo seco",
+                Language::Ja => "降水なし",
+            }
+            .to_string(),
+            Outlook::Continuing(kind) => continuing_phrase(kind, language),
+            Outlook::Clearing(kind, when) => {
+                let kind = kind_noun(kind, language);
+                let clearing = self.clearing_phrase(when, language);
+                match language {
+                    Language::En => format!("{kind} {clearing}"),
+                    Language::Ja => format!("{kind}は{clearing}"),
+                    Language::Fr | Language::De | Language::Es => format!("{kind}, {clearing}"),
+                }
+            }
+            Outlook::Developing(kind, from, clears) => {
+                let kind = kind_noun(kind, language);
+                let start = self.when_phrase(from, language);
+                let developing = match language {
+                    Language::En => format!("{kind} developing {start}"),
+                    Language::Ja => format!("{start}{kind}"),
+                    Language::Fr | Language::De | Language::Es => format!("{kind} {start}"),
+                };
+                let Some(when) = clears else {
+                    return developing;
+                };
+                let clearing = self.clearing_phrase(when, language);
+                match language {
+                    Language::Ja => format!("{developing}、{clearing}"),
+                    _ => format!("{developing}, {clearing}"),
+                }
+            }
+        }
+    }
+}
+
+/// `text` ended with the language's full stop.
+fn sentence(text: &str, language: Language) -> String {
+    match language {
+        Language::Ja => format!("{text}。"),
+        _ => format!("{text}."),
+    }
+}
+
+fn kind_noun(kind: PrecipitationKind, language: Language) -> &'static str {
+    use PrecipitationKind::*;
+    match (language, kind) {
+        (Language::En, Showers) => "Showers",
+        (Language::En, Rain) => "Rain",
+        (Language::En, Snow) => "Snow",
+        (Language::En, Storms) => "Storms",
+        (Language::Fr, Showers) => "Averses",
+        (Language::Fr, Rain) => "Pluie",
+        (Language::Fr, Snow) => "Neige",
+        (Language::Fr, Storms) => "Orages",
+        (Language::De, Showers) => "Schauer",
+        (Language::De, Rain) => "Regen",
+        (Language::De, Snow) => "Schneefall",
+        (Language::De, Storms) => "Gewitter",
+        (Language::Es, Showers) => "Chubascos",
+        (Language::Es, Rain) => "Lluvia",
+        (Language::Es, Snow) => "Nieve",
+        (Language::Es, Storms) => "Tormentas",
+        (Language::Ja, Showers) => "にわか雨",
+        (Language::Ja, Rain) => "雨",
+        (Language::Ja, Snow) => "雪",
+        (Language::Ja, Storms) => "雷雨",
+    }
+}
+
+fn continuing_phrase(kind: PrecipitationKind, language: Language) -> String {
+    use PrecipitationKind::*;
+    match (language, kind) {
+        (Language::En, kind) => format!("{} continuing", kind_noun(kind, language)),
+        (Language::Ja, kind) => format!("{}が続く", kind_noun(kind, language)),
+        (Language::Fr, Showers) => "Averses persistantes".to_string(),
+        (Language::Fr, Rain) => "Pluie persistante".to_string(),
+        (Language::Fr, Snow) => "Neige persistante".to_string(),
+        (Language::Fr, Storms) => "Orages persistants".to_string(),
+        (Language::De, Showers) => "Anhaltende Schauer".to_string(),
+        (Language::De, Rain) => "Anhaltender Regen".to_string(),
+        (Language::De, Snow) => "Anhaltender Schneefall".to_string(),
+        (Language::De, Storms) => "Anhaltende Gewitter".to_string(),
+        (Language::Es, Showers) => "Chubascos persistentes".to_string(),
+        (Language::Es, Rain) => "Lluvia persistente".to_string(),
+        (Language::Es, Snow) => "Nieve persistente".to_string(),
+        (Language::Es, Storms) => "Tormentas persistentes".to_string(),
+    }
+}
+
+fn wind_phrase(wind: Wind, language: Language) -> String {
+    let direction = wind.direction.map(|d| d.label(language));
+    match (language, wind.strength, direction) {
+        (Language::En, WindStrength::Gusty, Some(d)) => format!("Gusty {d} winds"),
+        (Language::En, WindStrength::Gusty, None) => "Gusty winds".to_string(),
+        (Language::En, WindStrength::Strong, Some(d)) => format!("Strong {d} winds"),
+        (Language::En, WindStrength::Strong, None) => "Strong winds".to_string(),
+        (Language::Fr, WindStrength::Gusty, Some(d)) => format!("Rafales de secteur {d}"),
+        (Language::Fr, WindStrength::Gusty, None) => "Rafales".to_string(),
+        (Language::Fr, WindStrength::Strong, Some(d)) => format!("Vent fort de secteur {d}"),
+        (Language::Fr, WindStrength::Strong, None) => "Vent fort".to_string(),
+        (Language::De, WindStrength::Gusty, Some(d)) => format!("Böiger Wind aus {d}"),
+        (Language::De, WindStrength::Gusty, None) => "Böiger Wind".to_string(),
+        (Language::De, WindStrength::Strong, Some(d)) => format!("Starker Wind aus {d}"),
+        (Language::De, WindStrength::Strong, None) => "Starker Wind".to_string(),
+        (Language::Es, WindStrength::Gusty, Some(d)) => format!("Rachas del {d}"),
+        (Language::Es, WindStrength::Gusty, None) => "Rachas".to_string(),
+        (Language::Es, WindStrength::Strong, Some(d)) => format!("Viento fuerte del {d}"),
+        (Language::Es, WindStrength::Strong, None) => "Viento fuerte".to_string(),
+        (Language::Ja, WindStrength::Gusty, Some(d)) => format!("{d}の突風"),
+        (Language::Ja, WindStrength::Gusty, None) => "突風".to_string(),
+        (Language::Ja, WindStrength::Strong, Some(d)) => format!("{d}の強風"),
+        (Language::Ja, WindStrength::Strong, None) => "強風".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{Precipitation, Temperature, Wind as HourlyWind};
+
+    fn time(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339).unwrap().to_utc()
+    }
+
+    const NOW: &str = "2025-10-25T10:00:00Z";
+
+    /// 24 dry, calm hours from 10:00 UTC, with `wet` hours' chance and
+    /// weather code and `wind` hours' gust (km/h) and direction replaced.
+    fn hours(
+        wet: &[(u32, Option<WmoWeatherCode>)],
+        wind: &[(u32, u16, u16)],
+    ) -> Vec<HourlyForecast> {
+        (0..24)
+            .map(|offset| {
+                let time = time(NOW) + Duration::hours(offset);
+                let hour = time.hour();
+                let wet = wet.iter().find(|(h, _)| *h == hour);
+                let (gust, direction) = wind
+                    .iter()
+                    .find(|(h, _, _)| *h == hour)
+                    .map_or((10, None), |&(_, gust, dir)| (gust, Some(dir)));
+                HourlyForecast {
+                    time,
+                    temperature: Temperature::celsius(15.0),
+                    apparent_temperature: Temperature::celsius(15.0),
+                    wind: HourlyWind::new(5, gust).with_direction(direction),
+                    precipitation: Precipitation::new(
+                        Some(if wet.is_some() { 70 } else { 10 }),
+                        None,
+                        Some(1),
+                    ),
+                    uv_index: 0,
+                    relative_humidity: 50,
+                    is_night: false,
+                    cloud_cover: None,
+                    weather_code: wet.and_then(|(_, code)| code.map(Ok)),
+                }
+            })
+            .collect()
+    }
+
+    fn wet_hours(range: std::ops::RangeInclusive<u32>) -> Vec<(u32, Option<WmoWeatherCode>)> {
+        range.map(|hour| (hour % 24, None)).collect()
+    }
+
+    fn describe(hourly: &[HourlyForecast], language: Language) -> Vec<String> {
+        generate(hourly, time(NOW), chrono_tz::UTC, HourFormat::Auto)
+            .unwrap()
+            .candidates(language)
+    }
+
+    mod generate {
+        use super::*;
+
+        #[test]
+        fn developing_then_clearing_with_gusts() {
+            let hourly = hours(&wet_hours(15..=19), &[(16, 60, 315)]);
+
+            assert_eq!(
+                describe(&hourly, Language::En),
+                [
+                    "Showers developing after 3pm, clearing tonight. Gusty NW winds.",
+                    "Showers developing after 3pm, clearing tonight.",
+                ]
+            );
+        }
+
+        #[test]
+        fn every_language_is_phrased() {
+            let hourly = hours(&wet_hours(15..=19), &[(16, 60, 315)]);
+            let full = |language| describe(&hourly, language)[0].clone();
+
+            assert_eq!(
+                full(Language::Fr),
+                "Averses après 15:00, puis éclaircies ce soir. Rafales de secteur NO."
+            );
+            assert_eq!(
+                full(Language::De),
+                "Schauer ab 15:00, heute Abend abklingend. Böiger Wind aus NW."
+            );
+            assert_eq!(
+                full(Language::Es),
+                "Chubascos a partir de las 15:00, despejando esta noche. Rachas del NO."
+            );
+            assert_eq!(
+                full(Language::Ja),
+                "15:00からにわか雨、今夜には止む。北西の突風。"
+            );
+        }
+
+        #[test]
+        fn japanese_phrasing() {
+            let rain = Some(WmoWeatherCode::RainModerate);
+            let clearing: Vec<_> = (10..=15).map(|hour| (hour, rain)).collect();
+            let mut strong = hours(&[], &[]);
+            strong[3].wind = HourlyWind::new(45, 55).with_direction(None);
+
+            assert_eq!(describe(&hours(&[], &[]), Language::Ja), ["降水なし。"]);
+            assert_eq!(
+                describe(&hours(&wet_hours(10..=33), &[]), Language::Ja),
+                ["にわか雨が続く。"]
+            );
+            assert_eq!(
+                describe(&hours(&clearing, &[]), Language::Ja),
+                ["雨は16:00頃には止む。"]
+            );
+            assert_eq!(describe(&strong, Language::Ja)[0], "降水なし。強風。");
+        }
+
+        #[test]
+        fn english_when_the_fonts_lack_glyphs() {
+            let hourly = hours(&wet_hours(15..=19), &[]);
+            let summary = generate(&hourly, time(NOW), chrono_tz::UTC, HourFormat::Auto).unwrap();
+
+            assert_eq!(
+                drawable_candidates(&summary, Language::Ja, |_| true),
+                ["15:00からにわか雨、今夜には止む。"]
+            );
+            assert_eq!(
+                drawable_candidates(&summary, Language::Ja, |_| false),
+                ["Showers developing after 3pm, clearing tonight."]
+            );
+        }
+
+        #[test]
+        fn dry_and_calm() {
+            assert_eq!(describe(&hours(&[], &[]), Language::En), ["Staying dry."]);
+            assert_eq!(describe(&hours(&[], &[]), Language::De), ["Trocken."]);
+        }
+
+        #[test]
+        fn wet_throughout_is_continuing() {
+            let hourly = hours(&wet_hours(10..=33), &[]);
+
+            assert_eq!(describe(&hourly, Language::En), ["Showers continuing."]);
+            assert_eq!(describe(&hourly, Language::Fr), ["Averses persistantes."]);
+        }
+
+        #[test]
+        fn wet_now_clears_overnight() {
+            let rain = Some(WmoWeatherCode::RainModerate);
+            let wet: Vec<_> = (10..=25).map(|hour| (hour % 24, rain)).collect();
+
+            assert_eq!(
+                describe(&hours(&wet, &[]), Language::En),
+                ["Rain clearing overnight."]
+            );
+            assert_eq!(
+                describe(&hours(&wet, &[]), Language::Es),
+                ["Lluvia, despejando de madrugada."]
+            );
+        }
+
+        #[test]
+        fn snow_and_storms_by_weather_code() {
+            let snow: Vec<_> = (15..=33)
+                .map(|hour| (hour % 24, Some(WmoWeatherCode::SnowModerate)))
+                .collect();
+            let mut storms = wet_hours(12..=13);
+            storms.push((14, Some(WmoWeatherCode::Thunderstorm)));
+
+            assert_eq!(
+                describe(&hours(&snow, &[]), Language::De),
+                ["Schneefall ab 15:00."]
+            );
+            assert_eq!(
+                describe(&hours(&storms, &[]), Language::En),
+                ["Storms developing after 12pm, clearing after 3pm."]
+            );
+        }
+
+        #[test]
+        fn strong_wind_outranks_gusts() {
+            let mut hourly = hours(&[], &[(20, 70, 90)]);
+            hourly[3].wind = HourlyWind::new(45, 55).with_direction(Some(200));
+
+            assert_eq!(
+                describe(&hourly, Language::En)[0],
+                "Staying dry. Strong S winds."
+            );
+            assert_eq!(
+                describe(&hourly, Language::Fr)[0],
+                "Temps sec. Vent fort de secteur S."
+            );
+        }
+
+        #[test]
+        fn past_hours_are_ignored() {
+            let earlier = generate(
+                &hours(&wet_hours(10..=11), &[]),
+                time("2025-10-25T12:30:00Z"),
+                chrono_tz::UTC,
+                HourFormat::Auto,
+            );
+
+            assert_eq!(earlier.unwrap().outlook, Outlook::Dry);
+            assert!(generate(&[], time(NOW), chrono_tz::UTC, HourFormat::Auto).is_none());
+        }
+    }
+
+    mod compass {
+        use super::*;
+
+        #[test]
+        fn eight_points_in_each_language() {
+            assert_eq!(Compass::from_degrees(0).label(Language::En), "N");
+            assert_eq!(Compass::from_degrees(350).label(Language::En), "N");
+            assert_eq!(Compass::from_degrees(68).label(Language::En), "E");
+            assert_eq!(Compass::from_degrees(225).label(Language::Fr), "SO");
+            assert_eq!(Compass::from_degrees(270).label(Language::Es), "O");
+            assert_eq!(Compass::from_degrees(90).label(Language::De), "O");
+            assert_eq!(Compass::from_degrees(315).label(Language::De), "NW");
+            assert_eq!(Compass::from_degrees(135).label(Language::Ja), "南東");
+        }
+    }
+
+    mod provider {
+        use super::*;
+
+        fn day(date: &str, summary: &str, short_summary: &str) -> DailyForecast {
+            DailyForecast {
+                date: Some(date.parse().unwrap()),
+                temp_max: None,
+                temp_min: None,
+                precipitation: None,
+                astronomical: None,
+                cloud_cover: None,
+                weather_code: None,
+                summary: Some(summary.to_string()),
+                short_summary: Some(short_summary.to_string()),
+            }
+        }
+
+        #[test]
+        fn drops_trailing_sentences_then_uses_the_short_text() {
+            let day = day(
+                "2025-10-25",
+                "Cloudy. Medium chance of showers. Light winds.",
+                "Shower or two.",
+            );
+
+            assert_eq!(
+                provider_candidates(&day),
+                [
+                    "Cloudy. Medium chance of showers. Light winds.",
+                    "Cloudy. Medium chance of showers.",
+                    "Shower or two.",
+                ]
+            );
+        }
+
+        #[test]
+        fn switches_to_tomorrow_in_the_evening() {
+            let daily = [
+                day("2025-10-25", "Today.", "Today."),
+                day("2025-10-26", "Tomorrow.", "Tomorrow."),
+            ];
+            let melbourne = chrono_tz::Australia::Melbourne;
+
+            let morning = provider_day(&daily, time("2025-10-24T22:00:00Z"), melbourne);
+            let evening = provider_day(&daily, time("2025-10-25T07:00:00Z"), melbourne);
+
+            assert_eq!(morning.unwrap().summary.as_deref(), Some("Today."));
+            assert_eq!(evening.unwrap().summary.as_deref(), Some("Tomorrow."));
+        }
+
+        #[test]
+        fn used_in_english_only() {
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.misc.timezone = chrono_tz::UTC;
+            settings.render_options.language = Language::En;
+            let daily = [day("2025-10-25", "Sunny.", "Sunny.")];
+            let hourly = hours(&[], &[]);
+
+            let english = forecast_summary(&hourly, &daily, time(NOW), &settings, TEMPLATE_WIDTH);
+            settings.render_options.language = Language::Fr;
+            let french = forecast_summary(&hourly, &daily, time(NOW), &settings, TEMPLATE_WIDTH);
+
+            assert_eq!(english, "Sunny.");
+            assert_eq!(french, "Temps sec.");
+        }
+    }
+
+    mod fit {
+        use super::*;
+
+        /// 10 units per character.
+        fn width(text: &str) -> f32 {
+            10.0 * text.chars().count() as f32
+        }
+
+        fn strings(texts: &[&str]) -> Vec<String> {
+            texts.iter().map(ToString::to_string).collect()
+        }
+
+        #[test]
+        fn first_candidate_that_fits() {
+            let candidates = strings(&["Much too long.", "Fits.", "Also."]);
+
+            assert_eq!(fit(&candidates, 60.0, width), "Fits.");
+        }
+
+        #[test]
+        fn cuts_the_last_candidate_at_a_word() {
+            let candidates = strings(&["Showers developing after 3pm."]);
+
+            assert_eq!(fit(&candidates, 200.0, width), "Showers developing…");
+            assert_eq!(fit(&candidates, 20.0, width), "");
+            assert_eq!(fit(&[], 200.0, width), "");
+        }
+
+        #[test]
+        fn cuts_text_without_spaces_at_a_character() {
+            let candidates = strings(&["15:00からにわか雨。"]);
+
+            assert_eq!(fit(&candidates, 80.0, width), "15:00から…");
+        }
+
+        #[test]
+        fn real_text_fits_the_space() {
+            let long =
+                "Cloudy. Very high chance of rain, most likely in the morning and afternoon.";
+            let candidates = strings(&[long, "Rain. Storm developing."]);
+
+            let fitted = fit(&candidates, TEMPLATE_WIDTH, |text| {
+                measure_text_width(text, FONT_FAMILY, FONT_SIZE).unwrap()
+            });
+
+            assert_eq!(fitted, "Rain. Storm developing.");
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! The classic dashboard's panels as widgets. Each is a fragment of
+//! today's template, rendered against the template
+//! [`Context`](crate::dashboard::context::Context) with the same
+//! formatters, in its own box. Placing them at the positions below rebuilds
+//! the classic layout:
+//!
+//! | Widget               | x   | y   | Size      |
+//! |----------------------|-----|-----|-----------|
+//! | `current_conditions` | 0   | 0   | 290 × 480 |
+//! | `daily_strip`        | 220 | 0   | 580 × 190 |
+//! | `hourly_chart`       | 220 | 160 | 580 × 320 |
+//! | `diagnostics`        | 580 | 0   | 220 × 100 |
+//!
+//! The hourly chart's size depends on `display.model` (see
+//! [`layouts::chart_metrics`](super::layouts::chart_metrics)); the others
+//! are scaled into place.
+
+use super::{layouts, Widget, WidgetFragment, WidgetInput};
+use crate::{
+    configs::settings::DashboardSettings,
+    dashboard::{context::Context, formatters},
+};
+use anyhow::Error;
+use serde::Serialize;
+use tinytemplate::{format_unescaped, TinyTemplate};
+
+/// A widget drawn by a TinyTemplate fragment over the template context.
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateWidget {
+    name: &'static str,
+    template: &'static str,
+    width: f32,
+    height: f32,
+}
+
+impl TemplateWidget {
+    pub const fn new(name: &'static str, template: &'static str, width: f32, height: f32) -> Self {
+        Self {
+            name,
+            template,
+            width,
+            height,
+        }
+    }
+}
+
+impl Widget for TemplateWidget {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn render(&self, input: &WidgetInput<'_>) -> Result<WidgetFragment, Error> {
+        Ok(WidgetFragment {
+            svg: render_fragment(self.name, self.template, input.settings, input.context)?,
+            width: self.width,
+            height: self.height,
+        })
+    }
+}
+
+fn render_fragment(
+    name: &str,
+    template: &str,
+    settings: &DashboardSettings,
+    context: &impl Serialize,
+) -> Result<String, Error> {
+    let mut tt = TinyTemplate::new();
+    formatters::register(&mut tt, settings);
+    tt.set_default_formatter(&format_unescaped);
+    tt.add_template(name, template)?;
+    Ok(tt.render(name, context)?)
+}
+
+/// Icon, temperatures, sunrise/sunset and the UV/wind/humidity table.
+pub const CURRENT_CONDITIONS: TemplateWidget = TemplateWidget::new(
+    "current_conditions",
+    include_str!("templates/current_conditions.svg"),
+    290.0,
+    480.0,
+);
+
+/// Date heading over the next six days.
+pub const DAILY_STRIP: TemplateWidget = TemplateWidget::new(
+    "daily_strip",
+    include_str!("templates/daily_strip.svg"),
+    580.0,
+    190.0,
+);
+
+/// The 24-hour chart, sized for the display model. Its template also sees
+/// the chart's `<svg>` size and view box.
+#[derive(Debug, Clone, Copy)]
+pub struct HourlyChart;
+
+#[derive(Serialize)]
+struct ChartContext<'a> {
+    #[serde(flatten)]
+    context: &'a Context,
+    chart_svg_width: String,
+    chart_svg_height: String,
+    chart_view_box: String,
+}
+
+impl Widget for HourlyChart {
+    fn name(&self) -> &str {
+        "hourly_chart"
+    }
+
+    fn render(&self, input: &WidgetInput<'_>) -> Result<WidgetFragment, Error> {
+        let metrics = layouts::chart_metrics(input.settings.display.model);
+        let context = ChartContext {
+            context: input.context,
+            chart_svg_width: metrics.svg_width.to_string(),
+            chart_svg_height: metrics.svg_height.to_string(),
+            chart_view_box: metrics.view_box(),
+        };
+        Ok(WidgetFragment {
+            svg: render_fragment(
+                self.name(),
+                include_str!("templates/hourly_chart.svg"),
+                input.settings,
+                &context,
+            )?,
+            width: metrics.box_width,
+            height: metrics.box_height,
+        })
+    }
+}
+
+/// Warning icons and message, plus version details when debugging.
+pub const DIAGNOSTICS: TemplateWidget = TemplateWidget::new(
+    "diagnostics",
+    include_str!("templates/diagnostics.svg"),
+    220.0,
+    100.0,
+);
+
+/// Every built-in widget, in the order they are registered.
+pub fn built_in_widgets() -> Vec<Box<dyn Widget>> {
+    vec![
+        Box::new(CURRENT_CONDITIONS),
+        Box::new(DAILY_STRIP),
+        Box::new(HourlyChart),
+        Box::new(DIAGNOSTICS),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_templates_parse() {
+        let templates = [
+            ("current_conditions", CURRENT_CONDITIONS.template),
+            ("daily_strip", DAILY_STRIP.template),
+            ("hourly_chart", include_str!("templates/hourly_chart.svg")),
+            ("diagnostics", DIAGNOSTICS.template),
+        ];
+        for (name, template) in templates {
+            let mut tt = TinyTemplate::new();
+            tt.add_template(name, template)
+                .unwrap_or_else(|e| panic!("{name}: {e}"));
+        }
+    }
+}
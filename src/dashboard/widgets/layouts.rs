@@ -0,0 +1,218 @@
+//! Built-in widget layouts for each `display.model`.
+//!
+//! Landscape panels scale the classic arrangement to the canvas, giving the
+//! hourly chart whatever is left once the side panel and daily strip are
+//! placed. Portrait stacks the daily strip, current conditions and a
+//! full-width chart.
+
+use crate::{
+    configs::settings::{DisplayModel, WidgetPlacement},
+    dashboard::{chart::ChartMetrics, summary},
+};
+
+const HOURLY_CHART: &str = "hourly_chart";
+const DAILY_STRIP: &str = "daily_strip";
+const DIAGNOSTICS: &str = "diagnostics";
+/// The daily strip's own box, and where its template centres the summary
+/// line (see `templates/daily_strip.svg`).
+const STRIP_SIZE: (f32, f32) = (580.0, 190.0);
+const SUMMARY_CENTRE: (f32, f32) = (180.0, 72.0);
+/// The diagnostics box's own size.
+const DIAGNOSTICS_SIZE: (f32, f32) = (220.0, 100.0);
+/// Space kept clear on each side of the summary line.
+const SUMMARY_MARGIN: f32 = 10.0;
+
+fn placement(widget: &str, x: f32, y: f32, size: Option<(f32, f32)>) -> WidgetPlacement {
+    WidgetPlacement {
+        widget: widget.to_string(),
+        x,
+        y,
+        width: size.map(|(width, _)| width),
+        height: size.map(|(_, height)| height),
+    }
+}
+
+/// The model's layout, drawn in order.
+pub fn built_in(model: DisplayModel) -> Vec<WidgetPlacement> {
+    let (canvas_width, canvas_height) = model.canvas();
+    match model {
+        DisplayModel::Impression73 => vec![
+            placement("current_conditions", 0.0, 0.0, None),
+            placement(DAILY_STRIP, 220.0, 0.0, None),
+            placement(HOURLY_CHART, 220.0, 160.0, None),
+            placement(DIAGNOSTICS, 580.0, 0.0, None),
+        ],
+        DisplayModel::Impression73Portrait => vec![
+            placement(DAILY_STRIP, 0.0, 0.0, Some((canvas_width, 158.0))),
+            placement(
+                "current_conditions",
+                0.0,
+                158.0,
+                Some((canvas_width, 362.0)),
+            ),
+            placement(HOURLY_CHART, 0.0, 520.0, Some((canvas_width, 280.0))),
+            placement(DIAGNOSTICS, canvas_width - 220.0, 0.0, None),
+        ],
+        DisplayModel::Impression57 | DisplayModel::Impression4 | DisplayModel::Impression133 => {
+            // Side panel and strip keep the classic proportions; the chart
+            // stretches into the rest. It starts below the strip, where the
+            // classic chart's plot starts once its own top margin is added.
+            let scale = (canvas_width / 800.0).min(canvas_height / 480.0);
+            let side = 220.0 * scale;
+            vec![
+                placement(
+                    "current_conditions",
+                    0.0,
+                    0.0,
+                    Some((290.0 * scale, 480.0 * scale)),
+                ),
+                placement(
+                    DAILY_STRIP,
+                    side,
+                    0.0,
+                    Some((canvas_width - side, 190.0 * scale)),
+                ),
+                placement(
+                    HOURLY_CHART,
+                    side,
+                    190.0 * scale,
+                    Some((canvas_width - side, canvas_height - 190.0 * scale)),
+                ),
+                placement(
+                    DIAGNOSTICS,
+                    canvas_width - 220.0 * scale,
+                    0.0,
+                    Some((220.0 * scale, 100.0 * scale)),
+                ),
+            ]
+        }
+    }
+}
+
+/// Width the forecast summary can take, in the daily strip's own units: as
+/// far as the strip's left edge, or the diagnostics box when that is drawn
+/// beside the line, whichever is nearer. [`summary::TEMPLATE_WIDTH`] when
+/// the layout has no daily strip.
+pub fn summary_width(placements: &[WidgetPlacement]) -> f32 {
+    let Some(strip) = placements.iter().find(|p| p.widget == DAILY_STRIP) else {
+        return summary::TEMPLATE_WIDTH;
+    };
+    // A resized region scales the strip to fit and centres it.
+    let (region_width, region_height) = region(strip, STRIP_SIZE);
+    let scale = (region_width / STRIP_SIZE.0).min(region_height / STRIP_SIZE.1);
+    let left = strip.x + (region_width - STRIP_SIZE.0 * scale) / 2.0;
+    let top = strip.y + (region_height - STRIP_SIZE.1 * scale) / 2.0;
+    let centre_x = left + SUMMARY_CENTRE.0 * scale;
+    let line_y = top + SUMMARY_CENTRE.1 * scale;
+
+    let diagnostics_gap = placements
+        .iter()
+        .filter(|p| p.widget == DIAGNOSTICS)
+        .filter(|p| {
+            let (_, height) = region(p, DIAGNOSTICS_SIZE);
+            p.x > centre_x && (p.y..=p.y + height).contains(&line_y)
+        })
+        .map(|p| p.x - centre_x)
+        .fold(f32::INFINITY, f32::min);
+    let half_width = (SUMMARY_CENTRE.0 * scale).min(diagnostics_gap) / scale;
+    2.0 * (half_width - SUMMARY_MARGIN)
+}
+
+/// The placement's size, or the widget's own when it isn't resized.
+fn region(placement: &WidgetPlacement, own: (f32, f32)) -> (f32, f32) {
+    (
+        placement.width.unwrap_or(own.0),
+        placement.height.unwrap_or(own.1),
+    )
+}
+
+/// How the hourly chart is sized for the model: the classic chart on the
+/// 7.3", otherwise sized to the region its built-in layout gives it.
+pub fn chart_metrics(model: DisplayModel) -> ChartMetrics {
+    built_in(model)
+        .iter()
+        .find(|p| p.widget == HOURLY_CHART)
+        .and_then(|p| Some((p.width?, p.height?)))
+        .map_or_else(ChartMetrics::classic, |region| {
+            ChartMetrics::for_region(model.canvas(), region)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODELS: [DisplayModel; 5] = [
+        DisplayModel::Impression73,
+        DisplayModel::Impression57,
+        DisplayModel::Impression4,
+        DisplayModel::Impression133,
+        DisplayModel::Impression73Portrait,
+    ];
+
+    #[test]
+    fn every_layout_stays_on_its_canvas() {
+        for model in MODELS {
+            let (canvas_width, canvas_height) = model.canvas();
+            for p in built_in(model) {
+                let (width, height) = (p.width.unwrap_or(0.0), p.height.unwrap_or(0.0));
+                assert!(
+                    p.x >= 0.0 && p.x + width <= canvas_width + 0.01,
+                    "{model}: {p}"
+                );
+                assert!(
+                    p.y >= 0.0 && p.y + height <= canvas_height + 0.01,
+                    "{model}: {p}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn classic_model_keeps_the_classic_chart() {
+        assert_eq!(
+            chart_metrics(DisplayModel::Impression73),
+            ChartMetrics::classic()
+        );
+    }
+
+    #[test]
+    fn summary_fits_beside_the_diagnostics_box() {
+        let width = |model| summary_width(&built_in(model));
+
+        assert_eq!(width(DisplayModel::Impression73), summary::TEMPLATE_WIDTH);
+        for model in [
+            DisplayModel::Impression57,
+            DisplayModel::Impression4,
+            DisplayModel::Impression133,
+        ] {
+            assert!(
+                (width(model) - summary::TEMPLATE_WIDTH).abs() < 0.01,
+                "{model}"
+            );
+        }
+        // Portrait puts the diagnostics box over the right of the strip.
+        let portrait = width(DisplayModel::Impression73Portrait);
+        assert!(portrait > 200.0 && portrait < 300.0, "{portrait}");
+        assert_eq!(summary_width(&[]), summary::TEMPLATE_WIDTH);
+    }
+
+    #[test]
+    fn chart_size_and_ticks_follow_the_canvas() {
+        let small = chart_metrics(DisplayModel::Impression4);
+        let large = chart_metrics(DisplayModel::Impression133);
+        let portrait = chart_metrics(DisplayModel::Impression73Portrait);
+
+        // The 13.3" chart fills a region four times the 4"'s area but at a
+        // larger scale, so its plot in chart units is similar.
+        assert_eq!(large.svg_width, 1600.0 - 440.0);
+        assert!((large.width - small.width).abs() < 100.0);
+        // Portrait: a short, full-width chart.
+        assert_eq!(portrait.svg_width, 480.0);
+        assert!(portrait.width > portrait.height);
+        for metrics in [small, large, portrait] {
+            assert_eq!(24 % metrics.x_ticks, 0);
+            assert!(matches!(metrics.y_ticks, 4 | 5));
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Composable dashboard widgets.
+//!
+//! A [`Widget`] turns the settings, clock and fetched forecasts into an SVG
+//! fragment drawn in its own coordinate box. When `[layout]` lists any
+//! widgets, the dashboard is composed from them, each placed (and scaled to
+//! fit, if resized) into its region of the canvas, instead of rendering
+//! `misc.template_path`.
+//!
+//! The built-in widgets split today's template into its panels; see
+//! [`builtin`]. Other widgets can be added to a [`WidgetRegistry`] before
+//! composing.
+
+pub mod builtin;
+pub mod layouts;
+
+use crate::{
+    clock::Clock,
+    configs::settings::{DashboardSettings, DisplayModel, WidgetPlacement},
+    dashboard::context::Context,
+    domain::models::{DailyForecast, HourlyForecast},
+};
+use anyhow::{bail, Error};
+
+/// Forecasts fetched for this render, as handed to the context builder.
+#[derive(Debug, Clone, Default)]
+pub struct ForecastData {
+    pub daily: Vec<DailyForecast>,
+    pub hourly: Vec<HourlyForecast>,
+}
+
+/// Everything a widget may draw from. `context` is the template context
+/// built from the same data, so widgets can reuse its formatted fields
+/// rather than re-deriving them.
+pub struct WidgetInput<'a> {
+    pub settings: &'a DashboardSettings,
+    pub clock: &'a dyn Clock,
+    pub forecast: &'a ForecastData,
+    pub context: &'a Context,
+}
+
+/// A rendered widget: SVG drawn within `0 0 width height`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidgetFragment {
+    pub svg: String,
+    pub width: f32,
+    pub height: f32,
+}
+
+pub trait Widget {
+    /// Name used to place the widget in `[layout]`.
+    fn name(&self) -> &str;
+
+    fn render(&self, input: &WidgetInput<'_>) -> Result<WidgetFragment, Error>;
+}
+
+/// Widgets available to a layout, looked up by name.
+pub struct WidgetRegistry {
+    widgets: Vec<Box<dyn Widget>>,
+}
+
+impl WidgetRegistry {
+    pub fn empty() -> Self {
+        Self {
+            widgets: Vec::new(),
+        }
+    }
+
+    pub fn with_built_ins() -> Self {
+        let mut registry = Self::empty();
+        for widget in builtin::built_in_widgets() {
+            registry.register(widget);
+        }
+        registry
+    }
+
+    /// Adds a widget, replacing any registered under the same name.
+    pub fn register(&mut self, widget: Box<dyn Widget>) -> &mut Self {
+        self.widgets.retain(|w| w.name() != widget.name());
+        self.widgets.push(widget);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Widget> {
+        self.widgets
+            .iter()
+            .find(|w| w.name() == name)
+            .map(|w| w.as_ref())
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.widgets.iter().map(|w| w.name()).collect()
+    }
+}
+
+/// Renders every placement in order (later ones draw on top) onto the
+/// `display.model` canvas. Fails on a widget name the registry doesn't know, or when a
+/// widget fails to render.
+pub fn compose(
+    placements: &[WidgetPlacement],
+    registry: &WidgetRegistry,
+    input: &WidgetInput<'_>,
+) -> Result<String, Error> {
+    let mut regions = Vec::with_capacity(placements.len());
+    for placement in placements {
+        let Some(widget) = registry.get(&placement.widget) else {
+            bail!(
+                "Unknown widget \"{}\" in [layout]; available: {}",
+                placement.widget,
+                registry.names().join(", ")
+            );
+        };
+        let fragment = widget.render(input)?;
+        regions.push(place(placement, &fragment));
+    }
+
+    let (canvas_width, canvas_height) = input.settings.display.model.canvas();
+    Ok(format!(
+        r#"<svg width="{canvas_width}" height="{canvas_height}" font-family="Roboto, sans-serif" xmlns="http://www.w3.org/2000/svg">
+    <rect width="100%" height="100%" fill="{background}" />
+    {regions}
+    <!-- These are needed render svg in browser/ide -->
+    <style>
+        @font-face {{
+            font-family: 'Roboto';
+            src: url('static/fonts/Roboto-Regular.ttf') format('truetype');
+        }}
+
+        @font-face {{
+            font-family: 'Roboto-Regular-Dashed';
+            src: url('static/fonts/Roboto-Regular-Dashed.ttf') format('truetype');
+        }}
+
+        .line {{
+            stroke: {text};
+            stroke-width: 2;
+            stroke-linecap: round;
+            stroke-linejoin: round;
+        }}
+    </style>
+</svg>
+"#,
+        background = input.context.background_colour,
+        text = input.context.text_colour,
+        regions = regions.join("\n    "),
+    ))
+}
+
+/// Scales a page drawn for the 800×480 canvas (the classic and outlook
+/// templates) onto `model`'s canvas, centred, with `background` in the
+/// margins. Returned unchanged for the 7.3".
+pub fn fit_to_canvas(svg: String, model: DisplayModel, background: &str) -> String {
+    let (width, height) = model.canvas();
+    if (width, height) == DisplayModel::Impression73.canvas() {
+        return svg;
+    }
+    format!(
+        r#"<svg width="{width}" height="{height}" viewBox="0 0 800 480" preserveAspectRatio="xMidYMid meet" xmlns="http://www.w3.org/2000/svg">
+    <rect x="-100%" y="-100%" width="300%" height="300%" fill="{background}" />
+    {svg}
+</svg>
+"#
+    )
+}
+
+/// Wraps a fragment in a nested `<svg>` at the placement's region. The
+/// fragment's own box becomes the `viewBox`, so a resized region scales it
+/// to fit, keeping its aspect ratio. Overflow stays visible, as panels in
+/// the classic layout overlap slightly.
+fn place(placement: &WidgetPlacement, fragment: &WidgetFragment) -> String {
+    let width = placement.width.unwrap_or(fragment.width);
+    let height = placement.height.unwrap_or(fragment.height);
+    format!(
+        r#"<svg x="{x}" y="{y}" width="{width}" height="{height}" viewBox="0 0 {fw} {fh}" preserveAspectRatio="xMidYMid meet" style="overflow: visible;">
+    {svg}
+    </svg>"#,
+        x = placement.x,
+        y = placement.y,
+        fw = fragment.width,
+        fh = fragment.height,
+        svg = fragment.svg,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::dashboard::context::ContextBuilder;
+    use chrono::{TimeZone, Utc};
+
+    struct Label;
+
+    impl Widget for Label {
+        fn name(&self) -> &str {
+            "label"
+        }
+
+        fn render(&self, input: &WidgetInput<'_>) -> Result<WidgetFragment, Error> {
+            Ok(WidgetFragment {
+                svg: format!(
+                    r#"<text x="0" y="20" fill="{}">{} hours</text>"#,
+                    input.context.text_colour,
+                    input.forecast.hourly.len()
+                ),
+                width: 100.0,
+                height: 40.0,
+            })
+        }
+    }
+
+    fn placement(widget: &str, width: Option<f32>) -> WidgetPlacement {
+        WidgetPlacement {
+            widget: widget.to_string(),
+            x: 10.0,
+            y: 20.0,
+            width,
+            height: None,
+        }
+    }
+
+    fn compose_with(placements: &[WidgetPlacement]) -> Result<String, Error> {
+        let settings = DashboardSettings::load_test_config().unwrap();
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 12, 17, 1, 0, 0).unwrap());
+        let context = ContextBuilder::new(&settings, &clock).context;
+        let forecast = ForecastData::default();
+        let input = WidgetInput {
+            settings: &settings,
+            clock: &clock,
+            forecast: &forecast,
+            context: &context,
+        };
+        let mut registry = WidgetRegistry::with_built_ins();
+        registry.register(Box::new(Label));
+        compose(placements, &registry, &input)
+    }
+
+    #[test]
+    fn custom_widgets_are_placed_at_their_region() {
+        let svg = compose_with(&[placement("label", None)]).unwrap();
+        assert!(
+            svg.contains(r#"<svg x="10" y="20" width="100" height="40" viewBox="0 0 100 40""#),
+            "{svg}"
+        );
+        assert!(svg.contains(">0 hours</text>"));
+    }
+
+    #[test]
+    fn resized_regions_scale_the_fragment() {
+        let svg = compose_with(&[placement("label", Some(200.0))]).unwrap();
+        assert!(
+            svg.contains(r#"width="200" height="40" viewBox="0 0 100 40""#),
+            "{svg}"
+        );
+    }
+
+    #[test]
+    fn later_placements_draw_on_top() {
+        let svg =
+            compose_with(&[placement("diagnostics", None), placement("label", None)]).unwrap();
+        let diagnostics = svg.find(r#"viewBox="0 0 220 100""#).unwrap();
+        assert!(diagnostics < svg.find("hours</text>").unwrap());
+    }
+
+    #[test]
+    fn unknown_widgets_are_rejected() {
+        let error = compose_with(&[placement("clock_face", None)]).unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("\"clock_face\""), "{message}");
+        assert!(message.contains("hourly_chart"), "{message}");
+    }
+
+    #[test]
+    fn pages_are_fitted_to_other_canvases() {
+        let page = r#"<svg width="800" height="480"></svg>"#.to_string();
+        assert_eq!(
+            fit_to_canvas(page.clone(), DisplayModel::Impression73, "white"),
+            page
+        );
+        let fitted = fit_to_canvas(page.clone(), DisplayModel::Impression73Portrait, "black");
+        assert!(
+            fitted.starts_with(r#"<svg width="480" height="800" viewBox="0 0 800 480""#),
+            "{fitted}"
+        );
+        assert!(fitted.contains(r#"fill="black""#));
+        assert!(fitted.contains(&page));
+    }
+
+    #[test]
+    fn registering_a_name_again_replaces_the_widget() {
+        let mut registry = WidgetRegistry::with_built_ins();
+        let count = registry.names().len();
+        registry.register(Box::new(Label)).register(Box::new(Label));
+        assert_eq!(registry.names().len(), count + 1);
+    }
+}
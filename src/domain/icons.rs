@@ -4,6 +4,7 @@ use crate::weather::icons::{
     DayNight, Icon, IconContext, PrecipitationChanceName, PrecipitationKind, WindIconName,
 };
 use crate::weather::utils::moon_phase_icon_name;
+use strum::IntoEnumIterator;
 
 // ============================================================================
 // Icon implementations for domain models
@@ -81,6 +82,38 @@ fn precipitation_amount_to_name(
     }
 }
 
+/// Everything [`precipitation_amount_to_name`] can return.
+const FALLBACK_AMOUNTS: [Option<PrecipitationKind>; 4] = [
+    None,
+    Some(PrecipitationKind::Drizzle),
+    Some(PrecipitationKind::Rain),
+    Some(PrecipitationKind::Snow),
+];
+
+/// Icon name for forecasts without a usable weather code, e.g.
+/// "overcast-night-rain.svg", before the moon phase override.
+fn fallback_icon_name(
+    raw_cloud_name: PrecipitationChanceName,
+    amount_name: Option<PrecipitationKind>,
+    day_night: DayNight,
+) -> String {
+    // Apply precipitation override: ensure heavy rain requires adequate cloud cover
+    // Note: After override, Clear can only occur with amount_name = None
+    let cloud_name = apply_precipitation_override(raw_cloud_name, amount_name);
+    let suffix = amount_name.map(|k| k.to_string()).unwrap_or_default();
+    format!("{cloud_name}{day_night}{suffix}.svg")
+}
+
+/// Every name [`fallback_icon_name`] can produce, for icon pack validation.
+pub(crate) fn fallback_icon_names() -> impl Iterator<Item = String> {
+    PrecipitationChanceName::iter().flat_map(|cloud_name| {
+        FALLBACK_AMOUNTS.into_iter().flat_map(move |amount_name| {
+            [DayNight::Day, DayNight::Night]
+                .map(|day_night| fallback_icon_name(cloud_name, amount_name, day_night))
+        })
+    })
+}
+
 /// Ensures precipitation amount requires minimum cloud coverage.
 /// Heavy precipitation cannot occur with completely clear skies.
 ///
@@ -172,13 +205,7 @@ impl Icon for DailyForecast {
             };
 
             let amount_name = precipitation_amount_to_name(precip, false);
-
-            // Apply precipitation override: ensure heavy rain requires adequate cloud cover
-            // Note: After override, Clear can only occur with amount_name = None
-            let cloud_name = apply_precipitation_override(raw_cloud_name, amount_name);
-            let suffix = amount_name.map(|k| k.to_string()).unwrap_or_default();
-
-            format!("{cloud_name}{}{suffix}.svg", DayNight::Day)
+            fallback_icon_name(raw_cloud_name, amount_name, DayNight::Day)
         } else {
             // Default to clear day if no precipitation data
             format!("{}{}.svg", PrecipitationChanceName::Clear, DayNight::Day)
@@ -221,12 +248,7 @@ impl Icon for HourlyForecast {
             DayNight::Day
         };
 
-        // Apply precipitation override: ensure heavy rain requires adequate cloud cover
-        // Note: After override, Clear can only occur with amount_name = None
-        let cloud_name = apply_precipitation_override(raw_cloud_name, amount_name);
-        let suffix = amount_name.map(|k| k.to_string()).unwrap_or_default();
-
-        let icon = format!("{cloud_name}{day_night}{suffix}.svg");
+        let icon = fallback_icon_name(raw_cloud_name, amount_name, day_night);
         apply_moon_phase_override(icon, self.is_night, ctx)
     }
 }
@@ -445,6 +467,8 @@ mod tests {
                 astronomical: None,
                 cloud_cover: None,
                 weather_code: None,
+                summary: None,
+                short_summary: None,
             };
 
             assert_eq!(forecast.icon_name(&ctx), "clear-day.svg");
@@ -526,6 +550,8 @@ mod tests {
                 astronomical: None,
                 cloud_cover: None,
                 weather_code: None,
+                summary: None,
+                short_summary: None,
             };
 
             assert_eq!(forecast.icon_name(&ctx), "clear-day.svg");
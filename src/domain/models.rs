@@ -1,4 +1,5 @@
 use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer};
 use std::{
     fmt::{self, Display},
     ops::Deref,
@@ -8,7 +9,7 @@ use crate::configs::settings::TemperatureUnit;
 use crate::domain::weather_code::WmoWeatherCode;
 
 /// Domain-specific Temperature type, independent of any API
-#[derive(Debug, Copy, PartialOrd, PartialEq, Clone)]
+#[derive(Debug, Copy, PartialOrd, PartialEq, Clone, Deserialize)]
 pub struct Temperature {
     pub value: f32,
     pub unit: TemperatureUnit,
@@ -94,10 +95,14 @@ impl From<crate::apis::bom::models::Temperature> for Temperature {
 }
 
 /// Domain model for wind information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Wind {
     pub speed_kmh: u16,
     pub gust_speed_kmh: u16,
+    /// Meteorological direction the wind blows *from*, in degrees clockwise
+    /// from north (0-359). `None` when calm or not provided.
+    #[serde(default)]
+    pub direction_deg: Option<u16>,
 }
 
 impl Wind {
@@ -105,9 +110,17 @@ impl Wind {
         Self {
             speed_kmh,
             gust_speed_kmh,
+            direction_deg: None,
         }
     }
 
+    /// Sets the direction, normalising it into 0-359 (Open-Meteo reports due
+    /// north as either 0 or 360).
+    pub fn with_direction(mut self, direction_deg: Option<u16>) -> Self {
+        self.direction_deg = direction_deg.map(|d| d % 360);
+        self
+    }
+
     pub fn speed(&self, use_gust: bool) -> u16 {
         if use_gust {
             self.gust_speed_kmh
@@ -138,7 +151,7 @@ impl Wind {
 }
 
 /// Domain model for precipitation information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Precipitation {
     pub chance: Option<u16>,
     pub amount_min: Option<u16>,
@@ -241,7 +254,7 @@ impl Precipitation {
 /// Domain model for astronomical data
 /// Sunrise/sunset times are stored as NaiveDateTime (timezone-agnostic wall-clock times)
 /// since they represent the actual clock time at the location, not a UTC timestamp
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
 pub struct Astronomical {
     pub sunrise_time: Option<NaiveDateTime>,
     pub sunset_time: Option<NaiveDateTime>,
@@ -249,7 +262,7 @@ pub struct Astronomical {
 
 /// Domain model for hourly weather forecast
 /// This is what the application works with, independent of any API
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct HourlyForecast {
     pub time: DateTime<Utc>,
     pub temperature: Temperature,
@@ -261,12 +274,13 @@ pub struct HourlyForecast {
     pub is_night: bool,
     pub cloud_cover: Option<u16>,
     /// Parsed WMO Weather Interpretation Code — `Ok` if recognised, `Err(raw)` if not, `None` if absent
+    #[serde(default, deserialize_with = "deserialize_weather_code")]
     pub weather_code: Option<Result<WmoWeatherCode, u8>>,
 }
 
 /// Domain model for daily weather forecast
 /// This is what the application works with, independent of any API
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct DailyForecast {
     /// Calendar date (timezone-agnostic) representing the forecast day
     pub date: Option<NaiveDate>,
@@ -276,7 +290,24 @@ pub struct DailyForecast {
     pub astronomical: Option<Astronomical>,
     pub cloud_cover: Option<u16>,
     /// Parsed WMO Weather Interpretation Code — `Ok` if recognised, `Err(raw)` if not, `None` if absent
+    #[serde(default, deserialize_with = "deserialize_weather_code")]
     pub weather_code: Option<Result<WmoWeatherCode, u8>>,
+    /// Forecaster's text for the day ("Cloudy. Medium chance of showers.")
+    pub summary: Option<String>,
+    /// One-line version of `summary` ("Shower or two.")
+    pub short_summary: Option<String>,
+}
+
+/// Reads a weather code in the neutral forecast JSON as the raw WMO number,
+/// parsed the same way as Open-Meteo's.
+fn deserialize_weather_code<'de, D>(
+    deserializer: D,
+) -> Result<Option<Result<WmoWeatherCode, u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let code = Option::<u8>::deserialize(deserializer)?;
+    Ok(code.map(|code| WmoWeatherCode::try_from(code).map_err(|()| code)))
 }
 
 // ============================================================================
@@ -295,7 +326,8 @@ impl HourlyForecast {
             time: bom.time,
             temperature: Temperature::from(bom.temp).to_unit(unit),
             apparent_temperature: Temperature::from(bom.temp_feels_like).to_unit(unit),
-            wind: Wind::new(bom.wind.speed_kilometre, bom.wind.gust_speed_kilometre),
+            wind: Wind::new(bom.wind.speed_kilometre, bom.wind.gust_speed_kilometre)
+                .with_direction(bom.wind.direction_degrees()),
             precipitation: Precipitation::new(
                 bom.rain.chance,
                 bom.rain.amount.min,
@@ -334,6 +366,8 @@ impl DailyForecast {
             }),
             cloud_cover: None,  // BOM API doesn't provide cloud cover data
             weather_code: None, // BOM API doesn't provide WMO weather codes
+            summary: bom.extended_text,
+            short_summary: bom.short_text,
         }
     }
 }
@@ -480,6 +514,7 @@ mod tests {
             assert_eq!(forecast.precipitation.amount_max, Some(100));
             assert_eq!(forecast.wind.speed_kmh, 111);
             assert_eq!(forecast.wind.gust_speed_kmh, 148);
+            assert_eq!(forecast.wind.direction_deg, Some(180));
             assert_eq!(forecast.uv_index, 14);
         }
 
@@ -586,8 +621,9 @@ mod tests {
             fn any_bom_wind()(
                 speed_kilometre in any::<u16>(),
                 gust_speed_kilometre in any::<u16>(),
+                direction in proptest::option::of("[A-Z]{0,5}"),
             ) -> BomWind {
-                BomWind { speed_kilometre, gust_speed_kilometre }
+                BomWind { speed_kilometre, direction, gust_speed_kilometre }
             }
         }
 
@@ -638,6 +674,8 @@ mod tests {
                     date,
                     temp_max,
                     temp_min,
+                    extended_text: None,
+                    short_text: None,
                 }
             }
         }
@@ -724,6 +762,7 @@ mod tests {
                     "uv_index": [0.0, 0.0],
                     "wind_speed_10m": [15.0, 18.0],
                     "wind_gusts_10m": [25.0, 30.0],
+                    "wind_direction_10m": [270, 360],
                     "relative_humidity_2m": [65, 70],
                     "cloud_cover": [30, 45]
                 },
@@ -756,6 +795,9 @@ mod tests {
             assert_eq!(domain[1].apparent_temperature.value, 16.0);
             assert_eq!(domain[1].precipitation.chance, Some(20));
             assert_eq!(domain[1].wind.speed_kmh, 18);
+            assert_eq!(domain[0].wind.direction_deg, Some(270));
+            // Open-Meteo reports due north as 360; normalised to 0.
+            assert_eq!(domain[1].wind.direction_deg, Some(0));
         }
 
         /// `is_night` comes directly from the hourly `is_day` array (each hour
@@ -865,6 +907,44 @@ mod tests {
             );
         }
 
+        /// Same pre-upgrade cache story as `is_day`: a cache without
+        /// `wind_direction_10m` still loads, with no direction to draw.
+        #[test]
+        fn hourly_missing_wind_direction_defaults_to_none() {
+            let json = r#"{
+                "latitude": -37.75, "longitude": 144.875, "timezone": "GMT",
+                "current_units": {"time": "iso8601", "interval": "seconds", "is_day": ""},
+                "current": {"time": "2025-10-10T12:00", "interval": 900, "is_day": 1},
+                "hourly_units": {
+                    "time": "iso8601", "temperature_2m": "°C", "apparent_temperature": "°C",
+                    "precipitation_probability": "%", "precipitation": "mm", "snowfall": "cm",
+                    "uv_index": "", "wind_speed_10m": "km/h", "wind_gusts_10m": "km/h",
+                    "relative_humidity_2m": "%"
+                },
+                "hourly": {
+                    "time": ["2025-10-10T00:00"],
+                    "temperature_2m": [10.0],
+                    "apparent_temperature": [10.0],
+                    "precipitation_probability": [0],
+                    "precipitation": [0.0], "snowfall": [0.0],
+                    "uv_index": [0.0],
+                    "wind_speed_10m": [5.0],
+                    "wind_gusts_10m": [10.0],
+                    "relative_humidity_2m": [50],
+                    "cloud_cover": [0]
+                }
+            }"#;
+
+            let response: OpenMeteoHourlyResponse =
+                serde_json::from_str(json).expect("inline fixture should deserialize");
+            let settings = DashboardSettings::load_test_config().unwrap();
+            let domain: Vec<HourlyForecast> = response.into_domain(&settings);
+
+            assert_eq!(domain.len(), 1);
+            assert_eq!(domain[0].wind.speed_kmh, 5);
+            assert_eq!(domain[0].wind.direction_deg, None);
+        }
+
         #[test]
         fn extreme_values_are_preserved() {
             let json = r#"{
@@ -1119,6 +1199,7 @@ mod tests {
                 wind_speed_10m in proptest::collection::vec(any::<f32>(), count),
                 wind_gusts_10m in proptest::collection::vec(any::<f32>(), count),
                 relative_humidity_2m in proptest::collection::vec(any::<u16>(), count),
+                wind_direction_10m in proptest::collection::vec(proptest::option::of(any::<u16>()), count),
                 cloud_cover in proptest::collection::vec(proptest::option::of(any::<u16>()), count),
                 is_day in proptest::collection::vec(any::<u16>(), count),
                 weather_code in proptest::option::of(proptest::collection::vec(any::<u8>(), count)),
@@ -1133,6 +1214,7 @@ mod tests {
                     uv_index,
                     wind_speed_10m,
                     wind_gusts_10m,
+                    wind_direction_10m,
                     relative_humidity_2m,
                     cloud_cover,
                     weather_code,
@@ -8,6 +8,8 @@
 
 use std::fmt;
 
+use strum_macros::EnumIter;
+
 use crate::weather::icons::{DayNight, PrecipitationChanceName, PrecipitationKind};
 
 /// WMO Weather Interpretation Codes
@@ -15,7 +17,7 @@ use crate::weather::icons::{DayNight, PrecipitationChanceName, PrecipitationKind
 /// These codes are provided by Open-Meteo API and represent the current
 /// weather condition as a single categorical value rather than separate
 /// precipitation/cloud/visibility data.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
 pub enum WmoWeatherCode {
     /// Code 0: Clear sky
     ClearSky,
@@ -114,6 +116,40 @@ impl TryFrom<u8> for WmoWeatherCode {
 }
 
 impl WmoWeatherCode {
+    /// The numeric WMO code, the inverse of `TryFrom<u8>`.
+    pub fn code(self) -> u8 {
+        match self {
+            Self::ClearSky => 0,
+            Self::MainlyClear => 1,
+            Self::PartlyCloudy => 2,
+            Self::Overcast => 3,
+            Self::Fog => 45,
+            Self::RimeFog => 48,
+            Self::DrizzleLight => 51,
+            Self::DrizzleModerate => 53,
+            Self::DrizzleDense => 55,
+            Self::FreezingDrizzleLight => 56,
+            Self::FreezingDrizzleDense => 57,
+            Self::RainSlight => 61,
+            Self::RainModerate => 63,
+            Self::RainHeavy => 65,
+            Self::FreezingRainLight => 66,
+            Self::FreezingRainHeavy => 67,
+            Self::SnowSlight => 71,
+            Self::SnowModerate => 73,
+            Self::SnowHeavy => 75,
+            Self::SnowGrains => 77,
+            Self::RainShowersSlight => 80,
+            Self::RainShowersModerate => 81,
+            Self::RainShowersViolent => 82,
+            Self::SnowShowersSlight => 85,
+            Self::SnowShowersHeavy => 86,
+            Self::Thunderstorm => 95,
+            Self::ThunderstormHailSlight => 96,
+            Self::ThunderstormHailHeavy => 99,
+        }
+    }
+
     /// Convert WMO weather code to icon filename
     ///
     /// Uses recommended intensity gradation:
@@ -439,6 +475,15 @@ mod tests {
             .contains("extreme"));
     }
 
+    #[test]
+    fn code_round_trips_through_try_from() {
+        for raw in 0..=u8::MAX {
+            if let Ok(code) = WmoWeatherCode::try_from(raw) {
+                assert_eq!(code.code(), raw);
+            }
+        }
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(WmoWeatherCode::Thunderstorm.to_string(), "Thunderstorm");
@@ -1,5 +1,5 @@
 use std::fmt;
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
 use crate::weather::icons::{Icon, IconContext};
 
@@ -32,7 +32,7 @@ impl fmt::Display for DashboardError {
     }
 }
 
-#[derive(Debug, Display)]
+#[derive(Debug, Display, EnumIter)]
 pub enum DashboardErrorIconName {
     #[strum(to_string = "code-orange.svg")]
     NoInternet,
@@ -34,6 +34,20 @@ pub enum TranslationKey {
     Metric,
     Now,
     Max,
+    /// Followed by the time the forecast was fetched.
+    Updated,
+    /// `{days}` is replaced by the age of the forecast in whole days.
+    DataDaysOld,
+    /// Title of the full-screen error page.
+    UpdateFailed,
+    /// Followed by when the forecast was last cached.
+    LastGoodData,
+    /// Shown after [`Self::LastGoodData`] when nothing was ever cached.
+    NoData,
+    /// Error page hint when a file couldn't be found.
+    MissingFileHint,
+    /// Error page hint for any other failure.
+    NetworkHint,
 }
 
 pub fn translate(key: TranslationKey, language: Language) -> &'static str {
@@ -63,6 +77,63 @@ pub fn translate(key: TranslationKey, language: Language) -> &'static str {
         (Language::Ja, TranslationKey::Metric) => "指標",
         (Language::Ja, TranslationKey::Now) => "今",
         (Language::Ja, TranslationKey::Max) => "最大",
+        (Language::En, TranslationKey::Updated) => "Updated",
+        (Language::Fr, TranslationKey::Updated) => "Mis à jour",
+        (Language::De, TranslationKey::Updated) => "Aktualisiert",
+        (Language::Es, TranslationKey::Updated) => "Actualizado",
+        (Language::En, TranslationKey::DataDaysOld) => "Data {days} d old",
+        (Language::Fr, TranslationKey::DataDaysOld) => "Données de {days} j",
+        (Language::De, TranslationKey::DataDaysOld) => "Daten {days} T alt",
+        (Language::Es, TranslationKey::DataDaysOld) => "Datos de {days} d",
+        (Language::En, TranslationKey::UpdateFailed) => "Weather update failed",
+        (Language::Fr, TranslationKey::UpdateFailed) => "Mise à jour échouée",
+        (Language::De, TranslationKey::UpdateFailed) => "Update fehlgeschlagen",
+        (Language::Es, TranslationKey::UpdateFailed) => "Error al actualizar",
+        (Language::En, TranslationKey::LastGoodData) => "Last good data",
+        (Language::Fr, TranslationKey::LastGoodData) => "Dernières données valides",
+        (Language::De, TranslationKey::LastGoodData) => "Letzte gültige Daten",
+        (Language::Es, TranslationKey::LastGoodData) => "Últimos datos válidos",
+        (Language::En, TranslationKey::NoData) => "none",
+        (Language::Fr, TranslationKey::NoData) => "aucune",
+        (Language::De, TranslationKey::NoData) => "keine",
+        (Language::Es, TranslationKey::NoData) => "ninguno",
+        (Language::En, TranslationKey::MissingFileHint) => {
+            "A file is missing: check template_path and the files next to the program."
+        }
+        (Language::Fr, TranslationKey::MissingFileHint) => {
+            "Fichier manquant : vérifiez template_path et les fichiers du programme."
+        }
+        (Language::De, TranslationKey::MissingFileHint) => {
+            "Datei fehlt: template_path und die Dateien neben dem Programm prüfen."
+        }
+        (Language::Es, TranslationKey::MissingFileHint) => {
+            "Falta un archivo: revise template_path y los archivos junto al programa."
+        }
+        (Language::En, TranslationKey::NetworkHint) => {
+            "Check the network connection and the config. Details: pi-inky-weather-epd.log"
+        }
+        (Language::Fr, TranslationKey::NetworkHint) => {
+            "Vérifiez le réseau et la config. Détails : pi-inky-weather-epd.log"
+        }
+        (Language::De, TranslationKey::NetworkHint) => {
+            "Netzwerk und Konfiguration prüfen. Details: pi-inky-weather-epd.log"
+        }
+        (Language::Es, TranslationKey::NetworkHint) => {
+            "Revise la red y la config. Detalles: pi-inky-weather-epd.log"
+        }
+        // The bundled Japanese font is a subset without 更新 or kana, so these
+        // stay in English until it is regenerated.
+        (Language::Ja, TranslationKey::Updated) => "Updated",
+        (Language::Ja, TranslationKey::DataDaysOld) => "Data {days} d old",
+        (Language::Ja, TranslationKey::UpdateFailed) => "Weather update failed",
+        (Language::Ja, TranslationKey::LastGoodData) => "Last good data",
+        (Language::Ja, TranslationKey::NoData) => "none",
+        (Language::Ja, TranslationKey::MissingFileHint) => {
+            "A file is missing: check template_path and the files next to the program."
+        }
+        (Language::Ja, TranslationKey::NetworkHint) => {
+            "Check the network connection and the config. Details: pi-inky-weather-epd.log"
+        }
     }
 }
 
@@ -0,0 +1,123 @@
+//! Single-instance lock.
+//!
+//! A run holds an advisory lock on `.lock` in `misc.weather_data_cache_path`
+//! from start to finish, so overlapping cron runs don't race on the cache and
+//! output files. A second run waits for the first for up to [`LOCK_WAIT`],
+//! then exits with [`LOCKED_EXIT_CODE`] without touching anything.
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Error;
+
+use crate::logger;
+
+pub const LOCK_FILE_NAME: &str = ".lock";
+/// Exit code of a run that gave up waiting for another one.
+pub const LOCKED_EXIT_CODE: i32 = 4;
+/// How long a run waits for one already in progress.
+pub const LOCK_WAIT: Duration = Duration::from_secs(120);
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Another run still held the lock after [`LOCK_WAIT`].
+#[derive(Debug)]
+pub struct AlreadyRunning {
+    pub lock_path: PathBuf,
+}
+
+impl fmt::Display for AlreadyRunning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Another run is still in progress (holding {}); exiting without changes",
+            self.lock_path.display()
+        )
+    }
+}
+
+impl std::error::Error for AlreadyRunning {}
+
+/// Held for the duration of a run; the lock is released on drop.
+#[derive(Debug)]
+pub struct InstanceLock {
+    _file: File,
+}
+
+impl InstanceLock {
+    /// Locks `cache_dir`, waiting up to [`LOCK_WAIT`] for a run already in
+    /// progress. Fails with [`AlreadyRunning`] if it doesn't finish in time.
+    pub fn acquire(cache_dir: &Path) -> Result<Self, Error> {
+        Self::acquire_within(cache_dir, LOCK_WAIT)
+    }
+
+    fn acquire_within(cache_dir: &Path, wait: Duration) -> Result<Self, Error> {
+        fs::create_dir_all(cache_dir)?;
+        let lock_path = cache_dir.join(LOCK_FILE_NAME);
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|e| Error::msg(format!("Failed to open {}: {e}", lock_path.display())))?;
+
+        let deadline = Instant::now() + wait;
+        let mut waiting = false;
+        loop {
+            match file.try_lock() {
+                Ok(()) => return Ok(Self { _file: file }),
+                Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                    if !waiting {
+                        logger::warning(format!(
+                            "Another run holds {}; waiting up to {}s",
+                            lock_path.display(),
+                            wait.as_secs()
+                        ));
+                        waiting = true;
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                }
+                Err(TryLockError::WouldBlock) => return Err(AlreadyRunning { lock_path }.into()),
+                Err(TryLockError::Error(e)) => {
+                    return Err(Error::msg(format!(
+                        "Failed to lock {}: {e}",
+                        lock_path.display()
+                    )))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_lock_fails_until_the_first_is_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("cache");
+
+        let first = InstanceLock::acquire_within(&cache, Duration::ZERO).unwrap();
+        let error = InstanceLock::acquire_within(&cache, Duration::ZERO).unwrap_err();
+        assert!(error.is::<AlreadyRunning>());
+
+        drop(first);
+        InstanceLock::acquire_within(&cache, Duration::ZERO).unwrap();
+    }
+
+    #[test]
+    fn waits_for_a_run_that_finishes() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = InstanceLock::acquire_within(dir.path(), Duration::ZERO).unwrap();
+        let release = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            drop(first);
+        });
+
+        InstanceLock::acquire_within(dir.path(), Duration::from_secs(10)).unwrap();
+        release.join().unwrap();
+    }
+}
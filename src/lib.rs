@@ -6,8 +6,11 @@ pub mod dashboard;
 pub mod domain;
 pub mod errors;
 pub mod i18n;
+pub mod instance_lock;
 mod logger;
+pub mod output;
 pub mod providers;
+pub mod refresh;
 pub mod update;
 pub mod utils;
 pub mod weather;
@@ -16,21 +19,37 @@ pub mod weather_dashboard;
 use crate::configs::settings::DashboardSettings;
 use crate::weather_dashboard::generate_weather_dashboard;
 use anyhow::Result;
+use instance_lock::{AlreadyRunning, InstanceLock};
+use refresh::RenderOutcome;
 use update::update_app;
 
 // Re-export for testing
+pub use crate::dashboard::renderer::{DashboardRenderer, RenderedDashboard};
+pub use crate::weather_dashboard::generate_weather_dashboard_from_files;
 pub use crate::weather_dashboard::generate_weather_dashboard_injection;
 pub use crate::weather_dashboard::render_svg_to_png;
+pub use crate::weather_dashboard::write_error_screen;
 pub use clock::{Clock, FixedClock, SystemClock};
 
-pub fn run_weather_dashboard(settings: &DashboardSettings) -> Result<(), anyhow::Error> {
+/// Logs why a run failed: another run holding the lock as a warning,
+/// anything else as an error.
+pub fn log_run_failure(error: &anyhow::Error) {
+    if error.is::<AlreadyRunning>() {
+        logger::warning(error);
+    } else {
+        logger::error(format!("{error:#}"));
+    }
+}
+
+pub fn run_weather_dashboard(settings: &DashboardSettings) -> Result<RenderOutcome, anyhow::Error> {
     logger::init(settings.dev.enable_debug_logs, settings.misc.timezone);
     logger::init_file_log();
     logger::app_start("Pi Inky Weather Display", env!("CARGO_PKG_VERSION"));
     settings.print_config();
+    let _lock = InstanceLock::acquire(&settings.misc.weather_data_cache_path)?;
 
     logger::section("Generating weather dashboard");
-    generate_weather_dashboard(settings)?;
+    let outcome = generate_weather_dashboard(settings)?;
 
     if settings.release.update_interval_days.into_inner() > 0 {
         logger::section("Checking for updates");
@@ -38,26 +57,78 @@ pub fn run_weather_dashboard(settings: &DashboardSettings) -> Result<(), anyhow:
     };
 
     logger::app_end();
-    Ok(())
+    Ok(outcome)
 }
 
 /// Run weather dashboard with a custom clock (for simulation/testing)
 pub fn run_weather_dashboard_with_clock(
     settings: &DashboardSettings,
     clock: &dyn Clock,
-) -> Result<(), anyhow::Error> {
+) -> Result<RenderOutcome, anyhow::Error> {
     logger::init(settings.dev.enable_debug_logs, settings.misc.timezone);
     logger::init_file_log();
     logger::app_start("Pi Inky Weather Display", env!("CARGO_PKG_VERSION"));
     settings.print_config();
+    let _lock = InstanceLock::acquire(&settings.misc.weather_data_cache_path)?;
 
     logger::section("Generating weather dashboard (simulation mode)");
     let output_svg_name = &settings.misc.generated_svg_name;
-    generate_weather_dashboard_injection(settings, clock, output_svg_name)?;
+    let outcome = generate_weather_dashboard_injection(settings, clock, output_svg_name)?;
 
     // Skip auto-update in simulation mode
     logger::detail("Skipping auto-update check in simulation mode");
 
     logger::app_end();
-    Ok(())
+    Ok(outcome)
+}
+
+/// Run weather dashboard from forecast files instead of the network
+pub fn run_weather_dashboard_from_files(
+    settings: &DashboardSettings,
+    clock: &dyn Clock,
+    hourly_path: &std::path::Path,
+    daily_path: &std::path::Path,
+) -> Result<RenderOutcome, anyhow::Error> {
+    logger::init(settings.dev.enable_debug_logs, settings.misc.timezone);
+    logger::init_file_log();
+    logger::app_start("Pi Inky Weather Display", env!("CARGO_PKG_VERSION"));
+    settings.print_config();
+    let _lock = InstanceLock::acquire(&settings.misc.weather_data_cache_path)?;
+
+    logger::section("Generating weather dashboard from local files");
+    let outcome = generate_weather_dashboard_from_files(settings, clock, hourly_path, daily_path)?;
+
+    // Skip auto-update when rendering local data
+    logger::detail("Skipping auto-update check for local forecast files");
+
+    logger::app_end();
+    Ok(outcome)
+}
+
+/// Convert an existing SVG to the PNG and extra output targets, holding the
+/// instance lock so a scheduled run can't write the same files meanwhile
+pub fn run_render_svg_to_png(
+    settings: &DashboardSettings,
+    svg_path: &std::path::Path,
+) -> Result<RenderOutcome, anyhow::Error> {
+    logger::init(settings.dev.enable_debug_logs, settings.misc.timezone);
+    logger::init_file_log();
+    let _lock = InstanceLock::acquire(&settings.misc.weather_data_cache_path)?;
+
+    render_svg_to_png(settings, svg_path)?;
+    Ok(RenderOutcome::Written)
+}
+
+/// Show `error` on the panel when the config failed to load, using settings
+/// that were deserialized but not validated
+/// (see [`DashboardSettings::load_unvalidated`])
+pub fn run_error_screen(
+    settings: &DashboardSettings,
+    error: anyhow::Error,
+) -> Result<RenderOutcome, anyhow::Error> {
+    logger::init(settings.dev.enable_debug_logs, settings.misc.timezone);
+    logger::init_file_log();
+    let _lock = InstanceLock::acquire(&settings.misc.weather_data_cache_path)?;
+
+    write_error_screen(settings, &SystemClock, error)
 }
@@ -1,5 +1,8 @@
 use anyhow::Result;
 use pi_inky_weather_epd::configs::settings::DashboardSettings;
+use pi_inky_weather_epd::instance_lock::{AlreadyRunning, LOCKED_EXIT_CODE};
+use pi_inky_weather_epd::refresh::{RenderOutcome, UNCHANGED_EXIT_CODE};
+use pi_inky_weather_epd::{log_run_failure, run_error_screen};
 
 #[cfg(not(feature = "cli"))]
 use pi_inky_weather_epd::run_weather_dashboard;
@@ -9,11 +12,35 @@ fn load_settings() -> DashboardSettings {
         Ok(settings) => settings,
         Err(e) => {
             eprintln!("Failed to load config: {e}");
+            // A config that parses but doesn't validate still says where the
+            // outputs go, so the panel can show why it stopped updating.
+            if let Ok(settings) = DashboardSettings::load_unvalidated() {
+                let error = anyhow::Error::new(e).context("Failed to load config");
+                if exit_for(run_error_screen(&settings, error)).is_ok() {
+                    std::process::exit(0);
+                }
+            }
             std::process::exit(1);
         }
     }
 }
 
+/// Exits with [`UNCHANGED_EXIT_CODE`] when the outputs were left untouched,
+/// or [`LOCKED_EXIT_CODE`] when another run was in progress, so a display
+/// script chained with `&&` skips the panel refresh. An error screen exits
+/// 0 so the panel shows it; any other failure is logged and exits 1.
+fn exit_for(result: Result<RenderOutcome>) -> Result<()> {
+    match result {
+        Ok(RenderOutcome::Unchanged) => std::process::exit(UNCHANGED_EXIT_CODE),
+        Ok(_) => Ok(()),
+        Err(e) => {
+            log_run_failure(&e);
+            let locked = e.is::<AlreadyRunning>();
+            std::process::exit(if locked { LOCKED_EXIT_CODE } else { 1 });
+        }
+    }
+}
+
 // CLI features only available when 'cli' feature is enabled (for simulation/testing)
 #[cfg(feature = "cli")]
 mod cli {
@@ -21,7 +48,8 @@ mod cli {
     use chrono::{DateTime, Utc};
     use clap::{Parser, Subcommand};
     use pi_inky_weather_epd::{
-        clock::FixedClock, render_svg_to_png, run_weather_dashboard,
+        clock::{Clock, FixedClock, SystemClock},
+        run_render_svg_to_png, run_weather_dashboard, run_weather_dashboard_from_files,
         run_weather_dashboard_with_clock,
     };
     use std::path::PathBuf;
@@ -51,6 +79,20 @@ mod cli {
             #[arg(value_name = "SVG_FILE")]
             svg_file: PathBuf,
         },
+        /// Render the dashboard from forecast files instead of fetching them.
+        /// Each file may be a BOM or Open-Meteo API response (as saved in the
+        /// cache) or domain JSON; the format is detected.
+        Render {
+            /// Hourly forecast file
+            #[arg(long, value_name = "FILE")]
+            hourly: PathBuf,
+            /// Daily forecast file
+            #[arg(long, value_name = "FILE")]
+            daily: PathBuf,
+            /// Render as if it's this time (RFC3339), e.g. when the files are old
+            #[arg(long, value_name = "TIMESTAMP", value_parser = parse_rfc3339)]
+            at: Option<DateTime<Utc>>,
+        },
     }
 
     /// Pi Inky Weather Display - Generate weather dashboards for e-paper displays
@@ -66,20 +108,23 @@ mod cli {
         let args = Args::parse();
         let settings = super::load_settings();
         // passing settings to the functions that need it, instead of relying on global state, makes everything purely functional and easier to test.
-        match args.command {
+        let outcome = match args.command {
             Some(Command::Simulate { timestamp }) => {
                 let fixed_clock = FixedClock::new(timestamp);
-                run_weather_dashboard_with_clock(&settings, &fixed_clock)?;
+                run_weather_dashboard_with_clock(&settings, &fixed_clock)
             }
-            Some(Command::RenderSvg { svg_file }) => {
-                render_svg_to_png(&settings, &svg_file)?;
+            Some(Command::RenderSvg { svg_file }) => run_render_svg_to_png(&settings, &svg_file),
+            Some(Command::Render { hourly, daily, at }) => {
+                let clock: Box<dyn Clock> = match at {
+                    Some(timestamp) => Box::new(FixedClock::new(timestamp)),
+                    None => Box::new(SystemClock),
+                };
+                run_weather_dashboard_from_files(&settings, clock.as_ref(), &hourly, &daily)
             }
-            None => {
-                run_weather_dashboard(&settings)?;
-            }
-        }
+            None => run_weather_dashboard(&settings),
+        };
 
-        Ok(())
+        super::exit_for(outcome)
     }
 
     #[cfg(test)]
@@ -120,6 +165,5 @@ fn main() -> Result<()> {
 #[cfg(not(feature = "cli"))]
 fn main() -> Result<()> {
     let settings = load_settings();
-    run_weather_dashboard(&settings)?;
-    Ok(())
+    exit_for(run_weather_dashboard(&settings))
 }
@@ -0,0 +1,213 @@
+//! Encoders turning a rendered, oriented image into the bytes of an output
+//! format.
+
+use anyhow::{Error, Result};
+use resvg::tiny_skia::{Pixmap, PremultipliedColorU8};
+
+use crate::configs::settings::OutputFormat;
+
+/// Encodes an image already at its target size and orientation.
+pub trait Encoder {
+    fn encode(&self, image: &Pixmap) -> Result<Vec<u8>, Error>;
+}
+
+/// The encoder for a file format. `None` for
+/// [`OutputFormat::Framebuffer`], which depends on the device (see
+/// [`super::framebuffer`]).
+pub fn for_format(format: OutputFormat) -> Option<Box<dyn Encoder>> {
+    match format {
+        OutputFormat::Png => Some(Box::new(Png)),
+        OutputFormat::Bmp => Some(Box::new(Bmp)),
+        OutputFormat::Pbm => Some(Box::new(Pbm)),
+        OutputFormat::Pgm => Some(Box::new(Pgm)),
+        OutputFormat::Waveshare1Bit => Some(Box::new(PackedGrey { bits: 1 })),
+        OutputFormat::Waveshare2Bit => Some(Box::new(PackedGrey { bits: 2 })),
+        OutputFormat::Framebuffer => None,
+    }
+}
+
+/// Perceived brightness, 0 (black) to 255 (white). Images are rendered onto
+/// white, so every pixel is opaque and premultiplication can be ignored.
+pub(super) fn luma(pixel: PremultipliedColorU8) -> u8 {
+    let weighted = 299 * u32::from(pixel.red())
+        + 587 * u32::from(pixel.green())
+        + 114 * u32::from(pixel.blue());
+    (weighted / 1000) as u8
+}
+
+/// Rows of `image`, top to bottom.
+fn rows(image: &Pixmap) -> std::slice::Chunks<'_, PremultipliedColorU8> {
+    image.pixels().chunks(image.width() as usize)
+}
+
+pub struct Png;
+
+impl Encoder for Png {
+    fn encode(&self, image: &Pixmap) -> Result<Vec<u8>, Error> {
+        image
+            .encode_png()
+            .map_err(|e| Error::msg(format!("Failed to encode PNG: {e}")))
+    }
+}
+
+/// 24-bit uncompressed BMP, stored bottom-up as the format expects.
+pub struct Bmp;
+
+impl Encoder for Bmp {
+    fn encode(&self, image: &Pixmap) -> Result<Vec<u8>, Error> {
+        const HEADERS_SIZE: u32 = 14 + 40;
+        let row_size = (image.width() * 3).div_ceil(4) * 4;
+        let data_size = row_size * image.height();
+
+        let mut bytes = Vec::with_capacity((HEADERS_SIZE + data_size) as usize);
+        // BITMAPFILEHEADER
+        bytes.extend_from_slice(b"BM");
+        bytes.extend_from_slice(&(HEADERS_SIZE + data_size).to_le_bytes());
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&HEADERS_SIZE.to_le_bytes());
+        // BITMAPINFOHEADER
+        bytes.extend_from_slice(&40u32.to_le_bytes());
+        bytes.extend_from_slice(&(image.width() as i32).to_le_bytes());
+        bytes.extend_from_slice(&(image.height() as i32).to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&24u16.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes()); // BI_RGB
+        bytes.extend_from_slice(&data_size.to_le_bytes());
+        bytes.extend_from_slice(&2835i32.to_le_bytes()); // 72 DPI
+        bytes.extend_from_slice(&2835i32.to_le_bytes());
+        bytes.extend_from_slice(&[0; 8]);
+
+        for row in rows(image).rev() {
+            let start = bytes.len();
+            for pixel in row {
+                bytes.extend_from_slice(&[pixel.blue(), pixel.green(), pixel.red()]);
+            }
+            bytes.resize(start + row_size as usize, 0);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Binary PBM (`P4`): 1 = black, 8 pixels per byte MSB first.
+pub struct Pbm;
+
+impl Encoder for Pbm {
+    fn encode(&self, image: &Pixmap) -> Result<Vec<u8>, Error> {
+        let mut bytes = format!("P4\n{} {}\n", image.width(), image.height()).into_bytes();
+        for row in rows(image) {
+            bytes.extend(pack(row, 1, |pixel| u8::from(luma(pixel) < 128)));
+        }
+        Ok(bytes)
+    }
+}
+
+/// Binary 8-bit greyscale PGM (`P5`).
+pub struct Pgm;
+
+impl Encoder for Pgm {
+    fn encode(&self, image: &Pixmap) -> Result<Vec<u8>, Error> {
+        let mut bytes = format!("P5\n{} {}\n255\n", image.width(), image.height()).into_bytes();
+        bytes.extend(image.pixels().iter().map(|&pixel| luma(pixel)));
+        Ok(bytes)
+    }
+}
+
+/// Waveshare's raw e-paper buffer: `bits` (1 or 2) of grey per pixel, white
+/// all ones, packed MSB first with each row padded to a whole byte.
+pub struct PackedGrey {
+    pub bits: u8,
+}
+
+impl Encoder for PackedGrey {
+    fn encode(&self, image: &Pixmap) -> Result<Vec<u8>, Error> {
+        let shift = 8 - self.bits;
+        Ok(rows(image)
+            .flat_map(|row| pack(row, self.bits, |pixel| luma(pixel) >> shift))
+            .collect())
+    }
+}
+
+/// Packs one row of `bits`-wide pixel values MSB first, padding the last
+/// byte with zero bits.
+fn pack(
+    row: &[PremultipliedColorU8],
+    bits: u8,
+    value: impl Fn(PremultipliedColorU8) -> u8,
+) -> Vec<u8> {
+    let per_byte = (8 / bits) as usize;
+    row.chunks(per_byte)
+        .map(|pixels| {
+            pixels.iter().enumerate().fold(0u8, |byte, (i, &pixel)| {
+                byte | value(pixel) << (8 - bits as usize * (i + 1))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One row: black, dark grey, light grey, white, black.
+    fn greys() -> Pixmap {
+        let mut pixmap = Pixmap::new(5, 1).unwrap();
+        for (pixel, grey) in pixmap.pixels_mut().iter_mut().zip([0, 80, 170, 255, 0]) {
+            *pixel = PremultipliedColorU8::from_rgba(grey, grey, grey, 255).unwrap();
+        }
+        pixmap
+    }
+
+    #[test]
+    fn luma_weights_green_highest() {
+        let red = PremultipliedColorU8::from_rgba(255, 0, 0, 255).unwrap();
+        let green = PremultipliedColorU8::from_rgba(0, 255, 0, 255).unwrap();
+        assert_eq!(luma(red), 76);
+        assert_eq!(luma(green), 149);
+    }
+
+    #[test]
+    fn waveshare_1bit_is_white_ones_padded_per_row() {
+        let bytes = PackedGrey { bits: 1 }.encode(&greys()).unwrap();
+        assert_eq!(bytes, [0b0011_0000]);
+    }
+
+    #[test]
+    fn waveshare_2bit_packs_four_greys_per_byte() {
+        let bytes = PackedGrey { bits: 2 }.encode(&greys()).unwrap();
+        assert_eq!(bytes, [0b00_01_10_11, 0b00_00_00_00]);
+    }
+
+    #[test]
+    fn pbm_marks_black_pixels() {
+        let bytes = Pbm.encode(&greys()).unwrap();
+        assert_eq!(bytes, b"P4\n5 1\n\xC8");
+    }
+
+    #[test]
+    fn pgm_keeps_the_grey_levels() {
+        let bytes = Pgm.encode(&greys()).unwrap();
+        assert_eq!(bytes, b"P5\n5 1\n255\n\x00\x50\xAA\xFF\x00");
+    }
+
+    #[test]
+    fn bmp_rows_are_bottom_up_and_padded() {
+        let mut image = Pixmap::new(1, 2).unwrap();
+        image.pixels_mut()[0] = PremultipliedColorU8::from_rgba(255, 0, 0, 255).unwrap();
+        image.pixels_mut()[1] = PremultipliedColorU8::from_rgba(0, 0, 255, 255).unwrap();
+
+        let bytes = Bmp.encode(&image).unwrap();
+
+        assert_eq!(&bytes[..2], b"BM");
+        assert_eq!(bytes.len(), 54 + 2 * 4);
+        assert_eq!(u32::from_le_bytes(bytes[2..6].try_into().unwrap()), 62);
+        // Bottom (blue) row first, as BGR plus one byte of padding.
+        assert_eq!(&bytes[54..], [255, 0, 0, 0, 0, 0, 255, 0]);
+    }
+
+    #[test]
+    fn png_round_trips() {
+        let bytes = Png.encode(&greys()).unwrap();
+        let decoded = Pixmap::decode_png(&bytes).unwrap();
+        assert_eq!(decoded.pixels(), greys().pixels());
+    }
+}
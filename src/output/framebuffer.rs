@@ -0,0 +1,176 @@
+//! Linux framebuffer (`/dev/fb*`) output.
+//!
+//! The device's size and pixel layout are read from
+//! `/sys/class/graphics/<device>/`. Without them the image is written as
+//! 32-bit BGRX rows with no padding, the common layout on a Raspberry Pi.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Error, Result};
+use resvg::tiny_skia::Pixmap;
+
+use super::encoders::Encoder;
+
+const SYSFS_GRAPHICS: &str = "/sys/class/graphics";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FramebufferInfo {
+    pub width: u32,
+    pub height: u32,
+    pub bits_per_pixel: u32,
+    /// Bytes per row, including any padding.
+    pub stride: u32,
+}
+
+impl FramebufferInfo {
+    /// Reads the layout of `device` (e.g. `/dev/fb0`) from sysfs.
+    pub fn for_device(device: &Path) -> Option<Self> {
+        let name = device.file_name()?;
+        Self::from_sysfs(&PathBuf::from(SYSFS_GRAPHICS).join(name))
+    }
+
+    fn from_sysfs(dir: &Path) -> Option<Self> {
+        let read = |file: &str| fs::read_to_string(dir.join(file)).ok();
+        let (width, height) = read("virtual_size")?
+            .trim()
+            .split_once(',')
+            .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))?;
+        let bits_per_pixel = read("bits_per_pixel")?.trim().parse().ok()?;
+        let stride = read("stride")
+            .and_then(|stride| stride.trim().parse().ok())
+            .unwrap_or(width * bits_per_pixel / 8);
+        Some(Self {
+            width,
+            height,
+            bits_per_pixel,
+            stride,
+        })
+    }
+}
+
+/// Encodes in the device's pixel format: 32-bit BGRX, 24-bit BGR or 16-bit
+/// RGB565, little-endian, each row padded to the stride.
+pub struct FramebufferEncoder {
+    bits_per_pixel: u32,
+    stride: Option<u32>,
+}
+
+impl FramebufferEncoder {
+    pub fn new(info: Option<&FramebufferInfo>) -> Self {
+        Self {
+            bits_per_pixel: info.map_or(32, |info| info.bits_per_pixel),
+            stride: info.map(|info| info.stride),
+        }
+    }
+}
+
+impl Encoder for FramebufferEncoder {
+    fn encode(&self, image: &Pixmap) -> Result<Vec<u8>, Error> {
+        let bytes_per_pixel = match self.bits_per_pixel {
+            16 | 24 | 32 => self.bits_per_pixel / 8,
+            bits => {
+                return Err(Error::msg(format!(
+                    "Unsupported framebuffer depth: {bits} bits per pixel"
+                )))
+            }
+        };
+        let row_size = image.width() * bytes_per_pixel;
+        let stride = self.stride.unwrap_or(row_size).max(row_size) as usize;
+
+        let mut bytes = Vec::with_capacity(stride * image.height() as usize);
+        for row in image.pixels().chunks(image.width() as usize) {
+            let start = bytes.len();
+            for pixel in row {
+                let (r, g, b) = (pixel.red(), pixel.green(), pixel.blue());
+                match bytes_per_pixel {
+                    4 => bytes.extend_from_slice(&[b, g, r, 0xFF]),
+                    3 => bytes.extend_from_slice(&[b, g, r]),
+                    _ => {
+                        let rgb565 = (u16::from(r) >> 3) << 11
+                            | (u16::from(g) >> 2) << 5
+                            | u16::from(b) >> 3;
+                        bytes.extend_from_slice(&rgb565.to_le_bytes());
+                    }
+                }
+            }
+            bytes.resize(start + stride, 0);
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use resvg::tiny_skia::PremultipliedColorU8;
+
+    fn orange() -> Pixmap {
+        let mut pixmap = Pixmap::new(1, 1).unwrap();
+        pixmap.pixels_mut()[0] = PremultipliedColorU8::from_rgba(255, 128, 0, 255).unwrap();
+        pixmap
+    }
+
+    fn info(bits_per_pixel: u32, stride: u32) -> FramebufferInfo {
+        FramebufferInfo {
+            width: 1,
+            height: 1,
+            bits_per_pixel,
+            stride,
+        }
+    }
+
+    #[test]
+    fn defaults_to_32_bit_bgrx() {
+        let bytes = FramebufferEncoder::new(None).encode(&orange()).unwrap();
+        assert_eq!(bytes, [0, 128, 255, 0xFF]);
+    }
+
+    #[test]
+    fn encodes_rgb565_little_endian() {
+        let bytes = FramebufferEncoder::new(Some(&info(16, 2)))
+            .encode(&orange())
+            .unwrap();
+        // Red 31 of 31, green 32 of 63, blue 0.
+        assert_eq!(bytes, ((31u16 << 11) | (32 << 5)).to_le_bytes());
+    }
+
+    #[test]
+    fn pads_rows_to_the_stride() {
+        let bytes = FramebufferEncoder::new(Some(&info(24, 8)))
+            .encode(&orange())
+            .unwrap();
+        assert_eq!(bytes, [0, 128, 255, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn rejects_unsupported_depths() {
+        assert!(FramebufferEncoder::new(Some(&info(8, 1)))
+            .encode(&orange())
+            .is_err());
+    }
+
+    #[test]
+    fn reads_the_layout_from_sysfs() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("virtual_size"), "1920,1080\n").unwrap();
+        fs::write(dir.path().join("bits_per_pixel"), "16\n").unwrap();
+        fs::write(dir.path().join("stride"), "3840\n").unwrap();
+
+        assert_eq!(
+            FramebufferInfo::from_sysfs(dir.path()),
+            Some(FramebufferInfo {
+                width: 1920,
+                height: 1080,
+                bits_per_pixel: 16,
+                stride: 3840,
+            })
+        );
+    }
+
+    #[test]
+    fn missing_sysfs_entries_give_no_layout() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(FramebufferInfo::from_sysfs(dir.path()), None);
+    }
+}
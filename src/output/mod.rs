@@ -0,0 +1,329 @@
+//! Extra raster outputs (`[output] targets`).
+//!
+//! Each target renders the dashboard SVG at its own resolution, rotates and
+//! mirrors it, then hands it to the [`Encoder`](encoders::Encoder) for its
+//! format. The PNG at `misc.generated_png_name` is written separately and is
+//! unaffected.
+
+pub mod encoders;
+pub mod framebuffer;
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Error, Result};
+use resvg::{tiny_skia, usvg};
+
+use crate::configs::settings::{Mirror, OutputFormat, OutputTarget, Rotation};
+use crate::logger;
+use crate::utils::{font_db_for, write_atomic};
+use encoders::Encoder;
+use framebuffer::{FramebufferEncoder, FramebufferInfo};
+
+/// Writes every target from the SVG at `svg_path`, stopping at the first
+/// one that fails.
+pub fn write_outputs(svg_path: &Path, targets: &[OutputTarget]) -> Result<(), Error> {
+    if targets.is_empty() {
+        return Ok(());
+    }
+    let svg_data = fs::read_to_string(svg_path)
+        .map_err(|e| Error::msg(format!("Failed to read SVG file: {e}")))?;
+    let opts = usvg::Options {
+        fontdb: font_db_for(&svg_data),
+        ..Default::default()
+    };
+    let tree = usvg::Tree::from_str(&svg_data, &opts)
+        .map_err(|e| Error::msg(format!("Failed to parse SVG: {e}")))?;
+
+    for target in targets {
+        write_output(&tree, target)
+            .map_err(|e| Error::msg(format!("Failed to write output {target}: {e}")))?;
+        logger::success(format!("Output saved: {target}"));
+    }
+    Ok(())
+}
+
+fn write_output(tree: &usvg::Tree, target: &OutputTarget) -> Result<(), Error> {
+    if target.format == OutputFormat::Framebuffer {
+        let info = FramebufferInfo::for_device(&target.path);
+        let size = target
+            .width
+            .zip(target.height)
+            .or(info.as_ref().map(|info| (info.width, info.height)));
+        let image = render(tree, size, target.rotation, target.mirror)?;
+        let bytes = FramebufferEncoder::new(info.as_ref()).encode(&image)?;
+        // A device can't be created or truncated, so open it as it is.
+        let mut device = fs::OpenOptions::new().write(true).open(&target.path)?;
+        device.write_all(&bytes)?;
+        return Ok(());
+    }
+
+    let image = render(
+        tree,
+        target.width.zip(target.height),
+        target.rotation,
+        target.mirror,
+    )?;
+    let encoder = encoders::for_format(target.format)
+        .ok_or_else(|| Error::msg(format!("No encoder for {}", target.format)))?;
+    if let Some(parent) = target.path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_atomic(&target.path, encoder.encode(&image)?)?;
+    Ok(())
+}
+
+/// Renders `tree` onto white at `size` (after rotation; the tree's own size
+/// when `None`), scaled to fit and centred, then rotated and mirrored.
+pub fn render(
+    tree: &usvg::Tree,
+    size: Option<(u32, u32)>,
+    rotation: Rotation,
+    mirror: Mirror,
+) -> Result<tiny_skia::Pixmap, Error> {
+    let tree_size = tree.size();
+    let (width, height) = match size {
+        Some((width, height)) if rotation.is_quarter_turn() => (height, width),
+        Some(size) => size,
+        None => {
+            let size = tree_size.to_int_size();
+            (size.width(), size.height())
+        }
+    };
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)
+        .ok_or_else(|| Error::msg("Failed to create pixmap"))?;
+    pixmap.fill(tiny_skia::Color::WHITE);
+
+    let scale = (width as f32 / tree_size.width()).min(height as f32 / tree_size.height());
+    let transform = tiny_skia::Transform::from_translate(
+        (width as f32 - tree_size.width() * scale) / 2.0,
+        (height as f32 - tree_size.height() * scale) / 2.0,
+    )
+    .pre_scale(scale, scale);
+    resvg::render(tree, transform, &mut pixmap.as_mut());
+
+    orient(&pixmap, rotation, mirror)
+}
+
+/// `pixmap` rotated clockwise by `rotation`, then mirrored.
+pub fn orient(
+    pixmap: &tiny_skia::Pixmap,
+    rotation: Rotation,
+    mirror: Mirror,
+) -> Result<tiny_skia::Pixmap, Error> {
+    if rotation == Rotation::None && mirror == Mirror::None {
+        return Ok(pixmap.clone());
+    }
+    let (src_width, src_height) = (pixmap.width(), pixmap.height());
+    let (width, height) = if rotation.is_quarter_turn() {
+        (src_height, src_width)
+    } else {
+        (src_width, src_height)
+    };
+    let mut oriented = tiny_skia::Pixmap::new(width, height)
+        .ok_or_else(|| Error::msg("Failed to create pixmap"))?;
+
+    let source = pixmap.pixels();
+    let pixels = oriented.pixels_mut();
+    for y in 0..height {
+        for x in 0..width {
+            // Undo the mirror, then the rotation, to find the source pixel.
+            let (rx, ry) = match mirror {
+                Mirror::None => (x, y),
+                Mirror::Horizontal => (width - 1 - x, y),
+                Mirror::Vertical => (x, height - 1 - y),
+            };
+            let (src_x, src_y) = match rotation {
+                Rotation::None => (rx, ry),
+                Rotation::Clockwise90 => (ry, src_height - 1 - rx),
+                Rotation::Clockwise180 => (src_width - 1 - rx, src_height - 1 - ry),
+                Rotation::Clockwise270 => (src_width - 1 - ry, rx),
+            };
+            pixels[(y * width + x) as usize] = source[(src_y * src_width + src_x) as usize];
+        }
+    }
+    Ok(oriented)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 3x2 image whose pixels are numbered 0..6 in the red channel, row
+    /// by row.
+    fn numbered() -> tiny_skia::Pixmap {
+        let mut pixmap = tiny_skia::Pixmap::new(3, 2).unwrap();
+        for (i, pixel) in pixmap.pixels_mut().iter_mut().enumerate() {
+            *pixel = tiny_skia::PremultipliedColorU8::from_rgba(i as u8, 0, 0, 255).unwrap();
+        }
+        pixmap
+    }
+
+    fn rows(pixmap: &tiny_skia::Pixmap) -> Vec<Vec<u8>> {
+        pixmap
+            .pixels()
+            .chunks(pixmap.width() as usize)
+            .map(|row| row.iter().map(|p| p.red()).collect())
+            .collect()
+    }
+
+    mod orient {
+        use super::*;
+
+        #[test]
+        fn unrotated_is_unchanged() {
+            let image = orient(&numbered(), Rotation::None, Mirror::None).unwrap();
+            assert_eq!(rows(&image), [[0, 1, 2], [3, 4, 5]]);
+        }
+
+        #[test]
+        fn quarter_turn_swaps_width_and_height() {
+            let image = orient(&numbered(), Rotation::Clockwise90, Mirror::None).unwrap();
+            assert_eq!(rows(&image), [[3, 0], [4, 1], [5, 2]]);
+        }
+
+        #[test]
+        fn half_turn_reverses_every_pixel() {
+            let image = orient(&numbered(), Rotation::Clockwise180, Mirror::None).unwrap();
+            assert_eq!(rows(&image), [[5, 4, 3], [2, 1, 0]]);
+        }
+
+        #[test]
+        fn three_quarter_turn() {
+            let image = orient(&numbered(), Rotation::Clockwise270, Mirror::None).unwrap();
+            assert_eq!(rows(&image), [[2, 5], [1, 4], [0, 3]]);
+        }
+
+        #[test]
+        fn mirrors_horizontally() {
+            let image = orient(&numbered(), Rotation::None, Mirror::Horizontal).unwrap();
+            assert_eq!(rows(&image), [[2, 1, 0], [5, 4, 3]]);
+        }
+
+        #[test]
+        fn mirrors_vertically_after_rotating() {
+            let image = orient(&numbered(), Rotation::Clockwise90, Mirror::Vertical).unwrap();
+            assert_eq!(rows(&image), [[5, 2], [4, 1], [3, 0]]);
+        }
+    }
+
+    mod render {
+        use super::*;
+
+        fn tree(svg: &str) -> usvg::Tree {
+            usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap()
+        }
+
+        const BLACK_LEFT_HALF: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="2"><rect width="2" height="2"/></svg>"#;
+
+        #[test]
+        fn defaults_to_the_svg_size() {
+            let image = render(&tree(BLACK_LEFT_HALF), None, Rotation::None, Mirror::None).unwrap();
+            assert_eq!((image.width(), image.height()), (4, 2));
+        }
+
+        #[test]
+        fn scales_to_the_target_size() {
+            let image = render(
+                &tree(BLACK_LEFT_HALF),
+                Some((8, 4)),
+                Rotation::None,
+                Mirror::None,
+            )
+            .unwrap();
+            assert_eq!((image.width(), image.height()), (8, 4));
+            assert_eq!(image.pixel(3, 3).unwrap().red(), 0);
+            assert_eq!(image.pixel(4, 0).unwrap().red(), 255);
+        }
+
+        #[test]
+        fn target_size_is_after_rotation() {
+            let image = render(
+                &tree(BLACK_LEFT_HALF),
+                Some((2, 4)),
+                Rotation::Clockwise90,
+                Mirror::None,
+            )
+            .unwrap();
+            assert_eq!((image.width(), image.height()), (2, 4));
+            // The black left half ends up on top.
+            assert_eq!(image.pixel(0, 0).unwrap().red(), 0);
+            assert_eq!(image.pixel(0, 3).unwrap().red(), 255);
+        }
+
+        #[test]
+        fn letterboxes_a_different_aspect_ratio_in_white() {
+            let image = render(
+                &tree(BLACK_LEFT_HALF),
+                Some((4, 4)),
+                Rotation::None,
+                Mirror::None,
+            )
+            .unwrap();
+            assert_eq!(image.pixel(0, 0).unwrap().red(), 255);
+            assert_eq!(image.pixel(0, 2).unwrap().red(), 0);
+            assert_eq!(image.pixel(0, 3).unwrap().red(), 255);
+        }
+    }
+
+    mod write_outputs {
+        use super::*;
+        use std::path::PathBuf;
+
+        fn target(format: OutputFormat, path: PathBuf) -> OutputTarget {
+            OutputTarget {
+                format,
+                path,
+                width: None,
+                height: None,
+                rotation: Rotation::None,
+                mirror: Mirror::None,
+            }
+        }
+
+        #[test]
+        fn writes_each_target() {
+            let dir = tempfile::tempdir().unwrap();
+            let svg = dir.path().join("dashboard.svg");
+            fs::write(
+                &svg,
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="8"/>"#,
+            )
+            .unwrap();
+            let pbm = dir.path().join("out/dashboard.pbm");
+            let raw = dir.path().join("dashboard.bin");
+
+            write_outputs(
+                &svg,
+                &[
+                    target(OutputFormat::Pbm, pbm.clone()),
+                    target(OutputFormat::Waveshare1Bit, raw.clone()),
+                ],
+            )
+            .unwrap();
+
+            assert!(fs::read(pbm).unwrap().starts_with(b"P4\n16 8\n"));
+            assert_eq!(fs::read(raw).unwrap(), vec![0xFF; 16]);
+        }
+
+        #[test]
+        fn framebuffer_device_must_exist() {
+            let dir = tempfile::tempdir().unwrap();
+            let svg = dir.path().join("dashboard.svg");
+            fs::write(
+                &svg,
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="2" height="2"/>"#,
+            )
+            .unwrap();
+
+            let device = dir.path().join("fb9");
+            let result = write_outputs(&svg, &[target(OutputFormat::Framebuffer, device.clone())]);
+            assert!(result.is_err());
+
+            fs::write(&device, []).unwrap();
+            write_outputs(&svg, &[target(OutputFormat::Framebuffer, device.clone())]).unwrap();
+            assert_eq!(fs::read(device).unwrap(), vec![0xFF; 16]);
+        }
+    }
+}
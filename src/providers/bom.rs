@@ -3,16 +3,14 @@ use std::path::PathBuf;
 
 use crate::{
     apis::bom::models::{BomError, DailyForecastResponse, HourlyForecastResponse},
+    clock::Clock,
     configs::settings::DashboardSettings,
     constants::{
         daily_forecast_endpoint, hourly_forecast_endpoint, DAILY_CACHE_SUFFIX, HOURLY_CACHE_SUFFIX,
     },
     domain::models::{DailyForecast, HourlyForecast},
     errors::DashboardError,
-    providers::{
-        fetcher::{FetchOutcome, Fetcher},
-        FetchResult, WeatherProvider,
-    },
+    providers::{fetcher::Fetcher, FetchResult, WeatherProvider},
 };
 
 fn check_bom_error(body: &str) -> Result<(), DashboardError> {
@@ -67,69 +65,61 @@ impl WeatherProvider for BomProvider {
     fn fetch_hourly_forecast(
         &self,
         settings: &DashboardSettings,
+        clock: &dyn Clock,
     ) -> Result<FetchResult<Vec<HourlyForecast>>, Error> {
-        match self.fetcher.fetch_data::<HourlyForecastResponse>(
-            settings,
-            hourly_forecast_endpoint(settings),
-            &self.generate_cache_filename(HOURLY_CACHE_SUFFIX),
-            Some(check_bom_error),
-        )? {
-            FetchOutcome::Fresh(data) => {
-                // Convert BOM models to domain models
-                let domain_data: Vec<HourlyForecast> = data
-                    .data
-                    .into_iter()
-                    .map(|h| HourlyForecast::from_bom(h, settings))
-                    .collect();
-                crate::logger::debug(format!(
-                    "Converted {} BOM hourly entries to domain model",
-                    domain_data.len()
-                ));
-                Ok(FetchResult::fresh(domain_data))
-            }
-            FetchOutcome::Stale { data, error } => {
-                let domain_data: Vec<HourlyForecast> = data
-                    .data
-                    .into_iter()
-                    .map(|h| HourlyForecast::from_bom(h, settings))
-                    .collect();
-                Ok(FetchResult::stale(domain_data, error))
-            }
-        }
+        let result = self
+            .fetcher
+            .fetch_data::<HourlyForecastResponse>(
+                settings,
+                hourly_forecast_endpoint(settings),
+                &self.generate_cache_filename(HOURLY_CACHE_SUFFIX),
+                Some(check_bom_error),
+            )?
+            .into_result(clock.now_utc());
+
+        // Convert BOM models to domain models
+        Ok(result.map(|data| {
+            let domain_data: Vec<HourlyForecast> = data
+                .data
+                .into_iter()
+                .map(|h| HourlyForecast::from_bom(h, settings))
+                .collect();
+            crate::logger::debug(format!(
+                "Converted {} BOM hourly entries to domain model",
+                domain_data.len()
+            ));
+            domain_data
+        }))
     }
 
     fn fetch_daily_forecast(
         &self,
         settings: &DashboardSettings,
+        clock: &dyn Clock,
     ) -> Result<FetchResult<Vec<DailyForecast>>, Error> {
-        match self.fetcher.fetch_data::<DailyForecastResponse>(
-            settings,
-            daily_forecast_endpoint(settings),
-            &self.generate_cache_filename(DAILY_CACHE_SUFFIX),
-            Some(check_bom_error),
-        )? {
-            FetchOutcome::Fresh(data) => {
-                // Convert BOM models to domain models
-                let domain_data: Vec<DailyForecast> = data
-                    .data
-                    .into_iter()
-                    .map(|d| DailyForecast::from_bom(d, settings))
-                    .collect();
-                crate::logger::debug(format!(
-                    "Converted {} BOM daily entries to domain model",
-                    domain_data.len()
-                ));
-                Ok(FetchResult::fresh(domain_data))
-            }
-            FetchOutcome::Stale { data, error } => {
-                let domain_data: Vec<DailyForecast> = data
-                    .data
-                    .into_iter()
-                    .map(|d| DailyForecast::from_bom(d, settings))
-                    .collect();
-                Ok(FetchResult::stale(domain_data, error))
-            }
-        }
+        let result = self
+            .fetcher
+            .fetch_data::<DailyForecastResponse>(
+                settings,
+                daily_forecast_endpoint(settings),
+                &self.generate_cache_filename(DAILY_CACHE_SUFFIX),
+                Some(check_bom_error),
+            )?
+            .into_result(clock.now_utc());
+
+        // Convert BOM models to domain models
+        Ok(result.map(|data| {
+            let domain_data: Vec<DailyForecast> = data
+                .data
+                .into_iter()
+                .map(|d| DailyForecast::from_bom(d, settings))
+                .collect();
+            crate::logger::debug(format!(
+                "Converted {} BOM daily entries to domain model",
+                domain_data.len()
+            ));
+            domain_data
+        }))
     }
 
     fn provider_name(&self) -> &str {
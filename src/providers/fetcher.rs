@@ -1,9 +1,12 @@
 use anyhow::Error;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use std::{fmt, fs, path::PathBuf, time::Duration};
 use url::Url;
 
 use crate::configs::settings::DashboardSettings;
+use crate::providers::FetchResult;
+use crate::utils::write_atomic;
 use crate::{errors::DashboardError, logger};
 
 /// Type alias for API-specific error checking function
@@ -63,8 +66,30 @@ impl<'a> RetryConfig<'a> {
 pub enum FetchOutcome<T> {
     /// Fresh data successfully fetched from API
     Fresh(T),
-    /// Stale cached data used due to error
-    Stale { data: T, error: DashboardError },
+    /// Stale cached data used due to error, last written at `updated_at`
+    Stale {
+        data: T,
+        error: DashboardError,
+        updated_at: DateTime<Utc>,
+    },
+    /// Cached data read without fetching (`dev.disable_weather_api_requests`),
+    /// last written at `updated_at`
+    Cached { data: T, updated_at: DateTime<Utc> },
+}
+
+impl<T> FetchOutcome<T> {
+    /// The outcome as a [`FetchResult`], with fresh data stamped `now`.
+    pub fn into_result(self, now: DateTime<Utc>) -> FetchResult<T> {
+        match self {
+            FetchOutcome::Fresh(data) => FetchResult::fresh(data, now),
+            FetchOutcome::Stale {
+                data,
+                error,
+                updated_at,
+            } => FetchResult::stale(data, error, updated_at),
+            FetchOutcome::Cached { data, updated_at } => FetchResult::fresh(data, updated_at),
+        }
+    }
 }
 
 /// Shared fetcher for API data with caching fallback
@@ -154,9 +179,23 @@ impl Fetcher {
         Ok(FetchOutcome::Stale {
             data,
             error: dashboard_error,
+            updated_at: Self::cache_modified(file_path)?,
         })
     }
 
+    /// When the cache file was last written
+    fn cache_modified(file_path: &PathBuf) -> Result<DateTime<Utc>, Error> {
+        let modified = fs::metadata(file_path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| {
+                Error::msg(format!(
+                    "Failed to read the modification time of {}: {e}",
+                    file_path.display()
+                ))
+            })?;
+        Ok(modified.into())
+    }
+
     /// Check if an error is retryable (transient network issues, rate limits, server errors)
     fn is_error_retryable(error: &reqwest::Error) -> bool {
         // Network connectivity issues are retryable
@@ -238,7 +277,7 @@ impl Fetcher {
             }
         }
 
-        fs::write(file_path, &body)?;
+        write_atomic(file_path, &body)?;
         logger::debug(format!("Cached response to: {}", file_path.display()));
         let data = serde_json::from_str(&body).map_err(Error::msg)?;
         Ok(FetchOutcome::Fresh(data))
@@ -508,19 +547,22 @@ impl Fetcher {
 
         match settings.dev.disable_weather_api_requests {
             true => {
-                let cached = self.load_cached(&file_path)?;
-                Ok(FetchOutcome::Fresh(cached))
+                let data = self.load_cached(&file_path)?;
+                Ok(FetchOutcome::Cached {
+                    data,
+                    updated_at: Self::cache_modified(&file_path)?,
+                })
             }
             false => self.try_fetch_with_retry(&endpoint, &file_path, error_checker, &config),
         }
     }
 }
 
-/// Tests for error classification, Retry-After parsing, and retryability
-/// decisions. `wiremock` is a dev-dependency, so tests that need a real
-/// `reqwest::Error` (which has no public constructor) get one from an actual
-/// failed request against a local mock server, same as the wiring tests in
-/// `tests/fetcher_test.rs`.
+/// Tests for error classification, Retry-After parsing, retryability
+/// decisions, and reading the cache with API requests disabled. `wiremock` is
+/// a dev-dependency, so tests that need a real `reqwest::Error` (which has no
+/// public constructor) get one from an actual failed request against a local
+/// mock server, same as the wiring tests in `tests/fetcher_test.rs`.
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -836,4 +878,37 @@ mod tests {
             .unwrap();
         }
     }
+
+    mod disabled_requests {
+        use super::*;
+
+        #[test]
+        fn cache_is_dated_by_its_modification_time() {
+            let cached_at = std::time::UNIX_EPOCH + Duration::from_secs(1_761_386_400);
+            let temp_dir = tempfile::tempdir().unwrap();
+            let cache_file = temp_dir.path().join("test_data.json");
+            fs::write(&cache_file, r#"{"value": "cached"}"#).unwrap();
+            fs::File::options()
+                .write(true)
+                .open(&cache_file)
+                .and_then(|file| file.set_modified(cached_at))
+                .unwrap();
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.dev.disable_weather_api_requests = true;
+
+            let outcome = Fetcher::new(temp_dir.path().to_path_buf())
+                .fetch_data::<serde_json::Value>(
+                    &settings,
+                    Url::parse("http://127.0.0.1:9/unused").unwrap(),
+                    "test_data.json",
+                    None,
+                )
+                .unwrap();
+            let result = outcome.into_result(Utc::now());
+
+            assert_eq!(result.data["value"], "cached");
+            assert!(result.warning.is_none());
+            assert_eq!(std::time::SystemTime::from(result.updated_at), cached_at);
+        }
+    }
 }
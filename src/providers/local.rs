@@ -0,0 +1,320 @@
+//! Forecasts read from local files instead of the network.
+//!
+//! Each file may hold a BOM or Open-Meteo API response, exactly as cached in
+//! `misc.weather_data_cache_path`, or a JSON array of the domain models
+//! ([`HourlyForecast`], [`DailyForecast`]) for data from other systems. The
+//! format is detected from the file's shape.
+
+use anyhow::{Context, Error};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use strum_macros::Display;
+
+use crate::{
+    apis::{bom, open_meteo::models as open_meteo},
+    clock::Clock,
+    configs::settings::DashboardSettings,
+    domain::models::{DailyForecast, HourlyForecast},
+    logger,
+    providers::{FetchResult, WeatherProvider},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Display)]
+pub enum ForecastFileFormat {
+    #[strum(to_string = "BOM")]
+    Bom,
+    #[strum(to_string = "Open-Meteo")]
+    OpenMeteo,
+    #[strum(to_string = "domain JSON")]
+    Domain,
+}
+
+impl ForecastFileFormat {
+    /// Detects the format from the top level of the file: an array is domain
+    /// JSON, a `data` member marks a BOM response and a `section` member
+    /// (`hourly` or `daily`) an Open-Meteo one.
+    pub fn detect(json: &Value, section: &str) -> Option<Self> {
+        match json {
+            Value::Array(_) => Some(Self::Domain),
+            Value::Object(members) if members.contains_key("data") => Some(Self::Bom),
+            Value::Object(members) if members.contains_key(section) => Some(Self::OpenMeteo),
+            _ => None,
+        }
+    }
+}
+
+pub struct LocalFileProvider {
+    hourly_path: PathBuf,
+    daily_path: PathBuf,
+}
+
+impl LocalFileProvider {
+    pub fn new(hourly_path: PathBuf, daily_path: PathBuf) -> Self {
+        Self {
+            hourly_path,
+            daily_path,
+        }
+    }
+}
+
+/// Reads `path` and detects its format.
+fn read_forecast_file(path: &Path, section: &str) -> Result<(Value, ForecastFileFormat), Error> {
+    let body =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let json: Value = serde_json::from_str(&body)
+        .map_err(|e| Error::msg(format!("{} is not valid JSON: {e}", path.display())))?;
+    let format = ForecastFileFormat::detect(&json, section).ok_or_else(|| {
+        Error::msg(format!(
+            "{} is not a BOM or Open-Meteo {section} forecast, or a domain JSON array",
+            path.display()
+        ))
+    })?;
+    logger::detail(format!(
+        "Reading {format} {section} forecast from {}",
+        path.display()
+    ));
+    Ok((json, format))
+}
+
+fn parse<T: DeserializeOwned>(
+    json: Value,
+    path: &Path,
+    format: ForecastFileFormat,
+) -> Result<T, Error> {
+    serde_json::from_value(json).map_err(|e| {
+        Error::msg(format!(
+            "Failed to parse {} ({format}): {e}",
+            path.display()
+        ))
+    })
+}
+
+impl WeatherProvider for LocalFileProvider {
+    fn fetch_hourly_forecast(
+        &self,
+        settings: &DashboardSettings,
+        clock: &dyn Clock,
+    ) -> Result<FetchResult<Vec<HourlyForecast>>, Error> {
+        let path = self.hourly_path.as_path();
+        let (json, format) = read_forecast_file(path, "hourly")?;
+        let forecasts = match format {
+            ForecastFileFormat::Bom => {
+                parse::<bom::models::HourlyForecastResponse>(json, path, format)?
+                    .data
+                    .into_iter()
+                    .map(|h| HourlyForecast::from_bom(h, settings))
+                    .collect()
+            }
+            ForecastFileFormat::OpenMeteo => {
+                parse::<open_meteo::OpenMeteoHourlyResponse>(json, path, format)?
+                    .into_domain(settings)
+            }
+            ForecastFileFormat::Domain => {
+                let unit = settings.render_options.temp_unit;
+                parse::<Vec<HourlyForecast>>(json, path, format)?
+                    .into_iter()
+                    .map(|mut hour| {
+                        hour.temperature = hour.temperature.to_unit(unit);
+                        hour.apparent_temperature = hour.apparent_temperature.to_unit(unit);
+                        hour
+                    })
+                    .collect()
+            }
+        };
+        Ok(FetchResult::fresh(forecasts, clock.now_utc()))
+    }
+
+    fn fetch_daily_forecast(
+        &self,
+        settings: &DashboardSettings,
+        clock: &dyn Clock,
+    ) -> Result<FetchResult<Vec<DailyForecast>>, Error> {
+        let path = self.daily_path.as_path();
+        let (json, format) = read_forecast_file(path, "daily")?;
+        let forecasts = match format {
+            ForecastFileFormat::Bom => {
+                parse::<bom::models::DailyForecastResponse>(json, path, format)?
+                    .data
+                    .into_iter()
+                    .map(|d| DailyForecast::from_bom(d, settings))
+                    .collect()
+            }
+            ForecastFileFormat::OpenMeteo => {
+                parse::<open_meteo::OpenMeteoDailyResponse>(json, path, format)?
+                    .into_domain(settings)
+            }
+            ForecastFileFormat::Domain => {
+                let unit = settings.render_options.temp_unit;
+                parse::<Vec<DailyForecast>>(json, path, format)?
+                    .into_iter()
+                    .map(|mut day| {
+                        day.temp_max = day.temp_max.map(|t| t.to_unit(unit));
+                        day.temp_min = day.temp_min.map(|t| t.to_unit(unit));
+                        day
+                    })
+                    .collect()
+            }
+        };
+        Ok(FetchResult::fresh(forecasts, clock.now_utc()))
+    }
+
+    fn provider_name(&self) -> &str {
+        "Local files"
+    }
+    fn provider_filename_prefix(&self) -> &str {
+        "local_"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::configs::settings::TemperatureUnit;
+    use crate::domain::weather_code::WmoWeatherCode;
+    use serde_json::json;
+
+    fn settings() -> DashboardSettings {
+        DashboardSettings::load_test_config().unwrap()
+    }
+
+    fn clock() -> FixedClock {
+        FixedClock::from_rfc3339("2025-10-25T10:00:00Z").unwrap()
+    }
+
+    fn write_json(dir: &Path, name: &str, json: &Value) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, json.to_string()).unwrap();
+        path
+    }
+
+    mod detect {
+        use super::*;
+
+        #[test]
+        fn array_is_domain_json() {
+            let format = ForecastFileFormat::detect(&json!([]), "hourly");
+            assert_eq!(format, Some(ForecastFileFormat::Domain));
+        }
+
+        #[test]
+        fn data_member_is_bom() {
+            let format = ForecastFileFormat::detect(&json!({"data": []}), "daily");
+            assert_eq!(format, Some(ForecastFileFormat::Bom));
+        }
+
+        #[test]
+        fn section_member_is_open_meteo() {
+            let json = json!({"latitude": 0, "hourly": {}});
+            assert_eq!(
+                ForecastFileFormat::detect(&json, "hourly"),
+                Some(ForecastFileFormat::OpenMeteo)
+            );
+            assert_eq!(ForecastFileFormat::detect(&json, "daily"), None);
+        }
+    }
+
+    mod provider {
+        use super::*;
+
+        #[test]
+        fn reads_bom_and_open_meteo_samples() {
+            let provider = LocalFileProvider::new(
+                "tests/fixtures/open_meteo_hourly_forecast.json".into(),
+                "tests/fixtures/bom_daily_forecast.json".into(),
+            );
+
+            let hourly = provider
+                .fetch_hourly_forecast(&settings(), &clock())
+                .unwrap();
+            let daily = provider
+                .fetch_daily_forecast(&settings(), &clock())
+                .unwrap();
+
+            assert!(!hourly.data.is_empty());
+            assert!(hourly.warning.is_none());
+            assert_eq!(hourly.updated_at, clock().now_utc());
+            assert!(!daily.data.is_empty());
+        }
+
+        #[test]
+        fn reads_domain_json_in_the_configured_unit() {
+            let dir = tempfile::tempdir().unwrap();
+            let hourly = write_json(
+                dir.path(),
+                "hourly.json",
+                &json!([{
+                    "time": "2025-10-25T10:00:00Z",
+                    "temperature": {"value": 100.0, "unit": "F"},
+                    "apparent_temperature": {"value": 20.0, "unit": "C"},
+                    "wind": {"speed_kmh": 10, "gust_speed_kmh": 20},
+                    "precipitation": {"chance": 30, "amount_max": 2},
+                    "uv_index": 3,
+                    "relative_humidity": 50,
+                    "is_night": false,
+                    "weather_code": 61
+                }]),
+            );
+            let daily = write_json(
+                dir.path(),
+                "daily.json",
+                &json!([{
+                    "date": "2025-10-25",
+                    "temp_max": {"value": 25.0, "unit": "C"},
+                    "weather_code": 250
+                }]),
+            );
+            let provider = LocalFileProvider::new(hourly, daily);
+
+            let hour = &provider
+                .fetch_hourly_forecast(&settings(), &clock())
+                .unwrap()
+                .data[0];
+            let day = &provider
+                .fetch_daily_forecast(&settings(), &clock())
+                .unwrap()
+                .data[0];
+
+            assert_eq!(hour.temperature.unit, TemperatureUnit::C);
+            assert_eq!(hour.temperature.round(), 38.0);
+            assert_eq!(hour.wind.direction_deg, None);
+            assert_eq!(hour.weather_code, Some(Ok(WmoWeatherCode::RainSlight)));
+            assert_eq!(day.temp_max.unwrap().value, 25.0);
+            assert!(day.temp_min.is_none());
+            assert_eq!(day.weather_code, Some(Err(250)));
+        }
+
+        #[test]
+        fn unknown_shape_is_an_error() {
+            let dir = tempfile::tempdir().unwrap();
+            let path = write_json(dir.path(), "hourly.json", &json!({"forecast": []}));
+            let provider = LocalFileProvider::new(path, PathBuf::new());
+
+            let Err(error) = provider.fetch_hourly_forecast(&settings(), &clock()) else {
+                panic!("expected an error");
+            };
+
+            assert!(error.to_string().contains("not a BOM or Open-Meteo hourly"));
+        }
+
+        #[test]
+        fn domain_json_missing_a_field_names_it() {
+            let dir = tempfile::tempdir().unwrap();
+            let path = write_json(
+                dir.path(),
+                "hourly.json",
+                &json!([{"time": "2025-10-25T10:00:00Z"}]),
+            );
+            let provider = LocalFileProvider::new(path, PathBuf::new());
+
+            let Err(error) = provider.fetch_hourly_forecast(&settings(), &clock()) else {
+                panic!("expected an error");
+            };
+
+            assert!(error.to_string().contains("domain JSON"));
+            assert!(error.to_string().contains("temperature"));
+        }
+    }
+}
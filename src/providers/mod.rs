@@ -1,10 +1,13 @@
 use anyhow::Error;
+use chrono::{DateTime, Utc};
 
 pub mod bom;
 pub mod factory;
 pub mod fetcher;
+pub mod local;
 pub mod open_meteo;
 
+use crate::clock::Clock;
 use crate::configs::settings::DashboardSettings;
 use crate::domain::models::{DailyForecast, HourlyForecast};
 use crate::errors::DashboardError;
@@ -13,25 +16,29 @@ use crate::errors::DashboardError;
 pub struct FetchResult<T> {
     pub data: T,
     pub warning: Option<DashboardError>,
+    /// When `data` was fetched: the clock's time for data fetched just now,
+    /// the cache file's modification time for cached data.
+    pub updated_at: DateTime<Utc>,
 }
 
 impl<T> FetchResult<T> {
-    pub fn fresh(data: T) -> Self {
+    pub fn fresh(data: T, updated_at: DateTime<Utc>) -> Self {
         Self {
             data,
             warning: None,
+            updated_at,
         }
     }
 
-    pub fn stale(data: T, error: DashboardError) -> Self {
+    pub fn stale(data: T, error: DashboardError, updated_at: DateTime<Utc>) -> Self {
         Self {
             data,
             warning: Some(error),
+            updated_at,
         }
     }
 
     /// Transform the data inside FetchResult while preserving the warning state
-    #[allow(dead_code)] // Utility method - not currently used but useful for future transformations
     pub fn map<U, F>(self, f: F) -> FetchResult<U>
     where
         F: FnOnce(T) -> U,
@@ -39,18 +46,22 @@ impl<T> FetchResult<T> {
         FetchResult {
             data: f(self.data),
             warning: self.warning,
+            updated_at: self.updated_at,
         }
     }
 }
 
 pub trait WeatherProvider {
+    /// `clock` stamps data fetched just now (see [`FetchResult::updated_at`]).
     fn fetch_hourly_forecast(
         &self,
         settings: &DashboardSettings,
+        clock: &dyn Clock,
     ) -> Result<FetchResult<Vec<HourlyForecast>>, Error>;
     fn fetch_daily_forecast(
         &self,
         settings: &DashboardSettings,
+        clock: &dyn Clock,
     ) -> Result<FetchResult<Vec<DailyForecast>>, Error>;
     fn provider_name(&self) -> &str;
     fn provider_filename_prefix(&self) -> &str;
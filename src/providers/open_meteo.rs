@@ -6,6 +6,7 @@ use std::path::PathBuf;
 
 use crate::{
     apis::open_meteo::models::{OpenMeteoDailyResponse, OpenMeteoError, OpenMeteoHourlyResponse},
+    clock::Clock,
     configs::settings::DashboardSettings,
     constants::{
         open_meteo_daily_endpoint, open_meteo_hourly_endpoint, DAILY_CACHE_SUFFIX,
@@ -13,10 +14,7 @@ use crate::{
     },
     domain::models::{DailyForecast, HourlyForecast},
     errors::DashboardError,
-    providers::{
-        fetcher::{FetchOutcome, Fetcher},
-        FetchResult, WeatherProvider,
-    },
+    providers::{fetcher::Fetcher, FetchResult, WeatherProvider},
 };
 
 fn check_open_meteo_error(body: &str) -> Result<(), DashboardError> {
@@ -54,39 +52,37 @@ impl WeatherProvider for OpenMeteoProvider {
     fn fetch_hourly_forecast(
         &self,
         settings: &DashboardSettings,
+        clock: &dyn Clock,
     ) -> Result<FetchResult<Vec<HourlyForecast>>, Error> {
-        let result = match self.fetcher.fetch_data::<OpenMeteoHourlyResponse>(
-            settings,
-            open_meteo_hourly_endpoint(settings),
-            &self.generate_cache_filename(HOURLY_CACHE_SUFFIX),
-            Some(check_open_meteo_error),
-        )? {
-            FetchOutcome::Fresh(data) => FetchResult::fresh(data.into_domain(settings)),
-            FetchOutcome::Stale { data, error } => {
-                FetchResult::stale(data.into_domain(settings), error)
-            }
-        };
-
-        Ok(result)
+        let result = self
+            .fetcher
+            .fetch_data::<OpenMeteoHourlyResponse>(
+                settings,
+                open_meteo_hourly_endpoint(settings),
+                &self.generate_cache_filename(HOURLY_CACHE_SUFFIX),
+                Some(check_open_meteo_error),
+            )?
+            .into_result(clock.now_utc());
+
+        Ok(result.map(|data| data.into_domain(settings)))
     }
 
     fn fetch_daily_forecast(
         &self,
         settings: &DashboardSettings,
+        clock: &dyn Clock,
     ) -> Result<FetchResult<Vec<DailyForecast>>, Error> {
-        let result = match self.fetcher.fetch_data::<OpenMeteoDailyResponse>(
-            settings,
-            open_meteo_daily_endpoint(settings),
-            &self.generate_cache_filename(DAILY_CACHE_SUFFIX),
-            Some(check_open_meteo_error),
-        )? {
-            FetchOutcome::Fresh(data) => FetchResult::fresh(data.into_domain(settings)),
-            FetchOutcome::Stale { data, error } => {
-                FetchResult::stale(data.into_domain(settings), error)
-            }
-        };
-
-        Ok(result)
+        let result = self
+            .fetcher
+            .fetch_data::<OpenMeteoDailyResponse>(
+                settings,
+                open_meteo_daily_endpoint(settings),
+                &self.generate_cache_filename(DAILY_CACHE_SUFFIX),
+                Some(check_open_meteo_error),
+            )?
+            .into_result(clock.now_utc());
+
+        Ok(result.map(|data| data.into_domain(settings)))
     }
 
     fn provider_name(&self) -> &str {
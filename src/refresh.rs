@@ -0,0 +1,379 @@
+//! Skipping unchanged renders (`[refresh]`).
+//!
+//! Each run hashes the rendered SVG and compares it with the hash saved by
+//! the last run that wrote the outputs. When they match, the outputs are left
+//! as they are and `render_status.txt` (next to the PNG) reads `unchanged`,
+//! so the display script can skip the panel refresh.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::clock::Clock;
+use crate::configs::settings::DashboardSettings;
+use crate::logger;
+use crate::utils::write_atomic;
+
+/// Exit code of a run that skipped writing because nothing changed.
+pub const UNCHANGED_EXIT_CODE: i32 = 3;
+/// Status file written next to `misc.generated_png_name`.
+pub const STATUS_FILE_NAME: &str = "render_status.txt";
+const LAST_RENDER_FILE_NAME: &str = "last_render.json";
+
+/// Whether a run wrote its outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderOutcome {
+    Written,
+    Unchanged,
+    /// The dashboard failed to generate and the error screen was written in
+    /// its place.
+    ErrorScreen,
+}
+
+/// Saved in the weather data cache after every write.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct LastRender {
+    hash: u64,
+    refreshed_at: DateTime<Utc>,
+}
+
+/// 64-bit FNV-1a of the rendered SVG. Unlike `std`'s hasher it is stable
+/// across Rust releases, so a self-update doesn't force a refresh.
+pub fn content_hash(svg: &str) -> u64 {
+    svg.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// [`content_hash`] of `svg` without the data age label's fetch time
+/// ("Updated 14:05"), so a run that fetched the same forecast again still
+/// counts as unchanged. The label's style, which escalates as the data
+/// ages, still counts, and so does its day count ("Data 2 d old"), which
+/// the caller passes as an empty `fetch_time_label`.
+pub fn render_hash(svg: &str, fetch_time_label: &str) -> u64 {
+    if fetch_time_label.is_empty() {
+        content_hash(svg)
+    } else {
+        content_hash(&svg.replace(fetch_time_label, ""))
+    }
+}
+
+fn last_render_path(settings: &DashboardSettings) -> PathBuf {
+    settings
+        .misc
+        .weather_data_cache_path
+        .join(LAST_RENDER_FILE_NAME)
+}
+
+pub fn status_path(settings: &DashboardSettings) -> PathBuf {
+    settings
+        .misc
+        .generated_png_name
+        .with_file_name(STATUS_FILE_NAME)
+}
+
+fn read_last_render(settings: &DashboardSettings) -> Option<LastRender> {
+    let json = fs::read_to_string(last_render_path(settings)).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Why the outputs must be written for a render hashing to `hash`, or
+/// `None` when they can be left as they are.
+pub fn write_reason(
+    settings: &DashboardSettings,
+    clock: &dyn Clock,
+    hash: u64,
+    output_svg_name: &Path,
+) -> Option<&'static str> {
+    if !settings.refresh.skip_unchanged {
+        return Some("skip_unchanged is off");
+    }
+    let Some(last) = read_last_render(settings) else {
+        return Some("no previous render");
+    };
+    if last.hash != hash {
+        return Some("dashboard changed");
+    }
+    if !outputs(settings, output_svg_name)
+        .iter()
+        .all(|output| output.exists())
+    {
+        return Some("previous output missing");
+    }
+    let force_every = Duration::hours(i64::from(settings.refresh.force_every_hours));
+    if settings.refresh.force_every_hours > 0 && clock.now_utc() - last.refreshed_at >= force_every
+    {
+        return Some("forced refresh is due");
+    }
+    None
+}
+
+/// The files a write leaves behind: the SVG when PNG output is off,
+/// otherwise the PNG, and every `[output] targets` entry either way.
+fn outputs<'a>(settings: &'a DashboardSettings, output_svg_name: &'a Path) -> Vec<&'a Path> {
+    let mut outputs = vec![if settings.dev.disable_png_output {
+        output_svg_name
+    } else {
+        settings.misc.generated_png_name.as_path()
+    }];
+    outputs.extend(
+        settings
+            .output
+            .targets
+            .iter()
+            .map(|target| target.path.as_path()),
+    );
+    outputs
+}
+
+/// Records a run that wrote its outputs.
+pub fn record_written(settings: &DashboardSettings, clock: &dyn Clock, hash: u64) {
+    if !settings.refresh.skip_unchanged {
+        return;
+    }
+    let last = LastRender {
+        hash,
+        refreshed_at: clock.now_utc(),
+    };
+    let path = last_render_path(settings);
+    let result = fs::create_dir_all(&settings.misc.weather_data_cache_path).and_then(|()| {
+        write_atomic(
+            &path,
+            serde_json::to_string(&last).expect("LastRender serializes"),
+        )
+    });
+    if let Err(e) = result {
+        logger::error(format!("Failed to save {}: {e}", path.display()));
+    }
+    write_status(settings, "changed");
+}
+
+/// Records a run that left the outputs untouched.
+pub fn record_unchanged(settings: &DashboardSettings) {
+    write_status(settings, "unchanged");
+}
+
+fn write_status(settings: &DashboardSettings, status: &str) {
+    let path = status_path(settings);
+    if let Err(e) = write_atomic(&path, status) {
+        logger::error(format!("Failed to write render status: {e}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::configs::settings::{Mirror, OutputFormat, OutputTarget, Rotation};
+
+    const SVG_PATH: &str = "unused.svg";
+
+    /// Settings with skipping on, writing everything under `dir`.
+    fn settings(dir: &Path) -> DashboardSettings {
+        let mut settings = DashboardSettings::load_test_config().unwrap();
+        settings.refresh.skip_unchanged = true;
+        settings.refresh.force_every_hours = 6;
+        settings.dev.disable_png_output = false;
+        settings.misc.weather_data_cache_path = dir.join("cache");
+        settings.misc.generated_png_name = dir.join("dashboard.png");
+        settings
+    }
+
+    fn clock(rfc3339: &str) -> FixedClock {
+        FixedClock::from_rfc3339(rfc3339).unwrap()
+    }
+
+    /// Records a write of `hash` at 10:00 and creates the PNG.
+    fn written(settings: &DashboardSettings, hash: u64) {
+        record_written(settings, &clock("2025-10-25T10:00:00Z"), hash);
+        fs::write(&settings.misc.generated_png_name, "png").unwrap();
+    }
+
+    #[test]
+    fn hash_is_stable() {
+        assert_eq!(content_hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(content_hash("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(content_hash("<svg/>"), content_hash("<svg />"));
+    }
+
+    #[test]
+    fn fetch_time_is_not_a_change_but_days_old_is() {
+        let svg =
+            |label: &str, weight: &str| format!(r#"<text font-weight="{weight}">{label}</text>"#);
+
+        assert_eq!(
+            render_hash(&svg("Updated 14:05", "normal"), "Updated 14:05"),
+            render_hash(&svg("Updated 15:05", "normal"), "Updated 15:05")
+        );
+        assert_ne!(
+            render_hash(&svg("Updated 14:05", "normal"), "Updated 14:05"),
+            render_hash(&svg("Updated 14:05", "bold"), "Updated 14:05")
+        );
+        assert_ne!(
+            render_hash(&svg("Data 2 d old", "bold"), ""),
+            render_hash(&svg("Data 3 d old", "bold"), "")
+        );
+        assert_eq!(render_hash("<svg/>", ""), content_hash("<svg/>"));
+    }
+
+    #[test]
+    fn same_render_is_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings = settings(dir.path());
+        written(&settings, 42);
+
+        let reason = write_reason(
+            &settings,
+            &clock("2025-10-25T11:00:00Z"),
+            42,
+            Path::new(SVG_PATH),
+        );
+
+        assert_eq!(reason, None);
+        assert_eq!(
+            fs::read_to_string(status_path(&settings)).unwrap(),
+            "changed"
+        );
+        record_unchanged(&settings);
+        assert_eq!(
+            fs::read_to_string(status_path(&settings)).unwrap(),
+            "unchanged"
+        );
+    }
+
+    #[test]
+    fn different_render_is_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings = settings(dir.path());
+        written(&settings, 42);
+
+        let reason = write_reason(
+            &settings,
+            &clock("2025-10-25T11:00:00Z"),
+            43,
+            Path::new(SVG_PATH),
+        );
+
+        assert_eq!(reason, Some("dashboard changed"));
+    }
+
+    #[test]
+    fn first_render_is_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings = settings(dir.path());
+
+        let reason = write_reason(
+            &settings,
+            &clock("2025-10-25T11:00:00Z"),
+            42,
+            Path::new(SVG_PATH),
+        );
+
+        assert_eq!(reason, Some("no previous render"));
+    }
+
+    #[test]
+    fn missing_output_is_rewritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings = settings(dir.path());
+        written(&settings, 42);
+        fs::remove_file(&settings.misc.generated_png_name).unwrap();
+
+        let reason = write_reason(
+            &settings,
+            &clock("2025-10-25T11:00:00Z"),
+            42,
+            Path::new(SVG_PATH),
+        );
+
+        assert_eq!(reason, Some("previous output missing"));
+    }
+
+    /// Settings writing one `[output] targets` BMP under `dir`.
+    fn with_target(dir: &Path) -> DashboardSettings {
+        let mut settings = settings(dir);
+        settings.output.targets = vec![OutputTarget {
+            format: OutputFormat::Bmp,
+            path: dir.join("frame.bmp"),
+            width: None,
+            height: None,
+            rotation: Rotation::None,
+            mirror: Mirror::None,
+        }];
+        settings
+    }
+
+    #[test]
+    fn missing_extra_output_is_rewritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings = with_target(dir.path());
+        written(&settings, 42);
+        let now = clock("2025-10-25T11:00:00Z");
+        let svg = Path::new(SVG_PATH);
+
+        assert_eq!(
+            write_reason(&settings, &now, 42, svg),
+            Some("previous output missing")
+        );
+        fs::write(&settings.output.targets[0].path, "bmp").unwrap();
+        assert_eq!(write_reason(&settings, &now, 42, svg), None);
+    }
+
+    #[test]
+    fn missing_extra_output_is_rewritten_without_png_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut settings = with_target(dir.path());
+        settings.dev.disable_png_output = true;
+        written(&settings, 42);
+        let now = clock("2025-10-25T11:00:00Z");
+        let svg = dir.path().join("dashboard.svg");
+        fs::write(&svg, "svg").unwrap();
+
+        assert_eq!(
+            write_reason(&settings, &now, 42, &svg),
+            Some("previous output missing")
+        );
+        fs::write(&settings.output.targets[0].path, "bmp").unwrap();
+        assert_eq!(write_reason(&settings, &now, 42, &svg), None);
+    }
+
+    #[test]
+    fn refresh_is_forced_after_the_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut settings = settings(dir.path());
+        written(&settings, 42);
+        let svg = Path::new(SVG_PATH);
+
+        let before = clock("2025-10-25T15:59:00Z");
+        let due = clock("2025-10-25T16:00:00Z");
+        assert_eq!(write_reason(&settings, &before, 42, svg), None);
+        assert_eq!(
+            write_reason(&settings, &due, 42, svg),
+            Some("forced refresh is due")
+        );
+
+        settings.refresh.force_every_hours = 0;
+        assert_eq!(write_reason(&settings, &due, 42, svg), None);
+    }
+
+    #[test]
+    fn disabled_always_writes_and_records_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut settings = settings(dir.path());
+        settings.refresh.skip_unchanged = false;
+        written(&settings, 42);
+
+        let reason = write_reason(
+            &settings,
+            &clock("2025-10-25T11:00:00Z"),
+            42,
+            Path::new(SVG_PATH),
+        );
+
+        assert_eq!(reason, Some("skip_unchanged is off"));
+        assert!(!status_path(&settings).exists());
+        assert!(!last_render_path(&settings).exists());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::clock::Clock;
 use crate::configs::settings::DashboardSettings;
 use crate::logger;
+use crate::utils::write_atomic;
 use anyhow::{Context, Error, Result};
 use chrono::{DateTime, Duration, Utc};
 use semver::Version;
@@ -123,7 +124,7 @@ impl UpdateService {
         };
 
         if result.is_ok() {
-            fs::write(&last_checked_path, now_utc.to_rfc3339())?;
+            write_atomic(&last_checked_path, now_utc.to_rfc3339())?;
         }
         write_update_status(&self.base_dir, &result);
 
@@ -300,7 +301,7 @@ pub fn write_update_status(base_dir: &Path, result: &Result<(), Error>) {
         Err(e) => format!("failed: {e}"),
     };
 
-    if let Err(e) = fs::write(&status_path, status) {
+    if let Err(e) = write_atomic(&status_path, status) {
         logger::error(format!("Failed to write update status: {}", e));
     }
 }
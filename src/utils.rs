@@ -1,4 +1,5 @@
 use crate::configs::settings::{Latitude, Longitude};
+use crate::dashboard::self_contained;
 use crate::errors::GeohashError;
 use crate::logger;
 use anyhow::Error;
@@ -8,7 +9,8 @@ use chrono::TimeZone;
 use resvg::tiny_skia;
 use resvg::usvg;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
 use usvg::fontdb;
 
@@ -25,19 +27,55 @@ use usvg::fontdb;
 /// * `Result<(), Error>` - Ok(()) if successful, or an error message.
 pub fn convert_svg_to_png(
     input_path: &PathBuf,
-    output_path: &PathBuf,
+    output_path: &Path,
     scale_factor: f32,
 ) -> Result<(), Error> {
     // Read the SVG file
     let svg_data = fs::read_to_string(input_path)
         .map_err(|e| Error::msg(format!("Failed to read SVG file: {e}")))?;
 
+    // Save the PNG file
+    write_atomic(output_path, svg_to_png(&svg_data, scale_factor)?)
+        .map_err(|e| Error::msg(format!("Failed to save PNG: {e}")))?;
+
+    Ok(())
+}
+
+/// Writes `contents` to `path` through a temporary file in the same directory
+/// and a rename, so a reader never sees a half-written file. An existing
+/// file's permissions are kept; a new one gets the usual 0644.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let mut temp = tempfile::Builder::new()
+        .prefix(".")
+        .suffix(".tmp")
+        .tempfile_in(dir)?;
+    temp.write_all(contents.as_ref())?;
+    temp.as_file().sync_all()?;
+
+    let permissions = match fs::metadata(path) {
+        Ok(metadata) => metadata.permissions(),
+        #[cfg(unix)]
+        Err(_) => std::os::unix::fs::PermissionsExt::from_mode(0o644),
+        #[cfg(not(unix))]
+        Err(_) => temp.as_file().metadata()?.permissions(),
+    };
+    temp.as_file().set_permissions(permissions)?;
+    temp.persist(path).map_err(|e| e.error)?;
+    Ok(())
+}
+
+/// Renders SVG markup to PNG bytes, scaled by `scale_factor`.
+pub fn svg_to_png(svg_data: &str, scale_factor: f32) -> Result<Vec<u8>, Error> {
     let opts = usvg::Options {
-        fontdb: shared_font_db(),
+        fontdb: font_db_for(svg_data),
         ..Default::default()
     };
 
-    let tree = usvg::Tree::from_str(&svg_data, &opts)
+    let tree = usvg::Tree::from_str(svg_data, &opts)
         .map_err(|e| Error::msg(format!("Failed to parse SVG: {e}")))?;
 
     // Create a higher resolution canvas
@@ -53,12 +91,9 @@ pub fn convert_svg_to_png(
     // Render SVG onto the canvas with scaling
     resvg::render(&tree, transform, &mut pixmap.as_mut());
 
-    // Save the PNG file
     pixmap
-        .save_png(output_path)
-        .map_err(|e| Error::msg(format!("Failed to save PNG: {e}")))?;
-
-    Ok(())
+        .encode_png()
+        .map_err(|e| Error::msg(format!("Failed to encode PNG: {e}")))
 }
 
 /// Loads fonts into the provided font database.
@@ -73,8 +108,10 @@ fn load_fonts(font_db: &mut fontdb::Database) {
         "static/fonts/Roboto-VariableFont_wdth,wght.ttf",
         "static/fonts/Roboto-Italic-VariableFont_wdth,wght.ttf",
         "static/fonts/Roboto-Regular-Dashed.ttf",
-        // Subset covering only the kanji used by the Japanese locale strings in
-        // src/i18n.rs; Roboto has no CJK glyphs, so resvg falls back to this font.
+        // Subset covering only the Japanese characters used by src/i18n.rs and
+        // src/dashboard/summary.rs (regenerate with
+        // scripts/subset-japanese-font.sh); Roboto has no CJK glyphs, so resvg
+        // falls back to this font.
         "static/fonts/NotoSansJP-Weather-Regular.ttf",
     ];
 
@@ -92,7 +129,7 @@ fn load_fonts(font_db: &mut fontdb::Database) {
 /// [`measure_text_width`] match what resvg actually renders. Cached because
 /// loading font files involves filesystem I/O and measurement can run once
 /// per label per render.
-fn shared_font_db() -> Arc<fontdb::Database> {
+pub(crate) fn shared_font_db() -> Arc<fontdb::Database> {
     static FONT_DB: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
     FONT_DB
         .get_or_init(|| {
@@ -103,6 +140,60 @@ fn shared_font_db() -> Arc<fontdb::Database> {
         .clone()
 }
 
+/// Whether the bundled fonts have a glyph for every character of `text`.
+///
+/// resvg draws nothing for a character no font covers, and
+/// [`measure_text_width`] then measures the gap, so text from outside the
+/// font subsets has to be checked before it is drawn.
+pub(crate) fn fonts_cover(text: &str) -> bool {
+    let db = shared_font_db();
+    let missing: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let missing = db.faces().fold(missing, |missing, face| {
+        db.with_face_data(face.id, |data, index| {
+            match ttf_parser::Face::parse(data, index) {
+                Ok(face) => missing
+                    .iter()
+                    .copied()
+                    .filter(|&c| face.glyph_index(c).is_none())
+                    .collect(),
+                Err(_) => missing.clone(),
+            }
+        })
+        .unwrap_or(missing)
+    });
+    missing.is_empty()
+}
+
+/// The fonts embedded in `svg` when it carries its own (see
+/// [`self_contained`]), so it renders the same from any directory; the
+/// bundled fonts otherwise.
+pub(crate) fn font_db_for(svg: &str) -> Arc<fontdb::Database> {
+    let embedded = self_contained::embedded_fonts(svg);
+    if embedded.is_empty() {
+        return shared_font_db();
+    }
+    let mut font_db = fontdb::Database::new();
+    for data in embedded {
+        font_db.load_font_data(data);
+    }
+    Arc::new(font_db)
+}
+
+/// Byte offset just past the `>` of a page's root `<svg ...>` start tag.
+pub(crate) fn root_tag_end(svg: &str) -> Option<usize> {
+    let start = svg.find("<svg")?;
+    svg[start..].find('>').map(|end| start + end + 1)
+}
+
+/// Inserts `markup` just inside the page's root `<svg>` element, or returns
+/// the page unchanged if it has none.
+pub(crate) fn insert_after_root_tag(svg: &str, markup: &str) -> String {
+    match root_tag_end(svg) {
+        Some(root_end) => format!("{}{markup}{}", &svg[..root_end], &svg[root_end..]),
+        None => svg.to_string(),
+    }
+}
+
 // =============================================================================
 // TEMPORARY WORKAROUND — remove once resvg/usvg supports tspan `text-anchor`
 // with `dx`/`dy` centering correctly: https://github.com/linebender/resvg/issues/583
@@ -112,7 +203,7 @@ fn shared_font_db() -> Arc<fontdb::Database> {
 // pixels, rather than via native SVG anchoring.
 // =============================================================================
 
-fn escape_xml_text(text: &str) -> String {
+pub(crate) fn escape_xml_text(text: &str) -> String {
     text.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
@@ -223,6 +314,31 @@ pub fn measure_ink_y_center(svg: &str) -> Option<f32> {
     Some((min_y + max_y) as f32 / 2.0)
 }
 
+/// Rendered ink width (SVG user units) of a single line of `text`, or `None`
+/// if nothing rendered.
+///
+/// Like [`measure_stacked_label_dx`], this goes through the real resvg/usvg
+/// pipeline and font DB, so localized digits, units and fallback glyphs are
+/// measured as they will actually be drawn rather than estimated per
+/// character.
+pub fn measure_text_width(text: &str, font_family: &str, font_size: f32) -> Option<f32> {
+    if text.is_empty() {
+        return None;
+    }
+    let text = escape_xml_text(text);
+    let width = font_size * (text.chars().count() as f32 + 2.0) * 1.5;
+    let height = font_size * 2.0;
+    let svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" font-family="{font_family}">
+            <text x="{font_size}" y="{baseline}" font-size="{font_size}">{text}</text>
+        </svg>"#,
+        baseline = font_size * 1.5,
+    );
+    let pixmap = render_svg_to_pixmap(&svg)?;
+    let (min_x, max_x) = ink_extent_x_in_y_range(&pixmap, 0, pixmap.height() - 1)?;
+    Some((max_x - min_x + 1) as f32)
+}
+
 /// x-extent (min, max) of non-transparent pixels within rows `y_from..=y_to`
 /// (clamped to the pixmap's height), or `None` if no such pixel is found.
 fn ink_extent_x_in_y_range(
@@ -556,6 +672,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fonts_cover_checks_every_character() {
+        assert!(fonts_cover("Showers developing after 3pm."));
+        assert!(fonts_cover("Averses après 15:00, Böiger Wind"));
+        assert!(fonts_cover("土曜日"));
+        assert!(!fonts_cover("Rain 한"));
+    }
+
     #[test]
     fn shared_font_db_covers_every_translated_string() {
         // `load_fonts` deliberately doesn't load system fonts (see its doc
@@ -707,6 +831,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn text_width_grows_with_text_and_font_size() {
+        let short = measure_text_width("3°", "Roboto, sans-serif", 16.0).unwrap();
+        let long = measure_text_width("-13°", "Roboto, sans-serif", 16.0).unwrap();
+        let large = measure_text_width("3°", "Roboto, sans-serif", 32.0).unwrap();
+        assert!(short > 0.0);
+        assert!(long > short, "{long} <= {short}");
+        assert!(large > short * 1.5, "{large} vs {short}");
+    }
+
+    #[test]
+    fn text_width_is_none_for_empty_text() {
+        assert_eq!(measure_text_width("", "Roboto, sans-serif", 16.0), None);
+    }
+
     struct Point {
         time: DateTime<Utc>,
         value: f64,
@@ -948,4 +1087,38 @@ mod tests {
             }
         }
     }
+
+    mod write_atomic_tests {
+        use super::*;
+
+        #[test]
+        fn replaces_the_file_and_leaves_no_temp_files() {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("dashboard.png");
+            fs::write(&path, "old").unwrap();
+
+            write_atomic(&path, "new").unwrap();
+
+            assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+            assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn keeps_existing_permissions_and_makes_new_files_readable() {
+            use std::os::unix::fs::PermissionsExt;
+            let dir = tempfile::tempdir().unwrap();
+            let existing = dir.path().join("existing.svg");
+            fs::write(&existing, "old").unwrap();
+            fs::set_permissions(&existing, fs::Permissions::from_mode(0o600)).unwrap();
+            let new = dir.path().join("new.svg");
+
+            write_atomic(&existing, "new").unwrap();
+            write_atomic(&new, "new").unwrap();
+
+            let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode(&existing), 0o600);
+            assert_eq!(mode(&new), 0o644);
+        }
+    }
 }
@@ -0,0 +1,402 @@
+//! Icon pack manifests.
+//!
+//! Each directory usable as `misc.svg_icons_directory` ships a
+//! `manifest.toml` declaring the icons it provides and, optionally, which
+//! provided icon to draw in place of one it lacks:
+//!
+//! ```toml
+//! name = "Meteocons (line)"
+//! icons = ["clear-day.svg", "humidity.svg"]
+//!
+//! [fallbacks]
+//! "humidity-plus.svg" = "humidity.svg"
+//! ```
+//!
+//! The pack is checked at startup against every icon name the dashboard can
+//! produce, so a pack with gaps fails config validation instead of rendering
+//! a missing image on the display.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use config::{Config, File, FileFormat};
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+
+use crate::domain::icons::fallback_icon_names;
+use crate::domain::weather_code::WmoWeatherCode;
+use crate::errors::DashboardErrorIconName;
+use crate::weather::icons::{
+    HumidityIconName, NotAvailableIcon, SunPositionIconName, UVIndexIcon, WindIconName,
+};
+use crate::weather::utils::MoonPhaseIconName;
+
+/// File name of the manifest inside an icon pack directory.
+pub const MANIFEST_FILE_NAME: &str = "manifest.toml";
+
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+pub struct IconPack {
+    /// Human readable name, shown in the startup config summary.
+    #[serde(default)]
+    pub name: String,
+    /// Icon file names the pack provides.
+    pub icons: BTreeSet<String>,
+    /// Icon drawn in place of a missing one. Fallbacks may chain, as long as
+    /// the chain ends at a provided icon.
+    #[serde(default)]
+    pub fallbacks: BTreeMap<String, String>,
+    #[serde(skip)]
+    directory: PathBuf,
+}
+
+impl IconPack {
+    /// Loads the manifest from `directory`. A directory without one is
+    /// treated as a pack providing exactly the SVG files in it, with no
+    /// fallbacks.
+    pub fn load(directory: &Path) -> Result<Self, String> {
+        let manifest_path = directory.join(MANIFEST_FILE_NAME);
+        let mut pack = if manifest_path.is_file() {
+            Config::builder()
+                .add_source(File::from(manifest_path.as_path()).format(FileFormat::Toml))
+                .build()
+                .and_then(Config::try_deserialize::<IconPack>)
+                .map_err(|e| {
+                    format!(
+                        "Failed to read icon pack manifest {}: {e}",
+                        manifest_path.display()
+                    )
+                })?
+        } else {
+            Self::from_directory_listing(directory)?
+        };
+        pack.directory = directory.to_path_buf();
+        Ok(pack)
+    }
+
+    fn from_directory_listing(directory: &Path) -> Result<Self, String> {
+        let entries = std::fs::read_dir(directory).map_err(|e| {
+            format!(
+                "Failed to read svg_icons_directory {}: {e}",
+                directory.display()
+            )
+        })?;
+        let icons = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| name.ends_with(".svg"))
+            .collect();
+        Ok(Self {
+            name: directory.display().to_string(),
+            icons,
+            ..Self::default()
+        })
+    }
+
+    /// The provided icon to draw for `name`: `name` itself, or the end of its
+    /// fallback chain. `None` if neither is provided.
+    pub fn lookup<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        let mut current = name;
+        let mut visited = HashSet::new();
+        while visited.insert(current) {
+            if self.icons.contains(current) {
+                return Some(current);
+            }
+            current = self.fallbacks.get(current)?;
+        }
+        None
+    }
+
+    /// [`Self::lookup`], keeping `name` when the pack can't resolve it (or no
+    /// pack was loaded), so the render shows the missing image as before.
+    pub fn resolve<'a>(&'a self, name: &'a str) -> &'a str {
+        self.lookup(name).unwrap_or(name)
+    }
+
+    /// Path of the file drawn for `name`, if the pack resolves it to a file
+    /// that exists.
+    pub fn path_of(&self, name: &str) -> Option<PathBuf> {
+        self.lookup(name)
+            .map(|file| self.directory.join(file))
+            .filter(|path| path.is_file())
+    }
+
+    /// Checks that every name from [`required_icon_names`] resolves to an
+    /// icon file present in the pack directory, listing all the gaps.
+    pub fn validate(&self) -> Result<(), String> {
+        let gaps: Vec<String> = required_icon_names()
+            .iter()
+            .filter_map(|name| match self.lookup(name) {
+                None => Some(format!("{name} (not provided and no fallback)")),
+                Some(file) if !self.directory.join(file).is_file() => {
+                    if file == name {
+                        Some(format!("{name} (declared but file not found)"))
+                    } else {
+                        Some(format!("{name} (fallback {file} not found)"))
+                    }
+                }
+                Some(_) => None,
+            })
+            .collect();
+
+        if gaps.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Icon pack {} is missing {} icon(s): {}",
+                self.directory.display(),
+                gaps.len(),
+                gaps.join(", ")
+            ))
+        }
+    }
+}
+
+/// Every icon file name the dashboard can ask an icon pack for: the WMO
+/// code icons, the precipitation-based ones chosen for forecasts without a
+/// code (which include names no code maps to, such as "extreme-day.svg")
+/// and the fixed icons.
+pub fn required_icon_names() -> BTreeSet<String> {
+    let mut names: BTreeSet<String> = WmoWeatherCode::iter()
+        .flat_map(|code| [code.icon_name(false), code.icon_name(true)])
+        .collect();
+    names.extend(fallback_icon_names());
+    names.extend(MoonPhaseIconName::iter().map(|icon| icon.to_string()));
+    names.extend(UVIndexIcon::iter().map(|icon| icon.to_string()));
+    names.extend(WindIconName::iter().map(|icon| icon.to_string()));
+    names.extend(HumidityIconName::iter().map(|icon| icon.to_string()));
+    names.extend(SunPositionIconName::iter().map(|icon| icon.to_string()));
+    names.extend(NotAvailableIcon::iter().map(|icon| icon.to_string()));
+    names.extend(DashboardErrorIconName::iter().map(|icon| icon.to_string()));
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack(icons: &[&str], fallbacks: &[(&str, &str)]) -> IconPack {
+        IconPack {
+            icons: icons.iter().map(|icon| icon.to_string()).collect(),
+            fallbacks: fallbacks
+                .iter()
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .collect(),
+            ..IconPack::default()
+        }
+    }
+
+    mod lookup {
+        use super::*;
+
+        #[test]
+        fn provided_icon_resolves_to_itself() {
+            let pack = pack(&["humidity.svg"], &[]);
+            assert_eq!(pack.lookup("humidity.svg"), Some("humidity.svg"));
+        }
+
+        #[test]
+        fn fallback_chain_ends_at_a_provided_icon() {
+            let pack = pack(
+                &["humidity.svg"],
+                &[
+                    ("humidity-plus-plus.svg", "humidity-plus.svg"),
+                    ("humidity-plus.svg", "humidity.svg"),
+                ],
+            );
+            assert_eq!(pack.lookup("humidity-plus-plus.svg"), Some("humidity.svg"));
+        }
+
+        #[test]
+        fn fallback_to_a_missing_icon_does_not_resolve() {
+            let pack = pack(&[], &[("humidity-plus.svg", "humidity.svg")]);
+            assert_eq!(pack.lookup("humidity-plus.svg"), None);
+        }
+
+        #[test]
+        fn fallback_cycle_does_not_resolve() {
+            let pack = pack(&[], &[("a.svg", "b.svg"), ("b.svg", "a.svg")]);
+            assert_eq!(pack.lookup("a.svg"), None);
+        }
+
+        #[test]
+        fn unresolved_name_is_kept() {
+            let pack = IconPack::default();
+            assert_eq!(pack.resolve("wind.svg"), "wind.svg");
+        }
+    }
+
+    mod required_icon_names {
+        use super::*;
+
+        #[test]
+        fn covers_every_icon_source() {
+            let names = required_icon_names();
+            for name in [
+                "clear-night.svg",
+                "thunderstorms-day-extreme-hail.svg",
+                "moon-full.svg",
+                "uv-index-none.svg",
+                "umbrella-wind-alt.svg",
+                "humidity-plus-plus.svg",
+                "sunset.svg",
+                "not-available.svg",
+                "code-red.svg",
+            ] {
+                assert!(names.contains(name), "missing {name}");
+            }
+        }
+
+        /// Forecasts without a weather code get precipitation-based icons,
+        /// some of which no WMO code maps to.
+        #[test]
+        fn covers_every_precipitation_based_icon() {
+            use crate::configs::settings::DashboardSettings;
+            use crate::domain::models::{
+                DailyForecast, HourlyForecast, Precipitation, Temperature, Wind,
+            };
+            use crate::weather::icons::{placeholder_today, Icon, IconContext};
+
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.render_options.prefer_weather_codes = false;
+            let ctx = IconContext::from_settings(&settings, placeholder_today());
+            let names = required_icon_names();
+            // (min, max) mm and snowfall in tenths of a cm: none, drizzle,
+            // rain and snow, as hourly and as daily amounts.
+            let amounts = [
+                (0, 0, 0),
+                (0, 1, 0),
+                (1, 1, 0),
+                (5, 5, 0),
+                (30, 30, 0),
+                (1, 1, 20),
+                (5, 5, 50),
+            ];
+
+            let mut icons = Vec::new();
+            for chance in [0, 30, 60, 90] {
+                for (min, max, snow) in amounts {
+                    let precipitation = Precipitation::new_with_snowfall(
+                        Some(chance),
+                        Some(min),
+                        Some(max),
+                        Some(snow),
+                    );
+                    for is_night in [false, true] {
+                        let hour = HourlyForecast {
+                            time: chrono::Utc::now(),
+                            temperature: Temperature::celsius(10.0),
+                            apparent_temperature: Temperature::celsius(10.0),
+                            wind: Wind::new(0, 0),
+                            precipitation: precipitation.clone(),
+                            uv_index: 0,
+                            relative_humidity: 50,
+                            is_night,
+                            cloud_cover: None,
+                            weather_code: None,
+                        };
+                        icons.push(hour.icon_name(&ctx));
+                    }
+                    let day = DailyForecast {
+                        date: None,
+                        temp_max: None,
+                        temp_min: None,
+                        precipitation: Some(precipitation),
+                        astronomical: None,
+                        cloud_cover: None,
+                        weather_code: None,
+                        summary: None,
+                        short_summary: None,
+                    };
+                    icons.push(day.icon_name(&ctx));
+                }
+            }
+
+            assert!(icons.iter().any(|icon| icon == "extreme-day.svg"));
+            assert!(icons.iter().any(|icon| icon == "extreme-night.svg"));
+            for icon in icons {
+                assert!(names.contains(&icon), "missing {icon}");
+            }
+        }
+    }
+
+    mod shipped_packs {
+        use super::*;
+
+        #[test]
+        fn fill_pack_covers_every_required_icon() {
+            let pack = IconPack::load(Path::new("static/fill-svg-static")).unwrap();
+            assert_eq!(pack.validate(), Ok(()));
+            assert!(pack.fallbacks.is_empty());
+        }
+
+        #[test]
+        fn line_pack_covers_every_required_icon_through_fallbacks() {
+            let pack = IconPack::load(Path::new("static/line-svg-static")).unwrap();
+            assert_eq!(pack.validate(), Ok(()));
+            assert_eq!(pack.resolve("uv-index-extreme.svg"), "uv-index-11.svg");
+            assert_eq!(pack.resolve("humidity-plus-plus.svg"), "humidity.svg");
+        }
+
+        #[test]
+        fn every_declared_icon_exists() {
+            for directory in ["static/fill-svg-static", "static/line-svg-static"] {
+                let pack = IconPack::load(Path::new(directory)).unwrap();
+                for icon in &pack.icons {
+                    assert!(
+                        Path::new(directory).join(icon).is_file(),
+                        "{directory}/{icon}"
+                    );
+                }
+            }
+        }
+    }
+
+    mod validate {
+        use super::*;
+
+        #[test]
+        fn directory_without_manifest_lists_its_svg_files() {
+            let dir = tempfile::tempdir().unwrap();
+            std::fs::write(dir.path().join("wind.svg"), "<svg/>").unwrap();
+            std::fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+            let pack = IconPack::load(dir.path()).unwrap();
+
+            assert_eq!(pack.icons, BTreeSet::from(["wind.svg".to_string()]));
+        }
+
+        #[test]
+        fn reports_every_gap() {
+            let dir = tempfile::tempdir().unwrap();
+            std::fs::write(dir.path().join("wind.svg"), "<svg/>").unwrap();
+            std::fs::write(
+                dir.path().join(MANIFEST_FILE_NAME),
+                r#"
+                name = "partial"
+                icons = ["wind.svg", "sunset.svg"]
+
+                [fallbacks]
+                "sunrise.svg" = "sunset.svg"
+                "#,
+            )
+            .unwrap();
+
+            let error = IconPack::load(dir.path()).unwrap().validate().unwrap_err();
+
+            assert!(error.contains("sunset.svg (declared but file not found)"));
+            assert!(error.contains("sunrise.svg (fallback sunset.svg not found)"));
+            assert!(error.contains("moon-full.svg (not provided and no fallback)"));
+            assert!(!error.contains(" wind.svg ("));
+        }
+
+        #[test]
+        fn malformed_manifest_is_an_error() {
+            let dir = tempfile::tempdir().unwrap();
+            std::fs::write(dir.path().join(MANIFEST_FILE_NAME), "icons = 3").unwrap();
+
+            let error = IconPack::load(dir.path()).unwrap_err();
+
+            assert!(error.contains(MANIFEST_FILE_NAME));
+        }
+    }
+}
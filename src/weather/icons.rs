@@ -1,9 +1,12 @@
 use std::path::Path;
 
 use chrono::NaiveDate;
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
-use crate::configs::settings::{DashboardSettings, RenderOptions};
+use crate::configs::settings::{ColourProfile, DashboardSettings, RenderOptions};
+use crate::constants::OUTLINED_ICONS_DIRECTORY;
+use crate::utils::root_tag_end;
+use crate::weather::icon_pack::IconPack;
 
 /// The subset of settings icon rendering actually depends on — narrower than
 /// `&DashboardSettings` so icon impls aren't coupled to config fields
@@ -11,7 +14,11 @@ use crate::configs::settings::{DashboardSettings, RenderOptions};
 #[derive(Clone, Copy)]
 pub struct IconContext<'a> {
     pub svg_icons_directory: &'a Path,
+    /// Maps the icon names below onto the files the pack provides.
+    pub icon_pack: &'a IconPack,
     pub render_options: &'a RenderOptions,
+    /// Drawn from first when set, see [`outlined_icon_pack`].
+    pub outlined_icon_pack: Option<&'a IconPack>,
     /// Resolved once from the injected `Clock`, so moon-phase selection
     /// stays deterministic under `FixedClock`.
     pub today: NaiveDate,
@@ -21,10 +28,39 @@ impl<'a> IconContext<'a> {
     pub fn from_settings(settings: &'a DashboardSettings, today: NaiveDate) -> Self {
         Self {
             svg_icons_directory: &settings.misc.svg_icons_directory,
+            icon_pack: &settings.misc.icon_pack,
             render_options: &settings.render_options,
+            outlined_icon_pack: outlined_icon_pack(settings),
             today,
         }
     }
+
+    /// Path of the file the icon pack draws for `icon_name`, from the
+    /// outlined pack when it's in use and has one.
+    pub fn path_for(&self, icon_name: &str) -> String {
+        if let Some(path) = self
+            .outlined_icon_pack
+            .and_then(|pack| pack.path_of(icon_name))
+        {
+            return path.to_string_lossy().to_string();
+        }
+        self.svg_icons_directory
+            .join(Path::new(self.icon_pack.resolve(icon_name)))
+            .to_string_lossy()
+            .to_string()
+    }
+}
+
+/// The outlined pack 2- and 3-colour panels draw icons from, or `None` for
+/// full colour. Its icons stay legible once recoloured in the panel's inks,
+/// where the filled ones have to be redrawn as outlines by
+/// [`panel_icon_filter`].
+pub fn outlined_icon_pack(settings: &DashboardSettings) -> Option<&IconPack> {
+    settings
+        .misc
+        .outlined_icon_pack
+        .as_ref()
+        .filter(|_| settings.display.profile.is_limited())
 }
 
 /// Shared fixture date for tests building an `IconContext`. Unused by most
@@ -36,7 +72,7 @@ pub(crate) fn placeholder_today() -> NaiveDate {
     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
 }
 
-#[derive(Debug, Display, Copy, Clone)]
+#[derive(Debug, Display, Copy, Clone, EnumIter)]
 pub enum PrecipitationChanceName {
     #[strum(to_string = "clear")]
     Clear,
@@ -70,7 +106,7 @@ pub enum DayNight {
     Night,
 }
 
-#[derive(Debug, Display)]
+#[derive(Debug, Display, EnumIter)]
 pub enum WindIconName {
     #[strum(to_string = "wind.svg")]
     Wind,
@@ -80,7 +116,7 @@ pub enum WindIconName {
     UmbrellaWindAlt,
 }
 
-#[derive(Debug, Display)]
+#[derive(Debug, Display, EnumIter)]
 pub enum HumidityIconName {
     #[strum(to_string = "humidity.svg")]
     Humidity,
@@ -90,7 +126,7 @@ pub enum HumidityIconName {
     HumidityPlusPlus,
 }
 
-#[derive(Debug, Display)]
+#[derive(Debug, Display, EnumIter)]
 pub enum SunPositionIconName {
     #[strum(to_string = "sunrise.svg")]
     Sunrise,
@@ -98,7 +134,7 @@ pub enum SunPositionIconName {
     Sunset,
 }
 
-#[derive(Debug, Display)]
+#[derive(Debug, Display, EnumIter)]
 pub enum NotAvailableIcon {
     #[strum(to_string = "not-available.svg")]
     NotAvailable,
@@ -110,7 +146,7 @@ pub enum HumidityIcon {
     Humidity,
 }
 
-#[derive(Debug, Display, Copy, Clone)]
+#[derive(Debug, Display, Copy, Clone, EnumIter)]
 pub enum UVIndexIcon {
     #[strum(to_string = "uv-index-none.svg")]
     None,
@@ -154,6 +190,27 @@ impl UVIndexIcon {
             Self::Extreme => "purple".to_string(),
         }
     }
+
+    /// [`Self::to_colour`] within a panel's inks: low UV blends into the
+    /// page, moderate is drawn in `ink`, and high and above in the accent
+    /// when the panel has one.
+    pub fn to_panel_colour(
+        self,
+        background_colour: &str,
+        ink: &str,
+        profile: ColourProfile,
+    ) -> String {
+        if !profile.is_limited() {
+            return self.to_colour(background_colour);
+        }
+        match self {
+            Self::None | Self::Low => background_colour.to_string(),
+            Self::Moderate => ink.to_string(),
+            Self::High | Self::VeryHigh | Self::Extreme => {
+                profile.accent().unwrap_or(ink).to_string()
+            }
+        }
+    }
 }
 
 impl From<u16> for HumidityIconName {
@@ -177,19 +234,18 @@ impl From<u16> for HumidityIconName {
 /// - `icon_path(&self) -> String`
 ///
 ///   Returns the full path to the icon as a `String`. The path is constructed
-///   by concatenating the `svg_icons_directory` from the icon context with the icon name.
+///   by concatenating the `svg_icons_directory` from the icon context with the
+///   icon name, or the icon pack's fallback for it.
 pub trait Icon {
     /// Returns the name of the icon
     fn icon_name(&self, ctx: &IconContext) -> String;
 
     /// Returns the path of the icon as a `String`.
     /// The path is constructed using the `svg_icons_directory` from the given
-    /// context and the icon name obtained from `icon_name`.
+    /// context and the icon name obtained from `icon_name`, resolved
+    /// through the context's icon pack.
     fn icon_path(&self, ctx: &IconContext) -> String {
-        ctx.svg_icons_directory
-            .join(Path::new(&self.icon_name(ctx)))
-            .to_string_lossy()
-            .to_string()
+        ctx.path_for(&self.icon_name(ctx))
     }
 }
 
@@ -211,6 +267,102 @@ impl Icon for UVIndexIcon {
     }
 }
 
+/// `id` of the filter [`apply_panel_icon_filter`] draws icons through.
+pub const PANEL_ICON_FILTER_ID: &str = "panelIcons";
+/// `id` of the filter [`apply_panel_icon_filter`] draws outlined icons
+/// through.
+pub const PANEL_LINE_ICON_FILTER_ID: &str = "panelLineIcons";
+
+/// An SVG filter adapting the colour icons to a limited panel, or `None`
+/// for full colour. Coloured areas are redrawn in `background` outlined in
+/// `ink`, except warm ones (sun, lightning) on a panel with an accent ink,
+/// which are filled with it. Greys then snap to paper or ink, so gradient
+/// clouds don't dither.
+pub fn panel_icon_filter(profile: ColourProfile, ink: &str, background: &str) -> Option<String> {
+    profile.inks()?;
+    // Channel-wise colourfulness: warm (R over B), blue (B over R) and
+    // green; purples from a second matrix. Each is 0 for greys.
+    let warm_in_outline = if profile.accent().is_some() { 0 } else { 1 };
+    let accent = profile.accent().map_or_else(String::new, |accent| {
+        format!(
+            r#"<feColorMatrix in="hues" type="matrix" values="0 0 0 0 0  0 0 0 0 0  0 0 0 0 0  1 0 0 0 0" result="warmRaw"/><feComposite in="warmRaw" in2="SourceAlpha" operator="in" result="warm"/><feFlood flood-color="{accent}"/><feComposite in2="warm" operator="in" result="accent"/>"#
+        )
+    });
+    let accent_node = if accent.is_empty() {
+        ""
+    } else {
+        r#"<feMergeNode in="accent"/>"#
+    };
+    Some(format!(
+        r#"<filter id="{PANEL_ICON_FILTER_ID}" color-interpolation-filters="sRGB"><feColorMatrix in="SourceGraphic" type="matrix" values="2 0 -2 0 0  -2 0 2 0 0  -1 2 -1 0 0  0 0 0 1 0" result="hues"/><feColorMatrix in="SourceGraphic" type="matrix" values="0 0 0 0 0  0 0 0 0 0  0 0 0 0 0  1 -2 1 0 0" result="purples"/><feColorMatrix in="hues" type="matrix" values="0 0 0 0 0  0 0 0 0 0  0 0 0 0 0  {warm_in_outline} 1 1 0 0" result="cool"/><feComposite in="cool" in2="purples" operator="arithmetic" k2="1" k3="1" result="colourful"/><feComposite in="colourful" in2="SourceAlpha" operator="in" result="mask"/><feMorphology in="mask" operator="dilate" radius="1.5" result="grown"/><feComposite in="grown" in2="mask" operator="out" result="ring"/><feFlood flood-color="{background}"/><feComposite in2="mask" operator="in" result="blank"/><feFlood flood-color="{ink}"/><feComposite in2="ring" operator="in" result="outline"/>{accent}<feMerge><feMergeNode in="SourceGraphic"/><feMergeNode in="blank"/><feMergeNode in="outline"/>{accent_node}</feMerge><feComponentTransfer><feFuncR type="discrete" tableValues="0 1 1"/><feFuncG type="discrete" tableValues="0 1 1"/><feFuncB type="discrete" tableValues="0 1 1"/></feComponentTransfer></filter>"#
+    ))
+}
+
+/// An SVG filter recolouring the outlined icons in a limited panel's inks,
+/// or `None` for full colour. Every line is drawn in `ink`, except warm ones
+/// (sun, lightning) on a panel with an accent ink, which are drawn in it.
+pub fn panel_line_icon_filter(profile: ColourProfile, ink: &str) -> Option<String> {
+    profile.inks()?;
+    let accent = profile.accent().map_or_else(String::new, |accent| {
+        format!(
+            r#"<feColorMatrix in="SourceGraphic" type="matrix" values="0 0 0 0 0  0 0 0 0 0  0 0 0 0 0  2 0 -2 0 0" result="warmRaw"/><feComposite in="warmRaw" in2="SourceAlpha" operator="in" result="warm"/><feFlood flood-color="{accent}"/><feComposite in2="warm" operator="in" result="accent"/><feMerge><feMergeNode in="lines"/><feMergeNode in="accent"/></feMerge>"#
+        )
+    });
+    Some(format!(
+        r#"<filter id="{PANEL_LINE_ICON_FILTER_ID}" color-interpolation-filters="sRGB"><feFlood flood-color="{ink}"/><feComposite in2="SourceAlpha" operator="in" result="lines"/>{accent}</filter>"#
+    ))
+}
+
+/// Draws every `<image>` in a rendered page through [`panel_icon_filter`],
+/// or [`panel_line_icon_filter`] for icons from
+/// [`OUTLINED_ICONS_DIRECTORY`], defining both just inside the root `<svg>`.
+/// Images that set their own `filter` keep it. Returns the page unchanged
+/// for full colour.
+pub fn apply_panel_icon_filter(
+    svg: String,
+    profile: ColourProfile,
+    ink: &str,
+    background: &str,
+) -> String {
+    let (Some(filter), Some(line_filter)) = (
+        panel_icon_filter(profile, ink, background),
+        panel_line_icon_filter(profile, ink),
+    ) else {
+        return svg;
+    };
+    let Some(root_end) = root_tag_end(&svg) else {
+        return svg;
+    };
+
+    let mut out = String::with_capacity(svg.len() + filter.len() + 64);
+    out.push_str(&svg[..root_end]);
+    out.push_str(&format!("<defs>{filter}{line_filter}</defs>"));
+    let mut rest = &svg[root_end..];
+    while let Some(start) = rest.find("<image") {
+        let tag_end = rest[start..]
+            .find('>')
+            .map_or(rest.len(), |end| start + end);
+        out.push_str(&rest[..start]);
+        let tag = &rest[start..tag_end];
+        if tag.contains("filter=") {
+            out.push_str(tag);
+        } else {
+            let id = if tag.contains(OUTLINED_ICONS_DIRECTORY) {
+                PANEL_LINE_ICON_FILTER_ID
+            } else {
+                PANEL_ICON_FILTER_ID
+            };
+            out.push_str(&format!(
+                r#"<image filter="url(#{id})"{}"#,
+                &tag["<image".len()..]
+            ));
+        }
+        rest = &rest[tag_end..];
+    }
+    out.push_str(rest);
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,6 +441,105 @@ mod tests {
         assert_eq!(UVIndexIcon::Extreme.to_colour("bg"), "purple");
     }
 
+    #[test]
+    fn uv_colours_stay_within_the_panel_inks() {
+        let colour = |uv, profile| UVIndexIcon::from(uv).to_panel_colour("white", "black", profile);
+        assert_eq!(colour(11, ColourProfile::FullColour), "purple");
+        assert_eq!(colour(2, ColourProfile::BlackWhiteRed), "white");
+        assert_eq!(colour(4, ColourProfile::BlackWhiteRed), "black");
+        assert_eq!(colour(7, ColourProfile::BlackWhiteRed), "red");
+        assert_eq!(colour(7, ColourProfile::BlackWhite), "black");
+    }
+
+    mod panel_icon_filter {
+        use super::*;
+
+        const PAGE: &str = r#"<svg width="800" height="480"><image href="a.svg"/><g><image x="1" href="b.svg" filter="none"/></g></svg>"#;
+
+        #[test]
+        fn full_colour_pages_are_left_alone() {
+            let svg = apply_panel_icon_filter(
+                PAGE.to_string(),
+                ColourProfile::FullColour,
+                "black",
+                "white",
+            );
+            assert_eq!(svg, PAGE);
+        }
+
+        #[test]
+        fn images_are_drawn_through_the_filter() {
+            let svg = apply_panel_icon_filter(
+                PAGE.to_string(),
+                ColourProfile::BlackWhite,
+                "black",
+                "white",
+            );
+            assert!(
+                svg.starts_with(r#"<svg width="800" height="480"><defs><filter id="panelIcons""#),
+                "{svg}"
+            );
+            assert!(svg.contains(r#"<image filter="url(#panelIcons)" href="a.svg"/>"#));
+            // Images with their own filter keep it.
+            assert!(svg.contains(r#"<image x="1" href="b.svg" filter="none"/>"#));
+            assert!(!svg.contains("flood-color=\"red\""));
+        }
+
+        #[test]
+        fn warm_colours_use_the_accent_ink() {
+            let filter = panel_icon_filter(ColourProfile::BlackWhiteRed, "black", "white").unwrap();
+            assert!(filter.contains(r#"flood-color="red""#));
+            assert!(filter.contains(r#"<feMergeNode in="accent"/>"#));
+            let line_filter =
+                panel_line_icon_filter(ColourProfile::BlackWhiteRed, "black").unwrap();
+            assert!(line_filter.contains(r#"flood-color="red""#));
+        }
+
+        #[test]
+        fn outlined_icons_are_only_recoloured() {
+            let page = r#"<svg><image href="static/line-svg-static/rain.svg"/></svg>"#;
+            let svg = apply_panel_icon_filter(
+                page.to_string(),
+                ColourProfile::BlackWhite,
+                "black",
+                "white",
+            );
+            assert!(svg.contains(
+                r#"<image filter="url(#panelLineIcons)" href="static/line-svg-static/rain.svg"/>"#
+            ));
+        }
+    }
+
+    mod outlined_icons {
+        use super::*;
+
+        fn path(profile: ColourProfile, icon_name: &str) -> String {
+            let mut settings = DashboardSettings::load_test_config().unwrap();
+            settings.display.profile = profile;
+            IconContext::from_settings(&settings, placeholder_today()).path_for(icon_name)
+        }
+
+        #[test]
+        fn limited_panels_draw_from_the_outlined_pack() {
+            assert_eq!(
+                path(ColourProfile::BlackWhiteRed, "rain.svg"),
+                "static/line-svg-static/rain.svg"
+            );
+        }
+
+        #[test]
+        fn full_colour_keeps_the_configured_pack() {
+            assert!(!path(ColourProfile::FullColour, "rain.svg").contains("line-svg-static"));
+        }
+
+        #[test]
+        fn icons_the_outlined_pack_lacks_keep_the_configured_pack() {
+            assert!(
+                !path(ColourProfile::BlackWhite, "no-such-icon.svg").contains("line-svg-static")
+            );
+        }
+    }
+
     mod humidity_icon_name_boundaries {
         use super::*;
 
@@ -335,4 +586,19 @@ mod tests {
         assert!(path.ends_with("sunrise.svg"));
         assert!(path.starts_with(&ctx.svg_icons_directory.to_string_lossy().to_string()));
     }
+
+    #[test]
+    fn icon_path_uses_the_pack_fallback_for_a_missing_icon() {
+        let mut settings = DashboardSettings::load_test_config().unwrap();
+        settings.misc.svg_icons_directory = "static/line-svg-static".into();
+        settings.misc.icon_pack = IconPack::load(&settings.misc.svg_icons_directory).unwrap();
+        let ctx = IconContext::from_settings(&settings, placeholder_today());
+
+        assert!(HumidityIconName::HumidityPlusPlus
+            .icon_path(&ctx)
+            .ends_with("line-svg-static/humidity.svg"));
+        assert!(SunPositionIconName::Sunset
+            .icon_path(&ctx)
+            .ends_with("line-svg-static/sunset.svg"));
+    }
 }
@@ -1,2 +1,3 @@
+pub mod icon_pack;
 pub mod icons;
 pub mod utils;
@@ -1,8 +1,8 @@
 use chrono::Datelike;
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
 // Determine the moon phase icon based on the moon age
-#[derive(Debug, Display)]
+#[derive(Debug, Display, EnumIter)]
 pub enum MoonPhaseIconName {
     #[strum(to_string = "moon-new.svg")]
     New,
@@ -1,96 +1,16 @@
 use crate::clock::{Clock, SystemClock};
 use crate::configs::settings::DashboardSettings;
-use crate::dashboard::context::{Context, ContextBuilder};
-use crate::errors::{DashboardError, Description};
+use crate::dashboard::error_screen;
+use crate::dashboard::renderer::{DashboardRenderer, PNG_SCALE_FACTOR};
 use crate::logger;
-use crate::providers::factory::create_provider;
-use crate::update::read_last_update_status;
+use crate::output;
+use crate::providers::{local::LocalFileProvider, WeatherProvider};
+use crate::refresh::{self, RenderOutcome};
 use crate::utils;
 use anyhow::Error;
-use std::fs;
-use std::io::Write;
 use std::path::Path;
-use tinytemplate::{format_unescaped, TinyTemplate};
 pub use utils::*;
 
-fn update_forecast_context(
-    settings: &DashboardSettings,
-    context_builder: &mut ContextBuilder,
-    clock: &dyn Clock,
-) -> Result<(), Error> {
-    let provider = create_provider(settings)?;
-    let mut warnings: Vec<DashboardError> = Vec::new();
-
-    // Check if the last update failed and add warning if so
-    if let Some(error_details) = read_last_update_status() {
-        warnings.push(DashboardError::UpdateFailed {
-            details: error_details,
-        });
-    }
-
-    logger::subsection(format!("Using provider: {}", provider.provider_name()));
-
-    logger::subsection("Fetching daily forecast");
-    let daily_result = provider.fetch_daily_forecast(settings)?;
-    if let Some(warning) = daily_result.warning {
-        logger::warning(format!(
-            "Using cached data due to: {}",
-            warning.long_description()
-        ));
-        warnings.push(warning);
-    } else {
-        logger::success("Daily forecast retrieved");
-    }
-    context_builder.with_daily_forecast_data(daily_result.data, clock);
-
-    logger::subsection("Fetching hourly forecast");
-    let hourly_result = provider.fetch_hourly_forecast(settings)?;
-    if let Some(warning) = hourly_result.warning {
-        logger::warning(format!(
-            "Using cached data due to: {}",
-            warning.long_description()
-        ));
-        warnings.push(warning);
-    } else {
-        logger::success("Hourly forecast retrieved");
-    }
-    context_builder.with_hourly_forecast_data(hourly_result.data, clock);
-
-    // Add all accumulated warnings to the context
-    for warning in warnings {
-        context_builder.with_warning(warning);
-    }
-
-    Ok(())
-}
-
-fn render_dashboard_template(
-    context: &Context,
-    dashboard_svg: String,
-    output_svg_name: &Path,
-) -> Result<(), Error> {
-    let mut tt = TinyTemplate::new();
-    let tt_name = "dashboard";
-
-    if let Err(e) = tt.add_template(tt_name, &dashboard_svg) {
-        logger::error(format!("Failed to add template: {e}"));
-        return Err(e.into());
-    }
-    tt.set_default_formatter(&format_unescaped);
-    // Attempt to render the template
-    match tt.render(tt_name, &context) {
-        Ok(rendered) => {
-            let mut output = fs::File::create(output_svg_name)?;
-            output.write_all(rendered.as_bytes())?;
-            Ok(())
-        }
-        Err(e) => {
-            logger::error(format!("Failed to render template: {e}"));
-            Err(e.into())
-        }
-    }
-}
-
 /// Render an existing SVG file to PNG without fetching weather data or re-rendering the template.
 ///
 /// Converts the given SVG file directly to PNG using the configured output path.
@@ -116,7 +36,7 @@ pub fn render_svg_to_png(settings: &DashboardSettings, svg_path: &Path) -> Resul
     convert_svg_to_png(
         &svg_path.to_path_buf(),
         &settings.misc.generated_png_name,
-        2.0,
+        PNG_SCALE_FACTOR,
     )?;
 
     logger::success(format!(
@@ -126,11 +46,22 @@ pub fn render_svg_to_png(settings: &DashboardSettings, svg_path: &Path) -> Resul
             .display()
     ));
 
+    write_extra_outputs(settings, svg_path)?;
+
     Ok(())
 }
 
+/// Writes the `[output] targets`, if any, from the generated SVG.
+fn write_extra_outputs(settings: &DashboardSettings, svg_path: &Path) -> Result<(), Error> {
+    if settings.output.targets.is_empty() {
+        return Ok(());
+    }
+    logger::subsection("Writing extra outputs");
+    output::write_outputs(svg_path, &settings.output.targets)
+}
+
 /// Generate weather dashboard using the system clock (production)
-pub fn generate_weather_dashboard(settings: &DashboardSettings) -> Result<(), Error> {
+pub fn generate_weather_dashboard(settings: &DashboardSettings) -> Result<RenderOutcome, Error> {
     let clock = SystemClock;
     let output_svg_name = &settings.misc.generated_svg_name;
     generate_weather_dashboard_injection(settings, &clock, output_svg_name)
@@ -140,7 +71,10 @@ pub fn generate_weather_dashboard(settings: &DashboardSettings) -> Result<(), Er
 ///
 /// This function allows dependency injection of a Clock implementation and a
 /// custom output path, enabling deterministic testing with FixedClock. The
-/// input template is always read from `settings.misc.template_path`.
+/// input template is read from `settings.misc.template_path`, or from
+/// `settings.misc.outlook_template_path` when `render_options.view` selects
+/// the 14-day outlook for the clock's local hour. Rendering itself is done by
+/// [`DashboardRenderer`]; this writes its output to disk.
 ///
 /// # Arguments
 ///
@@ -149,7 +83,7 @@ pub fn generate_weather_dashboard(settings: &DashboardSettings) -> Result<(), Er
 ///
 /// # Examples
 ///
-/// `no_run`: this reads `settings.misc.template_path` from disk and fetches
+/// `no_run`: this reads the template from disk and fetches
 /// live weather data, so the example is compiled and type-checked by
 /// `cargo test` but not executed.
 ///
@@ -170,31 +104,105 @@ pub fn generate_weather_dashboard_injection(
     settings: &DashboardSettings,
     clock: &dyn Clock,
     output_svg_name: &Path,
-) -> Result<(), Error> {
+) -> Result<RenderOutcome, Error> {
     logger::init(settings.dev.enable_debug_logs, settings.misc.timezone);
-    let current_dir = std::env::current_dir()?;
-    let mut context_builder = ContextBuilder::new(settings, clock);
-    let input_template_name = &settings.misc.template_path;
+    write_dashboard(
+        settings,
+        clock,
+        DashboardRenderer::new(settings, clock).render_svg_and_fetch_time_label(),
+        output_svg_name,
+    )
+}
+
+/// Generate the weather dashboard from forecast files instead of the
+/// configured provider.
+///
+/// Each file holds a BOM or Open-Meteo API response or domain JSON (see
+/// [`crate::providers::local`]). Nothing is fetched or cached; the output is
+/// written to the configured paths as usual.
+pub fn generate_weather_dashboard_from_files(
+    settings: &DashboardSettings,
+    clock: &dyn Clock,
+    hourly_path: &Path,
+    daily_path: &Path,
+) -> Result<RenderOutcome, Error> {
+    logger::init(settings.dev.enable_debug_logs, settings.misc.timezone);
+    let provider = LocalFileProvider::new(hourly_path.to_path_buf(), daily_path.to_path_buf());
+    logger::subsection(format!("Using provider: {}", provider.provider_name()));
+    let rendered = render_from_files(settings, clock, &provider);
+
+    write_dashboard(settings, clock, rendered, &settings.misc.generated_svg_name)
+}
+
+/// Reads the forecast files through `provider` and renders them.
+fn render_from_files(
+    settings: &DashboardSettings,
+    clock: &dyn Clock,
+    provider: &LocalFileProvider,
+) -> Result<(String, String), Error> {
+    let hourly = provider.fetch_hourly_forecast(settings, clock)?.data;
+    let daily = provider.fetch_daily_forecast(settings, clock)?.data;
+    DashboardRenderer::new(settings, clock)
+        .with_forecast(hourly, daily)
+        .render_svg_and_fetch_time_label()
+}
 
-    let template_svg = match fs::read_to_string(input_template_name) {
-        Ok(svg) => svg,
+/// Writes the error screen for `error` to the configured outputs, for
+/// failures that happen before there's anything to render (e.g. a config
+/// that doesn't validate).
+pub fn write_error_screen(
+    settings: &DashboardSettings,
+    clock: &dyn Clock,
+    error: Error,
+) -> Result<RenderOutcome, Error> {
+    logger::init(settings.dev.enable_debug_logs, settings.misc.timezone);
+    write_dashboard(
+        settings,
+        clock,
+        Err(error),
+        &settings.misc.generated_svg_name,
+    )
+}
+
+/// Writes the `rendered` SVG, the PNG and any extra outputs, unless
+/// `[refresh]` finds the render unchanged since the last write. If fetching
+/// or rendering failed, the error screen is written instead so the panel
+/// doesn't keep showing an old forecast.
+fn write_dashboard(
+    settings: &DashboardSettings,
+    clock: &dyn Clock,
+    rendered: Result<(String, String), Error>,
+    output_svg_name: &Path,
+) -> Result<RenderOutcome, Error> {
+    let current_dir = std::env::current_dir()?;
+    let (svg, fetch_time_label, outcome) = match rendered {
+        Ok((svg, fetch_time_label)) => (svg, fetch_time_label, RenderOutcome::Written),
         Err(e) => {
-            logger::error(format!("Failed to read template file: {e}"));
-            logger::detail(format!("Current directory: {}", current_dir.display()));
-            logger::detail(format!("Template path: {}", &input_template_name.display()));
-            return Err(e.into());
+            logger::error(format!("Dashboard generation failed: {e:#}"));
+            logger::warning("Writing the error screen instead");
+            (
+                error_screen::render(settings, &e),
+                String::new(),
+                RenderOutcome::ErrorScreen,
+            )
         }
     };
 
-    update_forecast_context(settings, &mut context_builder, clock)?;
+    let hash = refresh::render_hash(&svg, &fetch_time_label);
+    match refresh::write_reason(settings, clock, hash, output_svg_name) {
+        None => {
+            refresh::record_unchanged(settings);
+            logger::success("Dashboard unchanged since the last refresh; outputs left as they are");
+            return Ok(RenderOutcome::Unchanged);
+        }
+        Some(reason) => logger::debug(format!("Writing outputs: {reason}")),
+    }
 
-    logger::subsection("Rendering dashboard to SVG");
     // Ensure the parent directory for the output SVG exists
     if let Some(parent) = output_svg_name.parent() {
         std::fs::create_dir_all(parent)?;
     }
-
-    render_dashboard_template(&context_builder.context, template_svg, output_svg_name)?;
+    write_atomic(output_svg_name, &svg)?;
     logger::success(format!(
         "SVG saved: {}",
         current_dir.join(output_svg_name).display()
@@ -207,10 +215,9 @@ pub fn generate_weather_dashboard_injection(
             std::fs::create_dir_all(png_parent)?;
         }
 
-        convert_svg_to_png(
-            &output_svg_name.to_path_buf(),
+        write_atomic(
             &settings.misc.generated_png_name,
-            2.0,
+            svg_to_png(&svg, PNG_SCALE_FACTOR)?,
         )?;
 
         logger::success(format!(
@@ -220,5 +227,8 @@ pub fn generate_weather_dashboard_injection(
                 .display()
         ));
     }
-    Ok(())
+
+    write_extra_outputs(settings, output_svg_name)?;
+    refresh::record_written(settings, clock, hash);
+    Ok(outcome)
 }
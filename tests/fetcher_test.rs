@@ -98,12 +98,17 @@ async fn test_retry_succeeds_on_third_attempt() {
                     data.value
                 );
             }
-            pi_inky_weather_epd::providers::fetcher::FetchOutcome::Stale { data, error } => {
+            pi_inky_weather_epd::providers::fetcher::FetchOutcome::Stale {
+                data, error, ..
+            } => {
                 panic!(
                     "Expected Fresh data, got Stale: {:?}, error: {:?}",
                     data, error
                 );
             }
+            pi_inky_weather_epd::providers::fetcher::FetchOutcome::Cached { data, .. } => {
+                panic!("Expected Fresh data, got Cached: {:?}", data);
+            }
         },
         Err(e) => panic!("Expected success, got error: {}", e),
     }
@@ -198,13 +203,18 @@ async fn test_body_level_transient_error_is_retried_and_recovers() {
         Ok(pi_inky_weather_epd::providers::fetcher::FetchOutcome::Fresh(data)) => {
             assert_eq!(data.value, "success");
         }
-        Ok(pi_inky_weather_epd::providers::fetcher::FetchOutcome::Stale { data, error }) => {
+        Ok(pi_inky_weather_epd::providers::fetcher::FetchOutcome::Stale {
+            data, error, ..
+        }) => {
             panic!(
                 "Expected Fresh data after recovering from a transient body-level error, \
                  got Stale: {:?}, error: {:?}",
                 data, error
             );
         }
+        Ok(pi_inky_weather_epd::providers::fetcher::FetchOutcome::Cached { data, .. }) => {
+            panic!("Expected Fresh data, got Cached: {:?}", data);
+        }
         Err(e) => panic!("Expected success, got error: {}", e),
     }
 
@@ -225,6 +235,7 @@ async fn test_body_level_client_error_is_not_retried() {
     // invalid parameter with HTTP 400) indicates a problem with the request itself.
     // Retrying it will fail identically every time, so it should fall back to cached
     // data after a single attempt instead of burning the retry budget.
+    let cached_at = std::time::UNIX_EPOCH + Duration::from_secs(1_761_386_400);
     let mock_server = MockServer::start().await;
 
     Mock::given(wiremock::matchers::method("GET"))
@@ -253,6 +264,12 @@ async fn test_body_level_client_error_is_not_retried() {
             .unwrap(),
         )
         .expect("Failed to write cache file");
+        // The fallback reports the cache file's age
+        std::fs::File::options()
+            .write(true)
+            .open(&cache_file)
+            .and_then(|file| file.set_modified(cached_at))
+            .expect("Failed to set the cache file's modification time");
 
         const RETRY_DELAYS: &[Duration; 5] = &[
             Duration::from_secs(1),
@@ -279,8 +296,17 @@ async fn test_body_level_client_error_is_not_retried() {
     .expect("Task panicked");
 
     match result {
-        Ok(pi_inky_weather_epd::providers::fetcher::FetchOutcome::Stale { data, error }) => {
+        Ok(pi_inky_weather_epd::providers::fetcher::FetchOutcome::Stale {
+            data,
+            error,
+            updated_at,
+        }) => {
             assert_eq!(data.value, "cached");
+            assert_eq!(
+                std::time::SystemTime::from(updated_at),
+                cached_at,
+                "Expected the cache file's modification time"
+            );
             match error {
                 DashboardError::ApiError { details } => {
                     assert!(details.contains("Invalid parameter"));
@@ -288,7 +314,10 @@ async fn test_body_level_client_error_is_not_retried() {
                 _ => panic!("Expected ApiError, got {:?}", error),
             }
         }
-        Ok(pi_inky_weather_epd::providers::fetcher::FetchOutcome::Fresh(_)) => {
+        Ok(
+            pi_inky_weather_epd::providers::fetcher::FetchOutcome::Fresh(_)
+            | pi_inky_weather_epd::providers::fetcher::FetchOutcome::Cached { .. },
+        ) => {
             panic!("Expected Stale fallback for a permanent client error");
         }
         Err(e) => panic!("Expected fallback to cache, got error: {}", e),
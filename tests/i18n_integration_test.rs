@@ -129,6 +129,22 @@ async fn japanese_language_override_localizes_rendered_dashboard() {
     assert!(!svg_content.contains("pm</text>"));
 }
 
+/// The forecast summary is only drawn in Japanese when the bundled Japanese
+/// font has every character of it, and falls back to English otherwise.
+#[tokio::test]
+#[ignore = "needs static/fonts/NotoSansJP-Weather-Regular.ttf regenerated with scripts/subset-japanese-font.sh"]
+async fn japanese_forecast_summary_reaches_the_dashboard() {
+    let svg_content = render_dashboard_svg(
+        Language::Ja,
+        None,
+        Path::new("tests/output/japanese_summary_dashboard.svg"),
+    )
+    .await;
+
+    assert!(svg_content.contains("夜間から雨"), "no Japanese summary");
+    assert!(!svg_content.contains("Rain developing"));
+}
+
 #[tokio::test]
 async fn hour_format_override_forces_twelve_hour_regardless_of_language() {
     let svg_content = render_dashboard_svg(
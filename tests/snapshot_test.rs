@@ -6,6 +6,10 @@ mod helpers;
 
 use helpers::test_utils;
 use helpers::wiremock_setup;
+use pi_inky_weather_epd::configs::settings::{
+    ColourProfile, DailyLayout, DashboardView, DisplayModel, NightShading, PrecipitationMode,
+    TempAxisScale, WidgetPlacement,
+};
 use pi_inky_weather_epd::i18n::Language;
 use pi_inky_weather_epd::{clock::FixedClock, generate_weather_dashboard_injection};
 use std::fs;
@@ -871,3 +875,1273 @@ mod localization {
         insta::assert_snapshot!(svg_content);
     }
 }
+
+/// Optional chart series snapshot tests
+///
+/// Render options that add layers to the hourly chart are off by default, so
+/// the provider snapshots above never exercise them.
+mod chart_series {
+    use super::*;
+
+    /// BOM fixture with `show_wind_series` enabled — BOM supplies a compass
+    /// direction for every hour, so both the dotted speed curve and its
+    /// rotated direction arrows appear.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_wind_series() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.render_options.show_wind_series = true;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+
+        let output_svg_name = Path::new("tests/output/snapshot_bom_wind_series.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert!(svg_content.contains("stroke-dasharray=\"1,6\""));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// BOM fixture with hatched night shading — the daily forecast's
+    /// sunrise/sunset times place the band, with a moon at sunset and a sun
+    /// at sunrise.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_night_shading() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.render_options.night_shading = NightShading::Hatched;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+
+        let output_svg_name = Path::new("tests/output/snapshot_bom_night_shading.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert!(svg_content.contains("<pattern id=\"nightShading\""));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// Open-Meteo fixture with the cloud-cover strip enabled — Open-Meteo
+    /// reports hourly cloud cover, drawn as stippled cells above the UV bar.
+    ///
+    /// **Fixed Time**: same as `provider::open_meteo_dashboard`.
+    #[tokio::test]
+    async fn open_meteo_cloud_cover() {
+        let mock_server = wiremock_setup::setup_open_meteo_mock(
+            "tests/fixtures/open_meteo_hourly_forecast.json",
+            "tests/fixtures/open_meteo_daily_forecast.json",
+        )
+        .await;
+
+        let mut settings = test_utils::open_meteo_settings(&mock_server.uri());
+        settings.render_options.show_cloud_cover = true;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T01:00:00Z").expect("Failed to create fixed clock");
+
+        let output_svg_name = Path::new("tests/output/snapshot_open_meteo_cloud_cover.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert!(svg_content.contains("<pattern id=\"cloudCover1\""));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// Open-Meteo fixture in `precipitation_mode = "both"` — hourly amounts
+    /// drawn as bars against a millimetre right axis, faded by chance.
+    ///
+    /// **Fixed Time**: same as `provider::open_meteo_dashboard`.
+    #[tokio::test]
+    async fn open_meteo_precipitation_amount() {
+        let mock_server = wiremock_setup::setup_open_meteo_mock(
+            "tests/fixtures/open_meteo_hourly_forecast.json",
+            "tests/fixtures/open_meteo_daily_forecast.json",
+        )
+        .await;
+
+        let mut settings = test_utils::open_meteo_settings(&mock_server.uri());
+        settings.render_options.precipitation_mode = PrecipitationMode::Both;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T01:00:00Z").expect("Failed to create fixed clock");
+
+        let output_svg_name =
+            Path::new("tests/output/snapshot_open_meteo_precipitation_amount.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert!(svg_content.contains("mm</text>"));
+        assert!(!svg_content.contains(">100%</text>"));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// New York fixture (-7° to 7°) with `temp_axis_scale = "nice"` and the
+    /// freezing line on — the axis rounds out to whole steps and the 0°
+    /// guideline is drawn across the chart.
+    ///
+    /// **Fixed Time**: same as `provider::open_meteo_ny_7pm_after_gmt_boundary`.
+    #[tokio::test]
+    async fn open_meteo_nice_axis_freezing_line() {
+        let mock_server = wiremock_setup::setup_open_meteo_mock(
+            "tests/fixtures/ny_7pm_after_gmt/open_meteo_hourly_forecast.json",
+            "tests/fixtures/ny_7pm_after_gmt/open_meteo_daily_forecast.json",
+        )
+        .await;
+        let mut settings =
+            test_utils::open_meteo_settings_in_tz(&mock_server.uri(), chrono_tz::America::New_York);
+        settings.render_options.temp_axis_scale = TempAxisScale::Nice;
+        settings.render_options.show_freezing_line = true;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-12-29T00:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name =
+            Path::new("tests/output/snapshot_open_meteo_nice_axis_freezing_line.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert!(svg_content.contains(r#"stroke-dasharray="10,4""#));
+        assert!(svg_content.contains(">0°</text>"));
+        insta::assert_snapshot!(svg_content);
+    }
+}
+
+mod daily_strip {
+    use super::*;
+
+    /// BOM fixture with `daily_layout = "range_bars"` — each day card swaps
+    /// its max | min numbers for a bar on the week's shared scale, with
+    /// rain chance and amount underneath.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_range_bars() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.render_options.daily_layout = DailyLayout::RangeBars;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+
+        let output_svg_name = Path::new("tests/output/snapshot_bom_range_bars.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert_eq!(svg_content.matches(r#"y="142" width="64""#).count(), 6);
+        assert!(svg_content.contains(r#"visibility="hidden">"#));
+        insta::assert_snapshot!(svg_content);
+    }
+}
+
+mod outlook {
+    use super::*;
+
+    /// Open-Meteo fixture with 14 days of daily data and `view = "outlook"`
+    /// — every card is filled and the trend spans the whole fortnight.
+    ///
+    /// **Fixed Time**: same as `provider::open_meteo_ny_6pm_before_gmt_boundary`.
+    #[tokio::test]
+    async fn open_meteo_fourteen_days() {
+        let mock_server = wiremock_setup::setup_open_meteo_mock(
+            "tests/fixtures/ny_6pm_before_gmt/open_meteo_hourly_forecast.json",
+            "tests/fixtures/ny_6pm_before_gmt/open_meteo_daily_forecast.json",
+        )
+        .await;
+        let mut settings =
+            test_utils::open_meteo_settings_in_tz(&mock_server.uri(), chrono_tz::America::New_York);
+        settings.render_options.view = DashboardView::Outlook;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-12-28T23:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name = Path::new("tests/output/snapshot_open_meteo_outlook.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert_eq!(
+            svg_content.matches(r#"width="112" height="145""#).count(),
+            14
+        );
+        assert!(!svg_content.contains(">N/A</text>"));
+        assert_eq!(svg_content.matches("<polyline").count(), 2);
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// BOM fixture with `view = "scheduled"` at 9pm local, inside the
+    /// default 20:00–06:00 window — BOM only forecasts about a week, so the
+    /// trailing cards degrade to "N/A".
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_scheduled_partial_fortnight() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.render_options.view = DashboardView::Scheduled;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name = Path::new("tests/output/snapshot_bom_outlook.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert_eq!(
+            svg_content.matches(r#"width="112" height="145""#).count(),
+            14
+        );
+        assert!(svg_content.contains(">N/A</text>"));
+        insta::assert_snapshot!(svg_content);
+    }
+}
+
+mod template_context {
+    use super::*;
+
+    /// A template that loops over `days` and `hours` with TinyTemplate's
+    /// `{{ for }}`, next to the flat `day2_*` fields existing templates use.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_iterable_days_and_hours() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.misc.template_path = "tests/fixtures/templates/iterable_context.svg".into();
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name = Path::new("tests/output/snapshot_bom_iterable_context.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert_eq!(svg_content.matches(r#"<tspan x="20" dy="22">"#).count(), 7);
+        assert_eq!(
+            svg_content.matches(r#"<tspan x="420" dy="15">"#).count(),
+            24
+        );
+        assert!(svg_content.contains("Sat 2025-10-25"), "{svg_content}");
+        assert!(svg_content.contains(" today</tspan>"));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// A template that formats `values` with the registered unit formatters
+    /// and branches on its booleans.
+    ///
+    /// **Fixed Time**: same as `precipitation::open_meteo_alaska_snow`, where
+    /// every chart hour is primarily snow.
+    #[tokio::test]
+    async fn open_meteo_typed_values_and_formatters() {
+        let mock_server = wiremock_setup::setup_open_meteo_mock(
+            "tests/fixtures/alaska_snow/open_meteo_hourly_forecast.json",
+            "tests/fixtures/alaska_snow/open_meteo_daily_forecast.json",
+        )
+        .await;
+        let mut settings = test_utils::open_meteo_settings_in_tz(
+            &mock_server.uri(),
+            chrono_tz::America::Anchorage,
+        );
+        settings.misc.template_path = "tests/fixtures/templates/typed_values.svg".into();
+
+        let clock =
+            FixedClock::from_rfc3339("2026-01-15T21:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name = Path::new("tests/output/snapshot_open_meteo_typed_values.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert!(
+            svg_content.contains(">Snow expected</text>"),
+            "{svg_content}"
+        );
+        assert!(svg_content.contains(">Freezing</text>"), "{svg_content}");
+        assert!(!svg_content.contains("{values."));
+        insta::assert_snapshot!(svg_content);
+    }
+}
+
+mod layout {
+    use super::*;
+
+    fn placement(widget: &str, x: f32, y: f32, size: Option<(f32, f32)>) -> WidgetPlacement {
+        WidgetPlacement {
+            widget: widget.to_string(),
+            x,
+            y,
+            width: size.map(|(w, _)| w),
+            height: size.map(|(_, h)| h),
+        }
+    }
+
+    async fn render_bom_layout(widgets: Vec<WidgetPlacement>, output: &'static str) -> String {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.layout.widgets = widgets;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name = Path::new(output);
+
+        tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked")
+    }
+
+    /// The built-in widgets at their classic positions, which should look
+    /// the same as `provider::bom_dashboard`.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_classic_widget_layout() {
+        let svg_content = render_bom_layout(
+            vec![
+                placement("current_conditions", 0.0, 0.0, None),
+                placement("daily_strip", 220.0, 0.0, None),
+                placement("hourly_chart", 220.0, 160.0, None),
+                placement("diagnostics", 580.0, 0.0, None),
+            ],
+            "tests/output/snapshot_bom_classic_widget_layout.svg",
+        )
+        .await;
+
+        assert!(svg_content
+            .contains(r#"<svg x="220" y="160" width="580" height="320" viewBox="0 0 580 320""#));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// A rearranged layout: the daily strip across the top and the chart
+    /// shrunk into the bottom right, without the current conditions.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_rearranged_widget_layout() {
+        let svg_content = render_bom_layout(
+            vec![
+                placement("daily_strip", 0.0, 0.0, Some((800.0, 262.0))),
+                placement("hourly_chart", 400.0, 260.0, Some((400.0, 220.0))),
+                placement("diagnostics", 0.0, 380.0, None),
+            ],
+            "tests/output/snapshot_bom_rearranged_widget_layout.svg",
+        )
+        .await;
+
+        assert!(!svg_content.contains("{current_hour_weather_icon}"));
+        assert!(!svg_content.contains("UV-index"));
+        assert!(svg_content.contains(r#"width="400" height="220" viewBox="0 0 580 320""#));
+        insta::assert_snapshot!(svg_content);
+    }
+}
+
+mod display_model {
+    use super::*;
+
+    async fn render_bom(model: DisplayModel, view: DashboardView, output: &'static str) -> String {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.display.model = model;
+        settings.render_options.view = view;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name = Path::new(output);
+
+        tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked")
+    }
+
+    /// Inky Impression 5.7" (600×448): the classic arrangement scaled down,
+    /// with the chart taking the extra height.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_impression_5_7() {
+        let svg_content = render_bom(
+            DisplayModel::Impression57,
+            DashboardView::Today,
+            "tests/output/snapshot_bom_impression_5_7.svg",
+        )
+        .await;
+        assert!(svg_content.starts_with(r#"<svg width="600" height="448""#));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// Inky Impression 4" (640×400).
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_impression_4() {
+        let svg_content = render_bom(
+            DisplayModel::Impression4,
+            DashboardView::Today,
+            "tests/output/snapshot_bom_impression_4.svg",
+        )
+        .await;
+        assert!(svg_content.starts_with(r#"<svg width="640" height="400""#));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// Inky Impression 13.3" (1600×1200): larger text from the bigger view
+    /// box scale, and a taller chart.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_impression_13_3() {
+        let svg_content = render_bom(
+            DisplayModel::Impression133,
+            DashboardView::Today,
+            "tests/output/snapshot_bom_impression_13_3.svg",
+        )
+        .await;
+        assert!(svg_content.starts_with(r#"<svg width="1600" height="1200""#));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// 7.3" portrait (480×800): daily strip, current conditions and a
+    /// full-width chart, stacked.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_impression_7_3_portrait() {
+        let svg_content = render_bom(
+            DisplayModel::Impression73Portrait,
+            DashboardView::Today,
+            "tests/output/snapshot_bom_impression_7_3_portrait.svg",
+        )
+        .await;
+        assert!(svg_content.starts_with(r#"<svg width="480" height="800""#));
+        assert!(svg_content.contains(r#"<svg x="0" y="520" width="480" height="280""#));
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// The outlook template is drawn for 800×480, so other models get it
+    /// scaled onto their canvas.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn outlook_is_fitted_to_the_canvas() {
+        let svg_content = render_bom(
+            DisplayModel::Impression73Portrait,
+            DashboardView::Outlook,
+            "tests/output/snapshot_bom_outlook_portrait.svg",
+        )
+        .await;
+        assert!(svg_content.starts_with(
+            r#"<svg width="480" height="800" viewBox="0 0 800 480" preserveAspectRatio="xMidYMid meet""#
+        ));
+    }
+}
+
+mod colour_profile {
+    use super::*;
+    use pi_inky_weather_epd::configs::settings::{Colour, DashboardSettings};
+
+    /// Restricts every colour to black, white and (if given) red.
+    fn use_inks(settings: &mut DashboardSettings, accent: &str) {
+        let ink = |name: &str| Colour::try_new(name).unwrap();
+        let colours = &mut settings.colours;
+        colours.background_colour = ink("white");
+        for colour in [
+            &mut colours.text_colour,
+            &mut colours.x_axis_colour,
+            &mut colours.y_right_axis_colour,
+            &mut colours.feels_like_colour,
+            &mut colours.rain_colour,
+            &mut colours.snow_colour,
+            &mut colours.wind_colour,
+            &mut colours.night_colour,
+            &mut colours.cloud_colour,
+            &mut colours.freezing_colour,
+        ] {
+            *colour = ink("black");
+        }
+        colours.y_left_axis_colour = ink(accent);
+        colours.actual_temp_colour = ink(accent);
+    }
+
+    /// Black/white/red panel: hatched rain, outlined icons with red warm
+    /// parts, and a UV strip in the panel's inks.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_black_white_red() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.display.profile = ColourProfile::BlackWhiteRed;
+        use_inks(&mut settings, "red");
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name = Path::new("tests/output/snapshot_bom_black_white_red.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert!(svg_content.contains(r#"<filter id="panelLineIcons""#));
+        assert!(svg_content.contains(r#"<image filter="url(#panelLineIcons)""#));
+        assert!(svg_content.contains(r#"href="static/line-svg-static/"#));
+        assert!(!svg_content.contains("fill-svg-static"));
+        assert!(svg_content.contains(r#"fill="url(#precipHatchRain)""#));
+        assert!(!svg_content.contains(r#"fill="url(#precipBg)""#));
+        let uv_start = svg_content
+            .find(r#"<linearGradient id="UVGradient""#)
+            .unwrap();
+        let uv_end = uv_start + svg_content[uv_start..].find("</linearGradient>").unwrap();
+        let uv_gradient = &svg_content[uv_start..uv_end];
+        for colour in ["green", "yellow", "orange", "purple"] {
+            assert!(
+                !uv_gradient.contains(&format!(r#"stop-color="{colour}""#)),
+                "{colour} left in the UV strip: {uv_gradient}"
+            );
+        }
+        insta::assert_snapshot!(svg_content);
+    }
+
+    /// Black and white only, in the snow: cross-hatched snow and the solid
+    /// night tint swapped for hatching.
+    ///
+    /// **Fixed Time**: same as `precipitation::open_meteo_alaska_snow`.
+    #[tokio::test]
+    async fn open_meteo_black_white_snow() {
+        let mock_server = wiremock_setup::setup_open_meteo_mock(
+            "tests/fixtures/alaska_snow/open_meteo_hourly_forecast.json",
+            "tests/fixtures/alaska_snow/open_meteo_daily_forecast.json",
+        )
+        .await;
+        let mut settings = test_utils::open_meteo_settings_in_tz(
+            &mock_server.uri(),
+            chrono_tz::America::Anchorage,
+        );
+        settings.display.profile = ColourProfile::BlackWhite;
+        settings.render_options.night_shading = NightShading::Solid;
+        use_inks(&mut settings, "black");
+
+        let clock =
+            FixedClock::from_rfc3339("2026-01-15T21:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name = Path::new("tests/output/snapshot_open_meteo_black_white_snow.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert!(svg_content.contains(r#"fill="url(#precipHatchSnow)""#));
+        assert!(svg_content.contains(r#"fill="url(#nightShading)""#));
+        assert!(!svg_content.contains(r#"fill-opacity="0.15""#));
+        insta::assert_snapshot!(svg_content);
+    }
+}
+
+mod night_palette {
+    use super::*;
+    use pi_inky_weather_epd::configs::settings::{Colour, NightSchedule};
+
+    /// 9pm in Melbourne, after the fixture's sunset: the dashboard switches
+    /// to the `[colours.night]` overrides on its own.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_after_sunset() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        let colour = |name: &str| Some(Colour::try_new(name).unwrap());
+        let night = &mut settings.colours.night;
+        night.schedule = NightSchedule::Sunset;
+        night.background_colour = colour("black");
+        night.text_colour = colour("white");
+        night.x_axis_colour = colour("white");
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+        let output_svg_name = Path::new("tests/output/snapshot_bom_after_sunset.svg");
+
+        let svg_content = tokio::task::spawn_blocking(move || {
+            let result = generate_weather_dashboard_injection(&settings, &clock, output_svg_name);
+            assert!(
+                result.is_ok(),
+                "Dashboard generation failed: {:?}",
+                result.err()
+            );
+            fs::read_to_string(output_svg_name).expect("Failed to read generated SVG file")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert!(svg_content.contains(r#"<rect width="100%" height="100%" fill="black""#));
+        assert!(svg_content.contains("stroke: white;"));
+        insta::assert_snapshot!(svg_content);
+    }
+}
+
+mod self_contained {
+    use super::*;
+    use pi_inky_weather_epd::utils::convert_svg_to_png;
+
+    /// Renders the BOM dashboard to SVG and PNG, as a file-referencing page
+    /// or a self-contained one.
+    async fn render_bom(self_contained: bool, name: &'static str) -> (String, Vec<u8>) {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.misc.self_contained_svg = self_contained;
+
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+        tokio::task::spawn_blocking(move || {
+            let svg_path = Path::new("tests/output").join(format!("{name}.svg"));
+            let png_path = Path::new("tests/output").join(format!("{name}.png"));
+            generate_weather_dashboard_injection(&settings, &clock, &svg_path)
+                .expect("Dashboard generation failed");
+            convert_svg_to_png(&svg_path, &png_path, 1.0).expect("PNG conversion failed");
+            (
+                fs::read_to_string(&svg_path).expect("Failed to read generated SVG file"),
+                fs::read(&png_path).expect("Failed to read generated PNG file"),
+            )
+        })
+        .await
+        .expect("Task panicked")
+    }
+
+    /// Icons become one `<symbol>` each and the fonts are embedded, with
+    /// nothing left pointing into `static/`, and the page renders exactly
+    /// as before.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_dashboard_renders_the_same() {
+        let (referencing_svg, referencing_png) = render_bom(false, "bom_referencing").await;
+        let (svg, png) = render_bom(true, "bom_self_contained").await;
+
+        assert!(referencing_svg.contains("<image"));
+        assert!(!svg.contains("<image"), "icons left as images");
+        assert!(!svg.contains("static/"), "paths into static/ left");
+        // Tuesday to Thursday share one sunny icon, drawn from one symbol.
+        assert_eq!(svg.matches(r##"<use href="#icon-clear-day""##).count(), 3);
+        assert_eq!(svg.matches(r#"<symbol id="icon-clear-day">"#).count(), 1);
+        assert!(svg.contains("font-family: 'Roboto'; font-style: italic;"));
+        assert!(svg.contains("font-family: 'Roboto-Regular-Dashed'"));
+        // Nesting the icons can nudge anti-aliasing, but nothing visible.
+        let decode = |png: &[u8]| resvg::tiny_skia::Pixmap::decode_png(png).unwrap();
+        let (pixmap, referencing) = (decode(&png), decode(&referencing_png));
+        assert_eq!(pixmap.width(), referencing.width());
+        let differing = pixmap
+            .data()
+            .iter()
+            .zip(referencing.data())
+            .filter(|(a, b)| a.abs_diff(**b) > 16)
+            .count();
+        assert_eq!(differing, 0, "self-contained page renders differently");
+    }
+}
+
+mod renderer {
+    use super::*;
+    use pi_inky_weather_epd::configs::settings::Providers;
+    use pi_inky_weather_epd::providers::{bom::BomProvider, WeatherProvider};
+    use pi_inky_weather_epd::{generate_weather_dashboard_from_files, DashboardRenderer};
+
+    fn bom_clock() -> FixedClock {
+        FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock")
+    }
+
+    /// Renders the BOM fixtures through the file-writing wrapper, against a
+    /// mock server of its own, and returns the saved SVG.
+    async fn written_bom_svg(svg_name: &'static str) -> String {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+        let settings = test_utils::bom_settings(&mock_server.uri());
+
+        tokio::task::spawn_blocking(move || {
+            let svg_path = Path::new("tests/output").join(svg_name);
+            generate_weather_dashboard_injection(&settings, &bom_clock(), &svg_path)
+                .expect("Dashboard generation failed");
+            fs::read_to_string(svg_path).expect("Failed to read generated SVG")
+        })
+        .await
+        .expect("Task panicked")
+    }
+
+    /// The in-memory render is exactly what the file-writing wrapper saves,
+    /// and comes with its PNG.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_render_matches_the_written_svg() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+        let settings = test_utils::bom_settings(&mock_server.uri());
+
+        let rendered = tokio::task::spawn_blocking(move || {
+            DashboardRenderer::new(&settings, &bom_clock())
+                .render()
+                .expect("In-memory render failed")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert_eq!(
+            rendered.svg,
+            written_bom_svg("renderer_bom_written.svg").await
+        );
+        let png = resvg::tiny_skia::Pixmap::decode_png(&rendered.png).expect("PNG decodes");
+        assert_eq!((png.width(), png.height()), (1600, 960));
+    }
+
+    /// Forecasts fetched up front and a template held in memory render the
+    /// same page, without the renderer requesting anything (the mocks expect
+    /// one request each) or reading `misc.template_path`.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_prefetched_forecast_and_in_memory_template() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        let template = fs::read(&settings.misc.template_path).expect("Failed to read template");
+        settings.misc.template_path = "tests/output/no-such-template.svg".into();
+
+        let prefetched = tokio::task::spawn_blocking(move || {
+            let provider = BomProvider::new(settings.misc.weather_data_cache_path.clone());
+            let daily = provider
+                .fetch_daily_forecast(&settings, &bom_clock())
+                .unwrap()
+                .data;
+            let hourly = provider
+                .fetch_hourly_forecast(&settings, &bom_clock())
+                .unwrap()
+                .data;
+            DashboardRenderer::new(&settings, &bom_clock())
+                .with_template(template)
+                .with_forecast(hourly, daily)
+                .render_svg()
+                .expect("Render from prefetched data failed")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert_eq!(
+            prefetched,
+            written_bom_svg("renderer_bom_prefetched.svg").await
+        );
+    }
+
+    /// Rendering from the BOM fixture files on disk gives the same page as
+    /// fetching them.
+    ///
+    /// **Fixed Time**: same as `provider::bom_dashboard`.
+    #[tokio::test]
+    async fn bom_render_from_forecast_files() {
+        let expected = written_bom_svg("renderer_bom_fetched.svg").await;
+        let settings = test_utils::test_settings(|s| {
+            s.api.provider = Providers::Bom;
+            s.misc.generated_svg_name = "tests/output/renderer_bom_from_files.svg".into();
+            s.misc.generated_png_name = "tests/output/renderer_bom_from_files.png".into();
+        });
+
+        let rendered = tokio::task::spawn_blocking(move || {
+            generate_weather_dashboard_from_files(
+                &settings,
+                &bom_clock(),
+                Path::new("tests/fixtures/bom_hourly_forecast.json"),
+                Path::new("tests/fixtures/bom_daily_forecast.json"),
+            )
+            .expect("Render from files failed");
+            fs::read_to_string(&settings.misc.generated_svg_name).expect("Failed to read SVG")
+        })
+        .await
+        .expect("Task panicked");
+
+        assert_eq!(rendered, expected);
+    }
+
+    /// A template given to the renderer is used on models that otherwise
+    /// compose a built-in layout, and with a `[layout]` configured.
+    #[test]
+    fn given_template_wins_over_layouts() {
+        let settings = test_utils::test_settings(|s| {
+            s.display.model = DisplayModel::Impression4;
+            s.layout.widgets = pi_inky_weather_epd::dashboard::widgets::layouts::built_in(
+                DisplayModel::Impression57,
+            );
+        });
+        let clock = bom_clock();
+        let template = r#"<svg width="800" height="480" xmlns="http://www.w3.org/2000/svg"><text id="given">{current_day_date}</text></svg>"#;
+
+        let svg = DashboardRenderer::new(&settings, &clock)
+            .with_template(template)
+            .with_forecast(Vec::new(), Vec::new())
+            .render_svg()
+            .expect("Render with a given template failed");
+
+        assert!(svg.contains(r#"<text id="given">"#));
+        assert!(svg.contains(r#"viewBox="0 0 800 480""#));
+    }
+
+    #[test]
+    fn template_must_be_utf8() {
+        let settings = test_utils::test_settings(|_| {});
+        let clock = bom_clock();
+
+        let error = DashboardRenderer::new(&settings, &clock)
+            .with_template(vec![0xFF, 0xFE])
+            .with_forecast(Vec::new(), Vec::new())
+            .render_svg()
+            .unwrap_err();
+
+        assert!(error.to_string().contains("not valid UTF-8"));
+    }
+}
+
+mod refresh {
+    use super::*;
+    use pi_inky_weather_epd::configs::settings::Providers;
+    use pi_inky_weather_epd::generate_weather_dashboard_from_files;
+    use pi_inky_weather_epd::refresh::{status_path, RenderOutcome};
+
+    /// A second run rendering the same page leaves the outputs alone and says
+    /// so in the status file; the next hour's page is written again.
+    #[test]
+    fn unchanged_render_skips_the_outputs() {
+        let output = tempfile::tempdir().expect("Failed to create output dir");
+        let settings = test_utils::test_settings(|s| {
+            s.api.provider = Providers::Bom;
+            s.refresh.skip_unchanged = true;
+            s.refresh.force_every_hours = 6;
+            s.dev.disable_png_output = false;
+            s.misc.generated_svg_name = output.path().join("dashboard.svg");
+            s.misc.generated_png_name = output.path().join("dashboard.png");
+        });
+        let render = |at: &str| {
+            let clock = FixedClock::from_rfc3339(at).expect("Failed to create fixed clock");
+            generate_weather_dashboard_from_files(
+                &settings,
+                &clock,
+                Path::new("tests/fixtures/bom_hourly_forecast.json"),
+                Path::new("tests/fixtures/bom_daily_forecast.json"),
+            )
+            .expect("Render from files failed")
+        };
+
+        assert_eq!(render("2025-10-25T10:00:00Z"), RenderOutcome::Written);
+        assert_eq!(
+            fs::read_to_string(status_path(&settings)).unwrap(),
+            "changed"
+        );
+        fs::remove_file(&settings.misc.generated_svg_name).unwrap();
+
+        assert_eq!(render("2025-10-25T10:05:00Z"), RenderOutcome::Unchanged);
+        assert_eq!(
+            fs::read_to_string(status_path(&settings)).unwrap(),
+            "unchanged"
+        );
+        assert!(!settings.misc.generated_svg_name.exists());
+
+        assert_eq!(render("2025-10-25T16:05:00Z"), RenderOutcome::Written);
+        assert!(settings.misc.generated_svg_name.exists());
+    }
+}
+
+mod error_screen {
+    use super::*;
+    use pi_inky_weather_epd::configs::settings::Providers;
+    use pi_inky_weather_epd::generate_weather_dashboard_from_files;
+    use pi_inky_weather_epd::refresh::RenderOutcome;
+
+    /// A run that can't generate the dashboard still writes a page saying
+    /// why, and reports it as written so the panel shows it.
+    #[test]
+    fn missing_template_writes_the_error_screen() {
+        let output = tempfile::tempdir().expect("Failed to create output dir");
+        let settings = test_utils::test_settings(|s| {
+            s.api.provider = Providers::Bom;
+            s.misc.template_path = output.path().join("missing.svg");
+            s.misc.generated_svg_name = output.path().join("dashboard.svg");
+        });
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+
+        let outcome = generate_weather_dashboard_from_files(
+            &settings,
+            &clock,
+            Path::new("tests/fixtures/bom_hourly_forecast.json"),
+            Path::new("tests/fixtures/bom_daily_forecast.json"),
+        )
+        .expect("The error screen should be written");
+
+        assert_eq!(outcome, RenderOutcome::ErrorScreen);
+        let svg = fs::read_to_string(&settings.misc.generated_svg_name)
+            .expect("Failed to read the error screen");
+        assert!(svg.contains("Weather update failed"));
+        assert!(svg.contains("Failed to read template"));
+        assert!(svg.contains("A file is missing"));
+        assert!(svg.contains("Last good data: none"));
+    }
+
+    #[test]
+    fn missing_forecast_file_writes_the_error_screen() {
+        let output = tempfile::tempdir().expect("Failed to create output dir");
+        let settings = test_utils::test_settings(|s| {
+            s.api.provider = Providers::Bom;
+            s.misc.generated_svg_name = output.path().join("dashboard.svg");
+        });
+        let clock =
+            FixedClock::from_rfc3339("2025-10-25T10:00:00Z").expect("Failed to create fixed clock");
+
+        let outcome = generate_weather_dashboard_from_files(
+            &settings,
+            &clock,
+            &output.path().join("missing_hourly.json"),
+            Path::new("tests/fixtures/bom_daily_forecast.json"),
+        )
+        .expect("The error screen should be written");
+
+        assert_eq!(outcome, RenderOutcome::ErrorScreen);
+        let svg = fs::read_to_string(&settings.misc.generated_svg_name)
+            .expect("Failed to read the error screen");
+        assert!(svg.contains("missing_hourly.json"));
+        assert!(svg.contains("A file is missing"));
+    }
+}
+
+mod data_age {
+    use super::*;
+    use pi_inky_weather_epd::DashboardRenderer;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const NOW: &str = "2025-10-25T10:00:00Z";
+
+    /// Renders against an Open-Meteo API that rejects every request, so both
+    /// forecasts come from a cache last written `cached_hours_ago` before
+    /// [`NOW`].
+    async fn render_from_cache(cached_hours_ago: u64) -> String {
+        let mock_server = MockServer::start().await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": true,
+                "reason": "Invalid parameter"
+            })))
+            .mount(&mock_server)
+            .await;
+        let settings = test_utils::open_meteo_settings(&mock_server.uri());
+        let clock = FixedClock::from_rfc3339(NOW).expect("Failed to create fixed clock");
+        let cached_at = std::time::SystemTime::from(
+            chrono::DateTime::parse_from_rfc3339(NOW).unwrap()
+                - chrono::Duration::hours(cached_hours_ago as i64),
+        );
+        for section in ["hourly", "daily"] {
+            let cache_file = settings
+                .misc
+                .weather_data_cache_path
+                .join(format!("open_meteo_{section}_forecast.json"));
+            fs::copy(
+                format!("tests/fixtures/open_meteo_{section}_forecast.json"),
+                &cache_file,
+            )
+            .expect("Failed to fill the cache");
+            fs::File::options()
+                .write(true)
+                .open(&cache_file)
+                .and_then(|file| file.set_modified(cached_at))
+                .expect("Failed to age the cache");
+        }
+
+        tokio::task::spawn_blocking(move || {
+            DashboardRenderer::new(&settings, &clock)
+                .render_svg()
+                .expect("Render from cache failed")
+        })
+        .await
+        .expect("Task panicked")
+    }
+
+    /// Cache younger than `stale_after_hours` shows when it was fetched, but
+    /// no stale-data diagnostic.
+    #[tokio::test]
+    async fn recent_cache_is_not_flagged() {
+        let svg = render_from_cache(1).await;
+
+        assert!(svg
+            .contains(r#"font-weight="normal" fill="black" visibility="visible">Updated 20:00<"#));
+        assert!(svg.contains(r#"height="100" text-anchor="middle" visibility="hidden""#));
+        assert!(!svg.contains("code-red.svg"));
+    }
+
+    /// Days-old cache says how old it is, in bold, next to the stale-data
+    /// diagnostic.
+    #[tokio::test]
+    async fn old_cache_is_flagged() {
+        let svg = render_from_cache(50).await;
+
+        assert!(svg.contains(r#"font-size="18" font-weight="bold""#));
+        assert!(svg.contains(">Data 2 d old<"));
+        assert!(svg.contains("code-red.svg"));
+    }
+}
+
+mod summary {
+    use super::*;
+    use pi_inky_weather_epd::DashboardRenderer;
+
+    /// Oct 25 2025, 01:00 UTC = Oct 25 2025, 12:00 Melbourne (AEDT) – noon
+    const NOW: &str = "2025-10-25T01:00:00Z";
+
+    async fn render(settings: test_utils::TestSettings) -> String {
+        let clock = FixedClock::from_rfc3339(NOW).expect("Failed to create fixed clock");
+        tokio::task::spawn_blocking(move || {
+            DashboardRenderer::new(&settings, &clock)
+                .render_svg()
+                .expect("Render failed")
+        })
+        .await
+        .expect("Task panicked")
+    }
+
+    /// The summary line's text, or `None` when it is hidden.
+    fn summary_line(svg: &str) -> Option<&str> {
+        let start = svg.find(r#"y="72" font-size="13""#)?;
+        let line = &svg[start..];
+        if line[..line.find('>')?].contains(r#"visibility="hidden""#) {
+            return None;
+        }
+        let text = &line[line.find('>')? + 1..];
+        Some(&text[..text.find('<')?])
+    }
+
+    /// BOM's own text for the day, whole when it fits.
+    #[tokio::test]
+    async fn bom_uses_the_forecaster_text() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+
+        let svg = render(test_utils::bom_settings(&mock_server.uri())).await;
+
+        assert_eq!(
+            summary_line(&svg),
+            Some("Cloudy. Medium chance of showers. Light winds.")
+        );
+    }
+
+    /// Open-Meteo has no text, so the line is generated in the display
+    /// language.
+    #[tokio::test]
+    async fn open_meteo_is_generated_in_the_display_language() {
+        let mock_server = wiremock_setup::setup_open_meteo_mock(
+            "tests/fixtures/open_meteo_hourly_forecast.json",
+            "tests/fixtures/open_meteo_daily_forecast.json",
+        )
+        .await;
+        let mut settings = test_utils::open_meteo_settings(&mock_server.uri());
+        settings.render_options.language = Language::De;
+
+        let svg = render(settings).await;
+
+        let line = summary_line(&svg).expect("summary shown");
+        assert!(line.ends_with('.'), "{line}");
+        assert!(!line.contains("developing"), "{line}");
+    }
+
+    #[tokio::test]
+    async fn hidden_when_disabled() {
+        let mock_server = wiremock_setup::setup_bom_mock(
+            "tests/fixtures/bom_daily_forecast.json",
+            "tests/fixtures/bom_hourly_forecast.json",
+        )
+        .await;
+        let mut settings = test_utils::bom_settings(&mock_server.uri());
+        settings.render_options.show_summary = false;
+
+        let svg = render(settings).await;
+
+        assert_eq!(summary_line(&svg), None);
+    }
+}
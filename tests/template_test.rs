@@ -19,3 +19,17 @@ fn base_template_svg_ok() {
         "The base template file is not a valid SVG"
     );
 }
+
+/// Validates that the 14-day outlook template file is valid SVG
+#[test]
+fn outlook_template_svg_ok() {
+    let settings = test_utils::test_settings(|_| {});
+    let svg_content = fs::read_to_string(&settings.misc.outlook_template_path)
+        .expect("Failed to read the outlook template SVG file");
+    let svg_tree = usvg::Tree::from_str(&svg_content, &usvg::Options::default());
+
+    assert!(
+        svg_tree.is_ok(),
+        "The outlook template file is not a valid SVG"
+    );
+}